@@ -0,0 +1,668 @@
+// ============================================================================
+// Dioxus学習プロジェクト: AI（コンピューター対戦）
+// ============================================================================
+// このファイルはコンピューターの着手選択ロジックを定義しています。
+//
+// 学習ポイント:
+// - ゲームロジック（GameLogic）を再利用した探索
+// - シード付き疑似乱数による再現可能なランダム性
+// - UIに依存しない純粋なロジックとテスト
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Board, GameLogic, GameState, Outcome, Player, Score, WinRule};
+
+// ============================================================================
+// 疑似乱数生成器: シード付きxorshift
+// ============================================================================
+// 外部クレートを使わない小さな疑似乱数生成器
+// 学習ポイント: 同じシードなら同じ乱数列になる（テストやリプレイで再現可能）
+// 再現性の約束: 乱数を使う処理（ランダム・腕前のAI、最善手が複数あるときの選択、観戦リンク）は
+// すべて呼び出し側から渡されたこのRngだけを使い、スレッドごとの乱数や時刻を内部で読まない。
+// そのため「同じシード＋同じ人間の入力」なら、何度実行しても同じ対局になる（determinism.rsで検証）
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// シードから生成する（0は内部状態として使えないため置き換える）
+    pub fn new(seed: u64) -> Self {
+        Rng(if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed })
+    }
+
+    /// 次の64ビット乱数を返す（xorshift64*）
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// 0以上n未満の乱数を返す
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+// ============================================================================
+// ミニマックス: 最善手の列挙
+// ============================================================================
+
+/// 着手後の結果を手番プレイヤーから見た点数にする（勝ち2・引き分け1・負け0）
+fn outcome_rank(winner: Option<Player>, player: Player) -> u8 {
+    match winner {
+        Some(w) if w == player => 2,
+        None => 1,
+        Some(_) => 0,
+    }
+}
+
+/// 指定したルールで最善の結果になる手をすべて返す（ゲーム終了済みなら空）
+/// 学習ポイント: 各候補手の最善手順の結果を比較し、最大のものを残す
+pub fn best_moves(board: Board, player: Player, rule: WinRule) -> Vec<(usize, usize)> {
+    if GameLogic::check_game_state_with(board, rule) != GameState::Playing {
+        return Vec::new();
+    }
+
+    let ranked: Vec<((usize, usize), u8)> = GameLogic::empty_cells(board)
+        .into_iter()
+        .map(|(row, col)| {
+            let mut next = board;
+            next[row][col] = Some(player);
+            let winner = GameLogic::perfect_play_winner_with(next, player.next(), rule);
+            ((row, col), outcome_rank(winner, player))
+        })
+        .collect();
+
+    let best = ranked.iter().map(|&(_, rank)| rank).max().unwrap_or(0);
+    ranked
+        .into_iter()
+        .filter(|&(_, rank)| rank == best)
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+/// 両者が最善手だけを指した対局を最後まで進める
+/// 学習ポイント: 最善手が複数ある場合はシードで選び、様々な最善手順を検証できる
+pub fn play_optimal(seed: u64) -> (Board, GameState) {
+    let mut rng = Rng::new(seed);
+    let mut board = GameLogic::empty_board();
+    let mut player = Player::X;
+
+    loop {
+        let state = GameLogic::check_game_state(board);
+        if state != GameState::Playing {
+            return (board, state);
+        }
+
+        let moves = best_moves(board, player, WinRule::Standard);
+        let (row, col) = moves[rng.below(moves.len())];
+        board[row][col] = Some(player);
+        player = player.next();
+    }
+}
+
+// ============================================================================
+// AI戦略: 着手選択アルゴリズムの種類
+// ============================================================================
+// 学習ポイント: enumで戦略を表し、match式で実装を切り替える（Strategyパターン）
+// - Serialize, Deserialize: 設定としてセッションに保存する（serde）
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum AiStrategy {
+    Random,     // 空きセルからランダム
+    #[default]
+    Heuristic,  // 定番の優先順位による戦略（探索なし）
+    Minimax,    // 全探索による最善手
+    Recorded(Vec<(usize, usize)>),  // 記録した手順を順に再生（過去の自分と対戦）
+    Skill(u8),  // 指定した割合（0〜100%）で最善手、それ以外は最善手以外のランダムな手
+}
+
+impl AiStrategy {
+    /// 選択肢に表示する名前
+    pub fn label(&self) -> &'static str {
+        match self {
+            AiStrategy::Random => "ランダム",
+            AiStrategy::Heuristic => "定石",
+            AiStrategy::Minimax => "最強",
+            AiStrategy::Recorded(_) => "過去の自分",
+            AiStrategy::Skill(_) => "腕前（%指定）",
+        }
+    }
+
+    /// URL・コマンドライン引数で使う名前（「過去の自分」「腕前」は値を持つためNone）
+    pub fn key(&self) -> Option<&'static str> {
+        match self {
+            AiStrategy::Random => Some("random"),
+            AiStrategy::Heuristic => Some("heuristic"),
+            AiStrategy::Minimax => Some("minimax"),
+            AiStrategy::Recorded(_) | AiStrategy::Skill(_) => None,
+        }
+    }
+
+    /// 名前から戦略を読み取る（大文字小文字は区別しない）
+    pub fn from_key(key: &str) -> Option<AiStrategy> {
+        match key.to_lowercase().as_str() {
+            "random" => Some(AiStrategy::Random),
+            "heuristic" => Some(AiStrategy::Heuristic),
+            "minimax" => Some(AiStrategy::Minimax),
+            _ => None,
+        }
+    }
+
+    /// 指定したルールで次の一手を選ぶ（置ける場所がなければNone）
+    /// 学習ポイント: 勝敗の判定も読みも同じルールで行う（「斜めなし」では斜めが揃っても対局は続く）
+    pub fn choose_move(&self, board: Board, player: Player, rule: WinRule, rng: &mut Rng) -> Option<(usize, usize)> {
+        if GameLogic::check_game_state_with(board, rule) != GameState::Playing {
+            return None;
+        }
+
+        match self {
+            AiStrategy::Random => pick(&GameLogic::empty_cells(board), rng),
+            AiStrategy::Heuristic => heuristic_move(board, player, rule),
+            AiStrategy::Minimax => pick(&best_moves(board, player, rule), rng),
+            AiStrategy::Recorded(moves) => recorded_move(board, player, moves, rule),
+            AiStrategy::Skill(percent) => skill_move(board, player, *percent, rule, rng),
+        }
+    }
+}
+
+// ============================================================================
+// AI同士の対戦: 戦略の比較
+// ============================================================================
+// 学習ポイント: GameLogicへのメソッド追加はこのファイルで行い、types.rsがai.rsに依存しないようにする
+impl GameLogic {
+    /// 2つの戦略でgames局対戦し、結果を集計する（Xの欄がaの勝ち、Oの欄がbの勝ち）
+    /// 学習ポイント: 先手を1局ごとに入れ替え、先手の有利さが片方に偏らないようにする
+    pub fn tournament(a: AiStrategy, b: AiStrategy, games: usize, seed: u64) -> Score {
+        let mut rng = Rng::new(seed);
+        let mut score = Score::default();
+
+        for game in 0..games {
+            let a_plays_x = game % 2 == 0;
+            let mut board = GameLogic::empty_board();
+            let mut player = Player::X;
+
+            let state = loop {
+                let state = GameLogic::check_game_state(board);
+                if state != GameState::Playing {
+                    break state;
+                }
+                let strategy = if (player == Player::X) == a_plays_x { &a } else { &b };
+                let Some((row, col)) = strategy.choose_move(board, player, WinRule::Standard, &mut rng) else {
+                    break state;
+                };
+                board[row][col] = Some(player);
+                player = player.next();
+            };
+
+            // 盤面上のX/Oではなく、どちらの戦略が勝ったかで記録する
+            match state {
+                GameState::Won(winner) if (winner == Player::X) == a_plays_x => score.record(Outcome::Win(Player::X)),
+                GameState::Won(_) => score.record(Outcome::Win(Player::O)),
+                _ => score.record(Outcome::Draw),
+            }
+        }
+        score
+    }
+}
+
+/// コーチモードで初心者に勧める最初の一手（中央）を返す（表示しない場合はNone）
+/// 学習ポイント: 「まだ誰も置いていない」「人間が先手」の両方を満たすときだけ表示する
+/// （一手戻して空の盤面に戻った場合も、コーチモードがONなら再び表示される）
+pub fn opening_suggestion(coach_mode: bool, board: Board, ai_player: Option<Player>) -> Option<(usize, usize)> {
+    let fresh = GameLogic::moves_remaining(board) == 9;
+    (coach_mode && fresh && ai_player != Some(Player::X)).then_some((1, 1))
+}
+
+// ============================================================================
+// 型定義: 予約したAIの着手
+// ============================================================================
+// AIは考える時間を置いてから着手するため、その間にリセット等でゲームが変わることがある
+// 学習ポイント: 盤面の比較だけでは「リセット後にたまた同じ盤面」を見分けられないので、
+// ゲームを置き換えるたびに増える世代番号（epoch）も一緒に覚えておく
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PendingMove {
+    epoch: u32,      // 予約したときのゲームの世代
+    board: Board,    // 予約したときの盤面
+    player: Player,  // 着手するAIのプレイヤー
+}
+
+impl PendingMove {
+    /// 現在の世代・盤面で、指定プレイヤーの着手を予約する
+    pub fn new(epoch: u32, board: Board, player: Player) -> Self {
+        PendingMove { epoch, board, player }
+    }
+
+    /// 予約した着手をまだ指してよいか（リセット・盤面の変化・AI設定の変更がなければtrue）
+    pub fn is_current(&self, epoch: u32, board: Board, ai_player: Option<Player>) -> bool {
+        self.epoch == epoch && self.board == board && ai_player == Some(self.player)
+    }
+}
+
+/// 読み上げ用の着手のお知らせ（AIが指した手だけ、観戦の再生中はなし）
+/// 学習ポイント: 人間の手はセルのラベルで伝わるので、読み上げるのはAIの手に限る
+pub fn move_announcement(
+    ai_player: Option<Player>,
+    mover: Player,
+    (row, col): (usize, usize),
+    spectating: bool,
+) -> Option<String> {
+    (!spectating && ai_player == Some(mover)).then(|| format!("AIが 行{} 列{} に置きました", row + 1, col + 1))
+}
+
+// ============================================================================
+// 強さの自動調整
+// ============================================================================
+/// 自動調整で行き来する強さ（弱い順、段階はこの配列のインデックス）
+pub const ADAPTIVE_LEVELS: [AiStrategy; 3] = [AiStrategy::Random, AiStrategy::Heuristic, AiStrategy::Minimax];
+
+/// 強さを1段変えるまでの連勝・連敗の数
+pub const ADAPT_STREAK: usize = 3;
+
+/// 前回の調整以降の結果から、次の強さの段階を決める（変えないならNone）
+/// 学習ポイント:
+/// - 人間がADAPT_STREAK連勝したら1段上げ、連敗したら1段下げる（引き分けで連続は途切れる）
+/// - 設定した範囲（bounds、両端を含む）の外には出さない
+pub fn adapt_level(level: usize, results: &[Outcome], ai_player: Player, bounds: (usize, usize)) -> Option<usize> {
+    let recent = &results[results.len().checked_sub(ADAPT_STREAK)?..];
+    let next = if recent.iter().all(|&outcome| outcome == Outcome::Win(ai_player.opponent())) {
+        level + 1
+    } else if recent.iter().all(|&outcome| outcome == Outcome::Win(ai_player)) {
+        level.saturating_sub(1)
+    } else {
+        return None;
+    };
+    let next = next.clamp(bounds.0, bounds.1);
+    (next != level).then_some(next)
+}
+
+/// AIが着手する番か（この間は人間の入力を受け付けない）
+/// 学習ポイント: UIの入力ロックの条件を純粋関数にしてテスト可能にする
+pub fn is_ai_turn(ai_player: Option<Player>, current_player: Player, game_state: GameState) -> bool {
+    game_state == GameState::Playing && ai_player == Some(current_player)
+}
+
+/// 候補からランダムに1つ選ぶ
+fn pick(moves: &[(usize, usize)], rng: &mut Rng) -> Option<(usize, usize)> {
+    if moves.is_empty() {
+        None
+    } else {
+        Some(moves[rng.below(moves.len())])
+    }
+}
+
+/// percent%の確率で最善手（GameLogic::best_move）、それ以外は最善手以外のランダムな手を指す
+/// 学習ポイント: 「わざと外す」手は最善手を除いて選ぶので、0%なら最善手を指さない
+/// （置ける場所が最善手しかなければ、それを指す）
+fn skill_move(board: Board, player: Player, percent: u8, rule: WinRule, rng: &mut Rng) -> Option<(usize, usize)> {
+    let best = GameLogic::best_move_with(board, player, rule)?;
+    if rng.below(100) < usize::from(percent) {
+        return Some(best);
+    }
+    let others: Vec<(usize, usize)> = GameLogic::empty_cells(board)
+        .into_iter()
+        .filter(|&cell| cell != best)
+        .collect();
+    pick(&others, rng).or(Some(best))
+}
+
+/// 記録した手順を順に指す（置けない手は飛ばし、使い切ったらヒューリスティックで補う）
+/// 学習ポイント: 盤上の自分の駒の数から「何手目か」を求め、状態を持たずに再生する
+fn recorded_move(board: Board, player: Player, moves: &[(usize, usize)], rule: WinRule) -> Option<(usize, usize)> {
+    let played = GameLogic::count_pieces(board, player);
+    moves
+        .iter()
+        .skip(played)
+        .copied()
+        .find(|&(row, col)| GameLogic::is_valid_move(board, row, col))
+        .or_else(|| heuristic_move(board, player, rule))
+}
+
+// ============================================================================
+// ヒューリスティック戦略: 定番の優先順位
+// ============================================================================
+// 1. 勝てるなら勝つ          2. 相手の勝ちを防ぐ
+// 3. ダブルリーチを作る      4. 相手のダブルリーチを防ぐ
+// 5. 中央                    6. 相手の角の対角
+// 7. 空いている角            8. 空いている辺
+// 学習ポイント: Option::or_elseによる優先順位付きのフォールバック
+
+/// ヒューリスティック戦略で次の一手を選ぶ（リーチ・ダブルリーチはルールの勝利ラインで数える）
+pub fn heuristic_move(board: Board, player: Player, rule: WinRule) -> Option<(usize, usize)> {
+    let opponent = player.opponent();
+
+    winning_moves(board, player, rule).first().copied()
+        .or_else(|| winning_moves(board, opponent, rule).first().copied())
+        .or_else(|| fork_moves(board, player, rule).first().copied())
+        .or_else(|| block_fork_move(board, player, rule))
+        .or_else(|| GameLogic::center_controlled(board).is_none().then_some((1, 1)))
+        .or_else(|| opposite_corner_move(board, player))
+        .or_else(|| first_empty(board, &GameLogic::CORNERS))
+        .or_else(|| first_empty(board, &GameLogic::SIDES))
+}
+
+/// 置けばすぐに揃う（勝てる）セルの一覧
+fn winning_moves(board: Board, player: Player, rule: WinRule) -> Vec<(usize, usize)> {
+    GameLogic::empty_cells(board)
+        .into_iter()
+        .filter(|&(row, col)| GameLogic::is_winning_move_with(board, row, col, player, rule))
+        .collect()
+}
+
+/// 置くとリーチ（次に勝てるセル）が2つ以上できるセルの一覧
+fn fork_moves(board: Board, player: Player, rule: WinRule) -> Vec<(usize, usize)> {
+    GameLogic::empty_cells(board)
+        .into_iter()
+        .filter(|&(row, col)| {
+            let mut next = board;
+            next[row][col] = Some(player);
+            winning_moves(next, player, rule).len() >= 2
+        })
+        .collect()
+}
+
+/// 相手のダブルリーチを防ぐ手
+/// 学習ポイント: 相手のダブルリーチ候補が複数あるときは、
+/// 「こちらがリーチをかけ、相手の受けがダブルリーチにならない」手で先手を取る
+fn block_fork_move(board: Board, player: Player, rule: WinRule) -> Option<(usize, usize)> {
+    let opponent_forks = fork_moves(board, player.opponent(), rule);
+    match opponent_forks.len() {
+        0 => None,
+        1 => Some(opponent_forks[0]),
+        _ => GameLogic::empty_cells(board)
+            .into_iter()
+            .find(|&(row, col)| {
+                let mut next = board;
+                next[row][col] = Some(player);
+                let threats = winning_moves(next, player, rule);
+                threats.len() == 1 && !opponent_forks.contains(&threats[0])
+            })
+            .or(Some(opponent_forks[0])),
+    }
+}
+
+/// 相手が角にいるとき、その対角の空き角
+/// 学習ポイント: 相手が角を1つも持っていなければ、角を調べるまでもなくNone
+fn opposite_corner_move(board: Board, player: Player) -> Option<(usize, usize)> {
+    if GameLogic::corner_count(board, player.opponent()) == 0 {
+        return None;
+    }
+    GameLogic::CORNERS
+        .iter()
+        .find(|&&(row, col)| board[row][col] == Some(player.opponent()) && board[2 - row][2 - col].is_none())
+        .map(|&(row, col)| (2 - row, 2 - col))
+}
+
+/// 候補の中で最初に空いているセル
+fn first_empty(board: Board, cells: &[(usize, usize)]) -> Option<(usize, usize)> {
+    cells.iter().copied().find(|&(row, col)| board[row][col].is_none())
+}
+
+// ============================================================================
+// テスト: AIロジックの検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BoardBuilder;
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert!(Rng::new(7).below(3) < 3);
+    }
+
+    #[test]
+    fn test_best_moves_takes_win() {
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).o(1, 0).o(1, 1).build();
+        assert_eq!(best_moves(board, Player::X, WinRule::Standard), vec![(0, 2)]);
+    }
+
+    #[test]
+    fn test_heuristic_win() {
+        // 勝ちと防御の両方が可能なら勝ちを優先
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).o(1, 0).o(1, 1).build();
+        assert_eq!(heuristic_move(board, Player::X, WinRule::Standard), Some((0, 2)));
+    }
+
+    #[test]
+    fn test_heuristic_block() {
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).o(1, 1).build();
+        assert_eq!(heuristic_move(board, Player::O, WinRule::Standard), Some((0, 2)));
+    }
+
+    #[test]
+    fn test_heuristic_fork() {
+        // 中央より先に、左上でダブルリーチを作る
+        let board = BoardBuilder::new().x(0, 1).x(1, 0).o(1, 2).o(2, 1).build();
+        assert_eq!(heuristic_move(board, Player::X, WinRule::Standard), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_heuristic_block_fork() {
+        // Xは対角の角を取っており、(0,2)と(2,0)の両方でダブルリーチを作れる
+        // 角で受けると負けるため、辺に置いてリーチをかけ先手を取る
+        let board = BoardBuilder::new().x(0, 0).x(2, 2).o(1, 1).build();
+        let choice = heuristic_move(board, Player::O, WinRule::Standard).unwrap();
+        assert!(GameLogic::SIDES.contains(&choice));
+
+        let mut next = board;
+        next[choice.0][choice.1] = Some(Player::O);
+        assert_eq!(GameLogic::perfect_play_winner(next, Player::X), None);
+    }
+
+    #[test]
+    fn test_heuristic_center() {
+        let board = BoardBuilder::new().x(0, 0).build();
+        assert_eq!(heuristic_move(board, Player::O, WinRule::Standard), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_heuristic_opposite_corner() {
+        let board = BoardBuilder::new().x(1, 1).o(0, 0).build();
+        assert_eq!(heuristic_move(board, Player::X, WinRule::Standard), Some((2, 2)));
+    }
+
+    #[test]
+    fn test_heuristic_empty_corner() {
+        let board = BoardBuilder::new().x(1, 1).o(0, 1).build();
+        assert_eq!(heuristic_move(board, Player::X, WinRule::Standard), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_heuristic_empty_side() {
+        // 角と中央が埋まり、残りの辺（上と下）はどちらが置いても揃わない対局中の盤面（Oの手番）
+        let board = BoardBuilder::new()
+            .x(0, 0).o(0, 2)
+            .o(1, 0).x(1, 1).x(1, 2)
+            .x(2, 0).o(2, 2)
+            .build();
+        assert_eq!(GameLogic::load_board(board), Ok((GameState::Playing, Player::O)));
+        assert_eq!(heuristic_move(board, Player::O, WinRule::Standard), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_strategy_choose_move() {
+        let mut rng = Rng::new(1);
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).o(1, 0).o(1, 1).build();
+
+        for strategy in [AiStrategy::Heuristic, AiStrategy::Minimax] {
+            assert_eq!(strategy.choose_move(board, Player::X, WinRule::Standard, &mut rng), Some((0, 2)));
+        }
+        let random = AiStrategy::Random.choose_move(board, Player::X, WinRule::Standard, &mut rng).unwrap();
+        assert!(GameLogic::is_valid_move(board, random.0, random.1));
+
+        // 終了した盤面では着手しない
+        let won = BoardBuilder::new().x(0, 0).x(0, 1).x(0, 2).build();
+        assert_eq!(AiStrategy::Heuristic.choose_move(won, Player::O, WinRule::Standard, &mut rng), None);
+    }
+
+    #[test]
+    fn test_ai_keeps_playing_after_diagonal_without_diagonals() {
+        // Xが斜めを揃えても「斜めなし」では対局が続くので、どの戦略もOの手を選ぶ
+        let board = BoardBuilder::new().x(0, 0).x(1, 1).x(2, 2).o(0, 1).o(1, 0).build();
+        for strategy in [AiStrategy::Random, AiStrategy::Heuristic, AiStrategy::Minimax, AiStrategy::Skill(50)] {
+            let mut rng = Rng::new(3);
+            let (row, col) = strategy.choose_move(board, Player::O, WinRule::NoDiagonals, &mut rng).unwrap();
+            assert!(GameLogic::is_valid_move(board, row, col));
+            assert_eq!(strategy.choose_move(board, Player::O, WinRule::Standard, &mut rng), None);
+        }
+
+        // 読みも同じルールで行うので、「斜めなし」では斜めのリーチを止めに行かない
+        let board = BoardBuilder::new().x(0, 0).x(1, 1).o(0, 1).build();
+        let choose = |rule| AiStrategy::Heuristic.choose_move(board, Player::O, rule, &mut Rng::new(1));
+        assert_eq!(choose(WinRule::Standard), Some((2, 2)));
+        assert_ne!(choose(WinRule::NoDiagonals), Some((2, 2)));
+    }
+
+    #[test]
+    fn test_input_locked_during_ai_turn() {
+        // AIの手番中はロック（人間の着手を拒否）
+        assert!(is_ai_turn(Some(Player::O), Player::O, GameState::Playing));
+
+        // 人間の手番・2人対戦・対局終了後はロックしない
+        assert!(!is_ai_turn(Some(Player::O), Player::X, GameState::Playing));
+        assert!(!is_ai_turn(None, Player::O, GameState::Playing));
+        assert!(!is_ai_turn(Some(Player::O), Player::O, GameState::Won(Player::X)));
+    }
+
+    #[test]
+    fn test_recorded_strategy_replays_moves() {
+        let mut rng = Rng::new(1);
+        let strategy = AiStrategy::Recorded(vec![(0, 0), (2, 0), (2, 2)]);
+
+        let board = GameLogic::empty_board();
+        assert_eq!(strategy.choose_move(board, Player::X, WinRule::Standard, &mut rng), Some((0, 0)));
+
+        let board = BoardBuilder::new().x(0, 0).o(1, 1).build();
+        assert_eq!(strategy.choose_move(board, Player::X, WinRule::Standard, &mut rng), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_recorded_strategy_falls_back() {
+        let mut rng = Rng::new(1);
+
+        // 2手目の(1,1)はOに取られているので飛ばして次の手を指す
+        let strategy = AiStrategy::Recorded(vec![(0, 0), (1, 1), (2, 2)]);
+        let board = BoardBuilder::new().x(0, 0).o(1, 1).build();
+        assert_eq!(strategy.choose_move(board, Player::X, WinRule::Standard, &mut rng), Some((2, 2)));
+
+        // 記録を使い切ったら空いているセルに置く
+        let strategy = AiStrategy::Recorded(vec![(0, 0)]);
+        let (row, col) = strategy.choose_move(board, Player::X, WinRule::Standard, &mut rng).unwrap();
+        assert!(GameLogic::is_valid_move(board, row, col));
+    }
+
+    #[test]
+    fn test_optimal_play_always_draws() {
+        // 「3x3の三目並べは最善を尽くせば必ず引き分け」という性質でミニマックスを検証
+        for seed in 0..20 {
+            let (board, state) = play_optimal(seed);
+            assert_eq!(state, GameState::Draw, "seed={} で引き分けにならない: {:?}", seed, board);
+        }
+    }
+
+    #[test]
+    fn test_opening_suggestion() {
+        let empty = GameLogic::empty_board();
+        assert_eq!(opening_suggestion(true, empty, None), Some((1, 1)));
+        assert_eq!(opening_suggestion(true, empty, Some(Player::O)), Some((1, 1)));
+
+        // コーチモードOFF・AIが先手・1手目以降は表示しない
+        assert_eq!(opening_suggestion(false, empty, None), None);
+        assert_eq!(opening_suggestion(true, empty, Some(Player::X)), None);
+        let board = BoardBuilder::new().x(0, 0).build();
+        assert_eq!(opening_suggestion(true, board, None), None);
+    }
+
+    #[test]
+    fn test_tournament_minimax_always_draws() {
+        let score = GameLogic::tournament(AiStrategy::Minimax, AiStrategy::Minimax, 6, 7);
+        assert_eq!(score, Score { x_wins: 0, o_wins: 0, draws: 6 });
+    }
+
+    #[test]
+    fn test_tournament_minimax_never_loses() {
+        // 先手・後手を入れ替えても、最強のAIはランダムに負けない
+        let score = GameLogic::tournament(AiStrategy::Minimax, AiStrategy::Random, 6, 3);
+        assert_eq!(score.wins(Player::O), 0);
+        assert_eq!(score.wins(Player::X) + score.draws, 6);
+    }
+
+    #[test]
+    fn test_pending_move_ignored_after_reset() {
+        // 空の盤面でAI（X）の着手を予約する
+        let empty = GameLogic::empty_board();
+        let pending = PendingMove::new(0, empty, Player::X);
+        assert!(pending.is_current(0, empty, Some(Player::X)));
+
+        // リセットで世代が進むと、盤面が同じ空の盤面でも指さない
+        assert!(!pending.is_current(1, empty, Some(Player::X)));
+
+        // 盤面が変わった・AIの担当が変わった場合も指さない
+        assert!(!pending.is_current(0, BoardBuilder::new().x(1, 1).build(), Some(Player::X)));
+        assert!(!pending.is_current(0, empty, None));
+    }
+
+    #[test]
+    fn test_skill_bounds() {
+        // ランダムな局面で、腕前100%は常に最善手、0%は最善手を指さない
+        // （空の盤面の全探索は時間がかかるので、1手目はランダムに置いてから始める）
+        for seed in 0..10 {
+            let mut rng = Rng::new(seed);
+            let mut board = GameLogic::empty_board();
+            board[rng.below(3)][rng.below(3)] = Some(Player::X);
+            let mut player = Player::O;
+            while let Some(best) = GameLogic::best_move(board, player) {
+                assert_eq!(AiStrategy::Skill(100).choose_move(board, player, WinRule::Standard, &mut rng), Some(best));
+                if GameLogic::moves_remaining(board) > 1 {
+                    assert_ne!(AiStrategy::Skill(0).choose_move(board, player, WinRule::Standard, &mut rng), Some(best));
+                }
+                let cells = GameLogic::empty_cells(board);
+                let (row, col) = cells[rng.below(cells.len())];
+                board[row][col] = Some(player);
+                player = player.next();
+            }
+        }
+
+        // 同じシードなら同じ手を選ぶ
+        let board = BoardBuilder::new().x(1, 1).build();
+        let choose = |seed| AiStrategy::Skill(70).choose_move(board, Player::O, WinRule::Standard, &mut Rng::new(seed));
+        assert_eq!(choose(9), choose(9));
+    }
+
+    #[test]
+    fn test_adapt_level() {
+        // AIはO。人間（X）の3連勝で1段上げ、2連勝ではまだ変えない
+        let human_win = Outcome::Win(Player::X);
+        let ai_win = Outcome::Win(Player::O);
+        let bounds = (0, ADAPTIVE_LEVELS.len() - 1);
+        assert_eq!(adapt_level(1, &[human_win, human_win], Player::O, bounds), None);
+        assert_eq!(adapt_level(1, &[ai_win, human_win, human_win, human_win], Player::O, bounds), Some(2));
+
+        // 3連敗で1段下げる。引き分けを挟むと連続にならない
+        assert_eq!(adapt_level(1, &[ai_win, ai_win, ai_win], Player::O, bounds), Some(0));
+        assert_eq!(adapt_level(1, &[ai_win, Outcome::Draw, ai_win], Player::O, bounds), None);
+
+        // 範囲の端では止まる
+        assert_eq!(adapt_level(2, &[human_win; 3], Player::O, bounds), None);
+        assert_eq!(adapt_level(1, &[human_win; 3], Player::O, (0, 1)), None);
+        assert_eq!(adapt_level(1, &[ai_win; 3], Player::O, (1, 2)), None);
+    }
+
+    #[test]
+    fn test_move_announcement_only_for_ai() {
+        assert_eq!(
+            move_announcement(Some(Player::O), Player::O, (1, 2), false).as_deref(),
+            Some("AIが 行2 列3 に置きました")
+        );
+
+        // 人間の手・2人対戦・観戦の再生中は読み上げない
+        assert_eq!(move_announcement(Some(Player::O), Player::X, (1, 2), false), None);
+        assert_eq!(move_announcement(None, Player::X, (0, 0), false), None);
+        assert_eq!(move_announcement(Some(Player::O), Player::O, (1, 2), true), None);
+    }
+}
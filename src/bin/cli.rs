@@ -0,0 +1,224 @@
+// ============================================================================
+// Dioxus学習プロジェクト: ターミナル版三目並べ
+// ============================================================================
+// このファイルはDioxusを使わずに、ターミナル上で三目並べを遊ぶための小さなバイナリです。
+// 盤面の判定やAIはDioxusアプリと同じライブラリ（GameLogic・AiStrategy）を使い、
+// 対局の進行（着手・一手戻す・やり直し）はGameControllerに任せます。
+//
+// 使い方:
+//   cargo run --bin cli
+//   cargo run --bin cli -- --ai o --strategy minimax
+//   cargo run --bin cli -- --board "XO./.X./..."
+//   cargo run --bin cli -- --ai o --strategy random --seed 42   （同じシードと入力なら同じ対局になる）
+//
+// 学習ポイント:
+// - ロジックをUIから切り離したことで、別のフロントエンドから再利用できる
+// - 標準入力（stdin）の行単位の読み取り
+// - std::env::argsによる簡単なコマンドライン引数の解析
+
+use std::io::{self, BufRead, Write};
+
+use tic_tac_toe::ai::{AiStrategy, Rng};
+use tic_tac_toe::controller::GameController;
+use tic_tac_toe::types::{Board, GameLogic, GameState, Outcome, Player, WinRule};
+
+const USAGE: &str = "使い方: cli [--ai x|o] [--strategy random|heuristic|minimax] [--board 盤面表記] [--seed 数値]";
+
+// ============================================================================
+// 型定義: コマンドラインオプション
+// ============================================================================
+struct Options {
+    ai_player: Option<Player>,  // AIが担当するプレイヤー（Noneは2人対戦）
+    strategy: AiStrategy,       // AIの戦略
+    board: Board,               // 開始局面
+    seed: Option<u64>,          // AIの乱数のシード（Noneなら起動時刻）
+}
+
+/// コマンドライン引数を解析する
+/// 学習ポイント: イテレータのnext()で「オプション名 → 値」の順に読み進める
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        ai_player: None,
+        strategy: AiStrategy::Heuristic,
+        board: GameLogic::empty_board(),
+        seed: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or_else(|| format!("{} の値がありません", arg))?;
+        match (arg.as_str(), value.to_lowercase().as_str()) {
+            ("--ai", "x") => options.ai_player = Some(Player::X),
+            ("--ai", "o") => options.ai_player = Some(Player::O),
+            ("--strategy", key) => {
+                options.strategy = AiStrategy::from_key(key)
+                    .ok_or_else(|| format!("不明なオプションです: {} {}", arg, value))?;
+            }
+            ("--board", _) => options.board = GameLogic::parse_board(&value)?,
+            ("--seed", seed) => {
+                let seed = seed.parse().map_err(|_| format!("シードは数値で指定してください: {}", value))?;
+                options.seed = Some(seed);
+            }
+            _ => return Err(format!("不明なオプションです: {} {}", arg, value)),
+        }
+    }
+
+    Ok(options)
+}
+
+// ============================================================================
+// 描画: テキストの盤面
+// ============================================================================
+
+/// セルに対応するテンキー番号（1〜9、GameLogic::cell_from_keyの逆）
+fn key_for_cell(row: usize, col: usize) -> usize {
+    (2 - row) * 3 + col + 1
+}
+
+/// 盤面をテキストで描画する（空きマスには入力に使う番号を表示）
+fn render_board(board: Board) -> String {
+    let rows: Vec<String> = (0..3)
+        .map(|row| {
+            (0..3)
+                .map(|col| match board[row][col] {
+                    Some(player) => player.symbol().to_string(),
+                    None => key_for_cell(row, col).to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" | ")
+        })
+        .collect();
+    rows.join("\n--+---+--\n")
+}
+
+// ============================================================================
+// メイン関数: 対局ループ
+// ============================================================================
+fn main() {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let mut game = match GameController::from_board(options.board) {
+        Ok(game) => game,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(2);
+        }
+    };
+
+    // シードの指定がなければ起動時刻をシードにして、毎回違う手を選ぶ
+    let seed = options.seed.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or(0)
+    });
+    let mut rng = Rng::new(seed);
+    let mut lines = io::stdin().lock().lines();
+
+    while game.game_state == GameState::Playing {
+        let (board, player) = (game.board, game.current_player);
+        println!("\n{}\n", render_board(board));
+
+        let (row, col) = if options.ai_player == Some(player) {
+            let Some(cell) = options.strategy.choose_move(board, player, WinRule::Standard, &mut rng) else {
+                break;
+            };
+            println!("AI（{}）: {}", player.symbol(), key_for_cell(cell.0, cell.1));
+            cell
+        } else {
+            print!("{}の番です（1〜9、uで一手戻す、rでやり直し、qで終了）> ", player.symbol());
+            io::stdout().flush().ok();
+
+            let Some(Ok(line)) = lines.next() else {
+                return;
+            };
+            let line = line.trim();
+            match line {
+                "q" => return,
+                // AI対戦ではAIの応手とあわせて自分の手番まで戻す
+                "u" => {
+                    let plies = game.history.undo_count(options.ai_player);
+                    if plies == 0 || !(0..plies).all(|_| game.undo()) {
+                        println!("これ以上戻せません");
+                    }
+                    continue;
+                }
+                "r" => {
+                    if !game.redo() {
+                        println!("やり直せる手がありません");
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            match GameLogic::cell_from_key(line) {
+                Some((row, col)) if GameLogic::is_valid_move(board, row, col) => (row, col),
+                _ => {
+                    println!("置けません: 1〜9の空いているマスを選んでください");
+                    continue;
+                }
+            }
+        };
+
+        if let Err(error) = game.play(row, col) {
+            println!("{}", error);
+        }
+    }
+
+    println!("\n{}\n", render_board(game.board));
+    match game.game_state.outcome() {
+        Some(Outcome::Win(winner)) => println!("{}の勝ち！", winner.symbol()),
+        Some(Outcome::Draw) => println!("引き分け！"),
+        None => {}
+    }
+}
+
+// ============================================================================
+// テスト: テキスト描画の検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_empty_board() {
+        // 空きマスはテンキー配列の番号（上段が7〜9）
+        assert_eq!(
+            render_board(GameLogic::empty_board()),
+            "7 | 8 | 9\n--+---+--\n4 | 5 | 6\n--+---+--\n1 | 2 | 3"
+        );
+    }
+
+    #[test]
+    fn test_render_board_with_pieces() {
+        let board = GameLogic::parse_board("X.O/.X./..O").unwrap();
+        assert_eq!(
+            render_board(board),
+            "X | 8 | O\n--+---+--\n4 | X | 6\n--+---+--\n1 | 2 | O"
+        );
+    }
+
+    #[test]
+    fn test_parse_seed() {
+        let args = ["--ai", "o", "--seed", "42"].map(String::from);
+        let options = parse_args(args.into_iter()).unwrap();
+        assert_eq!(options.seed, Some(42));
+        assert_eq!(parse_args(std::iter::empty()).unwrap().seed, None);
+        assert!(parse_args(["--seed", "abc"].map(String::from).into_iter()).is_err());
+    }
+
+    #[test]
+    fn test_key_for_cell_matches_cell_from_key() {
+        for row in 0..3 {
+            for col in 0..3 {
+                let key = key_for_cell(row, col).to_string();
+                assert_eq!(GameLogic::cell_from_key(&key), Some((row, col)));
+            }
+        }
+    }
+}
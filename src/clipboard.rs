@@ -0,0 +1,27 @@
+// ============================================================================
+// Dioxus学習プロジェクト: クリップボードの読み取り
+// ============================================================================
+// このファイルはクリップボードのテキストを非同期に読み取る関数を提供します。
+// 共有された盤面（絵文字グリッド）を貼り付けて読み込むために使い、Web（wasm32）でのみ動作します。
+//
+// 学習ポイント:
+// - web_sys::Clipboardが返すPromiseを、wasm_bindgen_futures::JsFutureでawaitする
+// - #[cfg]属性で、Web以外では同じAPIでエラーを返す
+
+/// クリップボードのテキストを読み取る（失敗したら表示用のエラー文）
+pub async fn read_text() -> Result<String, String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let window = web_sys::window().ok_or("ウィンドウを取得できませんでした")?;
+        let promise = window.navigator().clipboard().read_text();
+        let value = wasm_bindgen_futures::JsFuture::from(promise)
+            .await
+            .map_err(|_| "クリップボードを読み取れませんでした（貼り付けの許可を確認してください）")?;
+        value.as_string().ok_or_else(|| "クリップボードにテキストがありません".to_string())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Err("クリップボードからの読み込みはWeb版のみ対応しています".to_string())
+    }
+}
@@ -0,0 +1,125 @@
+// ============================================================================
+// Dioxus学習プロジェクト: ブリッツ（持ち時間）
+// ============================================================================
+// このファイルはチェスのような持ち時間付きの対局時計を定義します。
+// 各プレイヤーは持ち時間（基本時間）を持ち、自分の手番の間だけ減っていきます。
+// 着手を終えるたびに加算時間（インクリメント）が足され、0になったら時間切れ負けです。
+//
+// 学習ポイント:
+// - UIから切り離した状態機械（時刻の取得は呼び出し側、計算はここ）
+// - saturating_subによる0未満にならない引き算
+
+use crate::types::Player;
+
+/// 既定の持ち時間（ミリ秒）
+pub const BLITZ_BASE_MS: u64 = 30_000;
+
+/// 既定の加算時間（ミリ秒）
+pub const BLITZ_INCREMENT_MS: u64 = 2_000;
+
+// ============================================================================
+// 型定義: 対局時計
+// ============================================================================
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BlitzClock {
+    remaining_ms: [u64; 2],  // 残り時間（X・Oの順）
+    increment_ms: u64,       // 着手ごとの加算時間
+}
+
+impl BlitzClock {
+    /// 両者に同じ持ち時間と加算時間を設定する
+    pub fn new(base_ms: u64, increment_ms: u64) -> Self {
+        BlitzClock { remaining_ms: [base_ms; 2], increment_ms }
+    }
+
+    /// プレイヤーに対応する配列の位置
+    fn slot(player: Player) -> usize {
+        match player {
+            Player::X => 0,
+            Player::O => 1,
+        }
+    }
+
+    /// 指定プレイヤーの残り時間（ミリ秒）
+    pub fn remaining_ms(&self, player: Player) -> u64 {
+        self.remaining_ms[Self::slot(player)]
+    }
+
+    /// 手番のプレイヤーの時計を経過時間だけ進める（時間切れになったらtrue）
+    pub fn tick(&mut self, player: Player, elapsed_ms: u64) -> bool {
+        let remaining = &mut self.remaining_ms[Self::slot(player)];
+        *remaining = remaining.saturating_sub(elapsed_ms);
+        *remaining == 0
+    }
+
+    /// 着手を終えたプレイヤーに加算時間を足す（時間切れ後は足さない）
+    pub fn complete_move(&mut self, player: Player) {
+        let remaining = &mut self.remaining_ms[Self::slot(player)];
+        if *remaining > 0 {
+            *remaining += self.increment_ms;
+        }
+    }
+
+    /// 時間切れになったプレイヤー（まだならNone）
+    pub fn flagged(&self) -> Option<Player> {
+        [Player::X, Player::O]
+            .into_iter()
+            .find(|&player| self.remaining_ms(player) == 0)
+    }
+}
+
+impl Default for BlitzClock {
+    fn default() -> Self {
+        BlitzClock::new(BLITZ_BASE_MS, BLITZ_INCREMENT_MS)
+    }
+}
+
+/// 残り時間を「分:秒.1/10秒」の形に整形する（例: 1:05.3）
+pub fn format_clock(ms: u64) -> String {
+    format!("{}:{:02}.{}", ms / 60_000, ms / 1000 % 60, ms / 100 % 10)
+}
+
+// ============================================================================
+// テスト: 対局時計の検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_decrements_only_the_mover() {
+        let mut clock = BlitzClock::new(10_000, 1_000);
+
+        assert!(!clock.tick(Player::X, 2_500));
+        assert_eq!(clock.remaining_ms(Player::X), 7_500);
+        assert_eq!(clock.remaining_ms(Player::O), 10_000);
+
+        // 残り時間を超えて進めると0で止まり、時間切れになる
+        assert!(clock.tick(Player::X, 60_000));
+        assert_eq!(clock.remaining_ms(Player::X), 0);
+        assert_eq!(clock.flagged(), Some(Player::X));
+    }
+
+    #[test]
+    fn test_complete_move_adds_increment() {
+        let mut clock = BlitzClock::new(10_000, 1_000);
+        clock.tick(Player::O, 4_000);
+        clock.complete_move(Player::O);
+
+        assert_eq!(clock.remaining_ms(Player::O), 7_000);
+        assert_eq!(clock.remaining_ms(Player::X), 10_000);
+        assert_eq!(clock.flagged(), None);
+
+        // 時間切れの後は加算されない
+        clock.tick(Player::X, 10_000);
+        clock.complete_move(Player::X);
+        assert_eq!(clock.remaining_ms(Player::X), 0);
+    }
+
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(format_clock(65_300), "1:05.3");
+        assert_eq!(format_clock(BLITZ_BASE_MS), "0:30.0");
+        assert_eq!(format_clock(0), "0:00.0");
+    }
+}
@@ -12,7 +12,212 @@
 // - レスポンシブデザインの実装
 
 use dioxus::prelude::*;
-use crate::types::{Player, GameState};
+use crate::types::{BoardRotation, CellSize, Handicap, MarkStyle, MoveHint, MoveStatus, Outcome, Player, GameState, Score, Symmetry, SymbolTheme, WinRule};
+use crate::types::GameLogic;
+use crate::types::Board;
+use crate::puzzles::{PuzzleProgress, PUZZLES};
+use crate::ai::{AiStrategy, ADAPTIVE_LEVELS};
+use crate::history::board_after;
+use crate::stats::{FairnessTally, GameRecord, MatchSide, MatchStatus, MATCH_GAMES};
+use crate::clock::{format_clock, BlitzClock};
+use crate::timer;
+use std::collections::VecDeque;
+
+// ============================================================================
+// アセット定義（モジュール内で使用）
+// ============================================================================
+// asset!マクロを使用してコンパイル時にアセットの存在を検証
+const X_ICON: Asset = asset!("/assets/x-icon.svg");
+const O_ICON: Asset = asset!("/assets/o-icon.svg");
+
+// プレイヤーのアイコンアセットを返す
+// 学習ポイント: Asset型はUI側で扱い、ゲームロジック（types.rs）をDioxusから切り離す
+fn player_icon(player: Player) -> Asset {
+    match player {
+        Player::X => X_ICON,
+        Player::O => O_ICON,
+    }
+}
+
+// ============================================================================
+// スタイルヘルパー: プレイヤーテーマカラー
+// ============================================================================
+// プレイヤーごとの文字色クラスを返す（複数コンポーネントで共通利用）
+// 学習ポイント:
+// - 重複していたmatch式を関数に集約
+// - ハイコントラスト時はより濃い色を使用
+// - contrast-more:バリアントでOSの「コントラストを上げる」設定にも追従
+fn player_text_class(player: Player, high_contrast: bool) -> &'static str {
+    match (player, high_contrast) {
+        (Player::X, false) => "text-red-500 contrast-more:text-red-800",
+        (Player::O, false) => "text-blue-500 contrast-more:text-blue-900",
+        (Player::X, true) => "text-red-800",
+        (Player::O, true) => "text-blue-900",
+    }
+}
+
+// ============================================================================
+// スタイルヘルパー: セルの大きさ
+// ============================================================================
+// セルの大きさに応じた（ボタンの最小サイズ, 内側の箱, テキスト記号）のクラスを返す
+// 学習ポイント: 3つのクラスを1か所で対応付け、サイズ間で比率をそろえる
+fn cell_size_classes(size: CellSize) -> (&'static str, &'static str, &'static str) {
+    match size {
+        CellSize::Small => ("min-w-12 min-h-12", "w-9 h-9", "text-3xl"),
+        CellSize::Medium => ("min-w-16 min-h-16", "w-12 h-12", "text-4xl"),
+        CellSize::Large => ("min-w-24 min-h-24", "w-20 h-20", "text-6xl"),
+    }
+}
+
+// ============================================================================
+// スタイルヘルパー: アニメーション
+// ============================================================================
+// アニメーション・トランジション用のクラスを返す（「アニメーションを減らす」設定時は空）
+// 学習ポイント:
+// - 設定に応じたクラスの付け外しを1か所に集約
+// - 呼び出し側のクラスにはmotion-reduce:バリアントを含め、OSの
+//   prefers-reduced-motion設定にも追従する
+fn motion_class(reduce_motion: bool, classes: &'static str) -> &'static str {
+    if reduce_motion { "" } else { classes }
+}
+
+// ============================================================================
+// 描画ヘルパー: 駒の描画方法
+// ============================================================================
+// 学習ポイント: 「どう描くか」の判定を純粋関数にして、描画とは別にテストする
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum MarkKind {
+    Icon,                // SVGアイコン（img要素）
+    Css,                 // CSSで描いた線と輪
+    Text(&'static str),  // テキスト記号
+}
+
+// テーマ・描画方法・アイコンの読み込み状況から、駒の描き方を決める
+// 学習ポイント: アイコンの読み込みに失敗したら、テーマに関係なくテキスト記号で代替する
+fn mark_kind(theme: SymbolTheme, style: MarkStyle, player: Player, icon_failed: bool) -> MarkKind {
+    match (theme.uses_icons(), style) {
+        (true, MarkStyle::Css) => MarkKind::Css,
+        (true, MarkStyle::Svg) if !icon_failed => MarkKind::Icon,
+        _ => MarkKind::Text(theme.glyph(player)),
+    }
+}
+
+// CSSで描く駒の部品（X：交差する2本の線、O：放射グラデーションの輪）
+// 学習ポイント: 割合指定にして、盤面でもサムネイルでも同じ形に縮む
+fn css_mark_parts(player: Player) -> &'static [&'static str] {
+    match player {
+        Player::X => &[
+            "absolute left-[43%] top-[8%] w-[14%] h-[84%] rounded-full rotate-45 bg-red-500 contrast-more:bg-red-800",
+            "absolute left-[43%] top-[8%] w-[14%] h-[84%] rounded-full -rotate-45 bg-red-500 contrast-more:bg-red-800",
+        ],
+        Player::O => &[
+            "absolute inset-[10%] rounded-full bg-[radial-gradient(circle,transparent_55%,var(--color-blue-500)_57%)] contrast-more:bg-[radial-gradient(circle,transparent_55%,var(--color-blue-900)_57%)]",
+        ],
+    }
+}
+
+// ============================================================================
+// PlayerMark コンポーネント: プレイヤーの駒（記号）
+// ============================================================================
+// テーマ・描画方法に応じてSVGアイコン、CSSの図形、テキスト記号のいずれかで駒を描画するコンポーネント
+//
+// 学習ポイント:
+// - 描画方法の違いを1つのコンポーネントに閉じ込める
+// - role="img" + aria-labelでテキスト記号にも代替テキストを付与
+// - onerrorでアセットの404（配信先の設定ミス等）を検知し、テキスト記号に切り替える
+#[component]
+pub fn PlayerMark(
+    // 描画するプレイヤー
+    player: Player,
+    // 記号テーマ
+    theme: SymbolTheme,
+    // クラシックテーマの描画方法（SVG/CSS）
+    #[props(default)]
+    mark_style: MarkStyle,
+    // サイズ・効果などの共通クラス（アイコン/CSS/テキストのすべてに適用）
+    class: String,
+    // テキスト記号の文字サイズクラス
+    glyph_class: String,
+    // 代替テキスト
+    alt: String
+) -> Element {
+    // アイコンの読み込みに失敗したか
+    let mut icon_failed = use_signal(|| false);
+
+    match mark_kind(theme, mark_style, player, icon_failed()) {
+        MarkKind::Icon => rsx! {
+            img {
+                src: player_icon(player),
+                class: "object-contain {class}",
+                alt: "{alt}",
+                onerror: move |_| icon_failed.set(true)
+            }
+        },
+        MarkKind::Css => rsx! {
+            span {
+                class: "relative {class}",
+                role: "img",
+                "aria-label": "{alt}",
+                for part in css_mark_parts(player) {
+                    span { class: *part }
+                }
+            }
+        },
+        MarkKind::Text(glyph) => rsx! {
+            span {
+                class: "flex items-center justify-center leading-none select-none {glyph_class} {class}",
+                role: "img",
+                "aria-label": "{alt}",
+                {glyph}
+            }
+        },
+    }
+}
+
+// ============================================================================
+// MiniBoard コンポーネント: 盤面のサムネイル
+// ============================================================================
+// 履歴・練習問題の一覧・リプレイのタイムラインなどで使う、小さな読み取り専用の盤面
+//
+// 学習ポイント:
+// - クリック処理を持たない表示専用コンポーネント
+// - PlayerMarkを再利用し、駒の描画方法（アイコン/テキスト）をそろえる
+#[component]
+pub fn MiniBoard(
+    // 表示する盤面
+    board: Board,
+    // 記号テーマ
+    #[props(default)]
+    theme: SymbolTheme,
+    // クラシックテーマの描画方法
+    #[props(default)]
+    mark_style: MarkStyle
+) -> Element {
+    rsx! {
+        div {
+            class: "grid grid-cols-3 gap-px w-9 h-9 p-px rounded bg-slate-300",
+            role: "img",
+            "aria-label": "盤面のサムネイル",
+            for row in board {
+                for cell in row {
+                    div {
+                        class: "flex items-center justify-center bg-white",
+                        if let Some(player) = cell {
+                            PlayerMark {
+                                player,
+                                theme,
+                                mark_style,
+                                class: "w-2.5 h-2.5",
+                                glyph_class: "text-[8px] {player_text_class(player, false)}",
+                                alt: ""
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
 
 // ============================================================================
 // GameCell コンポーネント: 個別ゲームセル
@@ -35,55 +240,384 @@ pub fn GameCell(
     cell_value: Option<Player>,
     // 現在のゲーム状態
     game_state: GameState,
+    // このセルに置けるか（置けない理由を含む、GameLogic::move_statusで判定）
+    status: MoveStatus,
     // クリック時のイベントハンドラー（行、列のタプルを送信）
-    onclick: EventHandler<(usize, usize)>
+    onclick: EventHandler<(usize, usize)>,
+    // 駒を隠して描画するか（ブラインドモード用、盤面の状態自体は変えない）
+    // 学習ポイント: #[props(default)]による省略可能なプロパティ
+    #[props(default)]
+    hidden: bool,
+    // ハイコントラスト表示（単色・太い枠線）
+    #[props(default)]
+    high_contrast: bool,
+    // アニメーションを減らす（トランジション・ホバー移動を無効化）
+    #[props(default)]
+    reduce_motion: bool,
+    // 駒の記号テーマ
+    #[props(default)]
+    theme: SymbolTheme,
+    // タップで確定するときの選択中のマスか（もう一度タップすると置く）
+    #[props(default)]
+    selected: bool,
+    // クラシックテーマの描画方法
+    #[props(default)]
+    mark_style: MarkStyle,
+    // リセット直後の演出中か（対角線方向に順番に光らせる）
+    #[props(default)]
+    resetting: bool,
+    // セルの大きさ（最小サイズ・駒の大きさ）
+    #[props(default)]
+    size: CellSize,
+    // 「一手戻す」で取り除かれた直後の駒（フェードアウト表示用、盤面からはすでに消えている）
+    #[props(default)]
+    removing: Option<Player>,
+    // 現在のプレイヤー（キーボードフォーカスの枠線の色に使用、省略時は中立色）
+    #[props(default)]
+    current_player: Option<Player>,
+    // おすすめの手として強調するか（コーチモードの最初の一手）
+    #[props(default)]
+    suggested: bool,
+    // ホバー時のイベントハンドラー（入ったら座標、出たらNoneを送信、分析モード用）
+    #[props(default)]
+    onhover: Option<EventHandler<Option<(usize, usize)>>>,
+    // ここに置いた場合の評価値（ホバー中の分析モードのみ、省略時は表示なし）
+    #[props(default)]
+    preview: Option<i32>,
+    // AIがこれから置くマスとして強調するか（手の見える化）
+    #[props(default)]
+    intent: bool,
+    // 最善手順の何手目にこのマスへ置くか（1始まり、コーチ・分析モードのみ、省略時は表示なし）
+    #[props(default)]
+    marker: Option<usize>,
+    // 盤面編集モードのイベントハンドラー（座標と逆順かを送信、渡されたときだけ編集モード）
+    // 左クリックで空→X→O、右クリックで逆順に切り替える
+    #[props(default)]
+    onedit: Option<EventHandler<((usize, usize), bool)>>,
+    // 長押しで表示するヒント（コーチモードの空きセルのみ、省略時は長押ししても何もしない）
+    #[props(default)]
+    hint: Option<MoveHint>,
+    // 共有された盤面を読み込んだ直後の駒として強調するか（しばらくすると通常の表示に戻る）
+    #[props(default)]
+    loaded: bool,
+    // Tabキーでの移動順（盤面の中でフォーカスを持つセルだけ0、他は-1、省略時は0）
+    #[props(default)]
+    tabindex: i32,
+    // フォーカスを受け取ったときのイベントハンドラー（盤面のフォーカス位置の記録用）
+    #[props(default)]
+    onfocus: Option<EventHandler<()>>
 ) -> Element {
+    let (min_size_class, box_class, glyph_class) = cell_size_classes(size);
+
+    // 長押しの状態：押し始めた時刻（押していなければNone）と、ヒントを表示中か
+    // 学習ポイント: 一定時間後に「まだ同じタッチが続いているか」を時刻で確かめる
+    let mut press_started = use_signal(|| None::<u64>);
+    let mut showing_hint = use_signal(|| false);
+
+    // AIが選んだマス：置く直前まで枠を光らせる（アニメーションを減らす設定では枠だけ）
+    let intent_class = match (intent, reduce_motion) {
+        (false, _) => "",
+        (true, false) => "ring-4 ring-amber-400 animate-pulse motion-reduce:animate-none",
+        (true, true) => "ring-4 ring-amber-400",
+    };
+
+    // おすすめの手：やさしく点滅する枠で示す（アニメーションを減らす設定では枠だけ）
+    let suggest_class = match (suggested, reduce_motion) {
+        (false, _) => "",
+        (true, false) => "ring-4 ring-emerald-400 animate-pulse motion-reduce:animate-none",
+        (true, true) => "ring-4 ring-emerald-400",
+    };
+
+    // 読み込んだ直後の駒：枠を光らせ、新しく現れた駒は小さい状態から広げる（アニメーションを減らす設定では枠だけ）
+    // 学習ポイント: starting:バリアント（@starting-style）は要素が現れた瞬間だけ効くので、
+    // 読み込み前から同じ駒があったセルでは枠だけが光る
+    let (loaded_class, loaded_mark_class) = match (loaded && cell_value.is_some(), reduce_motion) {
+        (false, _) => ("", ""),
+        (true, false) => (
+            "ring-4 ring-sky-400 animate-pulse motion-reduce:animate-none",
+            "starting:scale-50 starting:opacity-0 transition duration-500 motion-reduce:transition-none",
+        ),
+        (true, true) => ("ring-4 ring-sky-400", ""),
+    };
+
+    // キーボードフォーカスの枠線：手番のプレイヤーのテーマカラーで「誰の番か」を示す
+    // 学習ポイント:
+    // - focus-visibleでキーボード操作のときだけ表示（クリックでは出さない）
+    // - outline-offsetでセルの外側に描き、明るい背景でも暗い背景（dark:）でも見えるようにする
+    let focus_class = match (current_player, high_contrast) {
+        (_, true) => "focus-visible:outline-4 focus-visible:outline-offset-2 focus-visible:outline-black",
+        (Some(Player::X), false) => "focus-visible:outline-4 focus-visible:outline-offset-2 focus-visible:outline-red-500 dark:focus-visible:outline-red-300 contrast-more:focus-visible:outline-red-800",
+        (Some(Player::O), false) => "focus-visible:outline-4 focus-visible:outline-offset-2 focus-visible:outline-blue-500 dark:focus-visible:outline-blue-300 contrast-more:focus-visible:outline-blue-900",
+        (None, false) => "focus-visible:outline-4 focus-visible:outline-offset-2 focus-visible:outline-indigo-500 dark:focus-visible:outline-indigo-300",
+    };
+
+    // リセット演出：左上からの対角線ごとに遅延をずらして光らせる
+    // 学習ポイント: row + col が同じセルは同じ対角線上にある
+    let reset_class = if resetting && !reduce_motion {
+        match row + col {
+            0 => "ring-4 ring-indigo-400 scale-95 motion-reduce:scale-100 delay-0",
+            1 => "ring-4 ring-indigo-400 scale-95 motion-reduce:scale-100 delay-75",
+            2 => "ring-4 ring-indigo-400 scale-95 motion-reduce:scale-100 delay-150",
+            3 => "ring-4 ring-indigo-400 scale-95 motion-reduce:scale-100 delay-225",
+            _ => "ring-4 ring-indigo-400 scale-95 motion-reduce:scale-100 delay-300",
+        }
+    } else {
+        ""
+    };
+
     // セルが無効（クリック不可）かどうかを判定
-    // 学習ポイント: 論理演算による状態の組み合わせ
-    let is_disabled = game_state != GameState::Playing || cell_value.is_some();
+    // 学習ポイント: 論理演算による状態の組み合わせ（編集モードでは駒のあるセルも切り替えられる）
+    let editing = onedit.is_some();
+    let is_disabled = !editing && status != MoveStatus::Ok;
+
+    // ゲーム終了により置けなくなった空きセルか（駒のあるセルとは見た目を区別する）
+    let is_locked = !editing && game_state != GameState::Playing && cell_value.is_none();
+
+    // 無効な理由（ツールチップ・読み上げ用、有効なセルではNone）
+    // 学習ポイント: is_disabledと同じMoveStatusから理由の文言を取り出す
+    let disabled_reason = if editing { None } else { status.reason() };
+
+    // 確定待ちの選択中のマス：点滅しない太い枠で「もう一度タップで置く」ことを示す
+    let selected_class = if selected { "ring-4 ring-violet-500 ring-offset-2" } else { "" };
 
     rsx! {
         // セルのボタン要素
         // 学習ポイント: 動的なクラス名生成とformat!マクロの活用
+        // 学習ポイント: (bool, bool, bool)のタプルマッチによるスタイル分岐
+        // （有効 / 駒あり / 終了後の空き × 通常 / ハイコントラスト）
         button {
             class: format!(
-                "aspect-square w-full min-w-16 min-h-16 border-2 rounded-lg flex items-center justify-center transition-all duration-200 {}",
+                "relative aspect-square w-full rounded-lg flex items-center justify-center {} {} {} {} {} {} {} {} {} {}",
+                selected_class,
+                min_size_class,
+                focus_class,
+                suggest_class,
+                intent_class,
+                loaded_class,
+                motion_class(reduce_motion, "transition-all duration-200 motion-reduce:transition-none"),
+                reset_class,
                 if is_disabled {
-                    "cursor-not-allowed bg-gradient-to-br from-slate-50 to-slate-100 border-slate-300 shadow-inner"
+                    ""
                 } else {
-                    "cursor-pointer bg-gradient-to-br from-white to-slate-50 border-slate-400 shadow-md hover:-translate-y-1 hover:shadow-lg active:translate-y-0"
+                    motion_class(reduce_motion, "hover:-translate-y-1 active:translate-y-0 motion-reduce:hover:translate-y-0")
+                },
+                // 暗い配色（dark:）ではカード（bg-slate-200）に合わせて一段暗い面にする（文字色は明るい背景向けのまま）
+                match (is_disabled, is_locked, high_contrast) {
+                    (false, _, false) => "cursor-pointer border-2 bg-gradient-to-br from-white to-slate-50 dark:from-slate-100 dark:to-slate-200 border-slate-400 shadow-md hover:shadow-lg contrast-more:bg-none contrast-more:bg-white contrast-more:border-4 contrast-more:border-black",
+                    (true, false, false) => "cursor-not-allowed border-2 bg-gradient-to-br from-slate-50 to-slate-100 dark:from-slate-200 dark:to-slate-300 border-slate-300 shadow-inner contrast-more:bg-none contrast-more:bg-slate-300 contrast-more:border-4 contrast-more:border-black",
+                    // 終了後の空きセル：破線の枠と減光で「もう置けない」ことを示す
+                    (true, true, false) => "cursor-not-allowed border-2 border-dashed bg-slate-200 border-slate-300 opacity-60 contrast-more:opacity-100 contrast-more:bg-slate-500 contrast-more:border-4 contrast-more:border-black",
+                    // ハイコントラスト：使用済みセルは灰色、空きセルは白、終了後の空きは濃い灰色で明確に区別
+                    (false, _, true) => "cursor-pointer border-4 bg-white border-black hover:bg-yellow-200",
+                    (true, false, true) => "cursor-not-allowed border-4 bg-slate-300 border-black",
+                    (true, true, true) => "cursor-not-allowed border-4 border-dashed bg-slate-500 border-black",
                 }
             ),
 
             // クリックイベントハンドリング
             // 学習ポイント: ガード条件付きイベント処理、closure moveパターン
-            onclick: move |_| if !is_disabled { onclick.call((row, col)) },
-
-            // HTML属性の設定
-            disabled: is_disabled,
+            onclick: move |_| match onedit {
+                Some(onedit) => onedit.call(((row, col), false)),
+                None if !is_disabled => onclick.call((row, col)),
+                None => {}
+            },
 
-            // セル内容の条件付きレンダリング
-            // 学習ポイント: match式によるOption<T>の処理、動的コンテンツ
-            match cell_value {
-                // プレイヤーの駒がある場合：アイコン画像を表示
-                Some(player) => rsx! {
-                    img {
-                        src: player.icon(),                           // Player enumのicon()メソッド呼び出し
-                        class: "object-contain w-12 h-12",           // 画像フィット調整とサイズ指定（大きめに）
-                        alt: format!("Player {}", player.symbol())   // アクセシビリティ対応
+            // 長押しでヒントを表示し、離したら消す（置かない）
+            // 学習ポイント:
+            // - 短いタップはprevent_defaultしないので、通常どおりonclickで駒を置ける
+            // - 長押しだったときだけtouchendでprevent_defaultし、続くクリックを発生させない
+            ontouchstart: move |_| {
+                if hint.is_none() || is_disabled {
+                    return;
+                }
+                let started = timer::now_ms();
+                press_started.set(Some(started));
+                spawn(async move {
+                    timer::sleep_ms(LONG_PRESS_MS).await;
+                    if *press_started.peek() == Some(started) {
+                        showing_hint.set(true);
                     }
-                },
-                // 空のセルの場合：透明なスペーサー
-                None => rsx! {
-                    div {
-                        class: "w-12 h-12"                          // レイアウト安定化のためのスペーサー
+                });
+            },
+            ontouchend: move |evt| {
+                if let Some(started) = press_started.take() {
+                    if is_long_press(timer::now_ms().saturating_sub(started)) {
+                        evt.prevent_default();
                     }
                 }
+                showing_hint.set(false);
+            },
+            ontouchcancel: move |_| {
+                press_started.set(None);
+                showing_hint.set(false);
+            },
+
+            // 編集モードの右クリック：メニューを出さずに逆順で切り替える
+            // 学習ポイント: 通常の対局ではprevent_defaultせず、ブラウザの右クリックメニューをそのまま使う
+            oncontextmenu: move |evt| {
+                if let Some(onedit) = onedit {
+                    evt.prevent_default();
+                    onedit.call(((row, col), true));
+                }
+            },
+
+            // ホバーで「ここに置いたら」の評価を求め、離れたら消す（置けるセルのみ）
+            // 学習ポイント: Option<EventHandler>は渡されたときだけ呼び出す
+            onmouseenter: move |_| {
+                if let (Some(onhover), false) = (onhover, is_disabled) {
+                    onhover.call(Some((row, col)));
+                }
+            },
+            onmouseleave: move |_| {
+                if let Some(onhover) = onhover {
+                    onhover.call(None);
+                }
+            },
+
+            // フォーカスを受け取ったら盤面に知らせる（クリック・Tabで移ったときも矢印キーの起点になる）
+            onfocus: move |_| {
+                if let Some(onfocus) = onfocus {
+                    onfocus.call(());
+                }
+            },
+
+            // HTML属性の設定
+            // 学習ポイント: disabledにするとフォーカスできず矢印キーで移れないため、
+            // aria-disabledで「押せない」ことだけを伝え、クリックはonclick側のガードで無視する
+            "aria-disabled": is_disabled,
+            tabindex,
+            // 盤面（role="grid"）のセルとして読み上げる（ボタンとして押せることは変わらない）
+            role: "gridcell",
+
+            // 無効な理由をツールチップと読み上げで伝える
+            // 学習ポイント: Option値の属性はNoneなら出力されない
+            title: if suggested { Some("ここから始めるのがおすすめ") } else { disabled_reason },
+            "aria-label": cell_label(row, col, cell_value, hidden, disabled_reason.or(suggested.then_some("ここから始めるのがおすすめ"))),
+            // 選択中（もう一度押すと置く）であることを読み上げで伝える（選択中のときだけ出力）
+            // 学習ポイント: gridcellの選択状態はaria-pressedではなくaria-selectedで表す
+            "aria-selected": selected.then_some("true"),
+
+            // 「ここに置いたら」の評価（駒は置かない、表示専用）
+            if let Some(evaluation) = preview {
+                span {
+                    class: "absolute top-0.5 right-0.5 px-1 rounded bg-slate-800/80 text-[10px] font-semibold text-white pointer-events-none",
+                    title: "ここに置いた場合の評価",
+                    {evaluation_label(evaluation)}
+                }
+            }
+
+            // 長押しのヒント（離すと消える、表示専用）
+            if let (true, Some(hint)) = (showing_hint(), hint) {
+                span {
+                    class: "absolute inset-x-0 -top-7 mx-auto w-max px-2 py-0.5 rounded bg-slate-800 text-xs font-semibold text-white shadow pointer-events-none z-10",
+                    role: "status",
+                    {hint.label()}
+                }
+            }
+
+            // 最善手順の番号（駒は置かない、表示専用）
+            if let Some(step) = marker {
+                span {
+                    class: "absolute bottom-0.5 left-0.5 w-4 h-4 rounded-full bg-emerald-600/90 text-[10px] font-bold leading-4 text-center text-white pointer-events-none",
+                    title: "最善手順の{step}手目",
+                    "{step}"
+                }
+            }
+
+            // 固定サイズの内側ボックス
+            // 学習ポイント: 空/駒ありで同じ箱を共有し、駒配置時のレイアウトシフトを防ぐ
+            // （shrink-0で縮まず、中身は箱いっぱいに収める）
+            div {
+                class: "{box_class} shrink-0 flex items-center justify-center pointer-events-none",
+
+                // セル内容の条件付きレンダリング
+                // 学習ポイント: match式によるOption<T>の処理、動的コンテンツ
+                // 学習ポイント: パターンガード（if !hidden）による表示制御
+                match cell_value {
+                    // プレイヤーの駒がある場合：テーマに応じた記号を表示
+                    Some(player) if !hidden => rsx! {
+                        PlayerMark {
+                            player,
+                            theme,
+                            mark_style,
+                            class: format!(                              // 箱いっぱいに表示（サイズは外側の箱で固定）
+                                "block w-full h-full {} {}",
+                                if high_contrast { "contrast-200 saturate-200" } else { "contrast-more:contrast-200" },
+                                loaded_mark_class
+                            ),
+                            glyph_class,
+                            alt: format!("Player {}", player.symbol())   // アクセシビリティ対応
+                        }
+                    },
+                    // 取り消した直後の駒：薄れて消える残像を表示（入力は妨げない）
+                    // 学習ポイント: starting:バリアント（@starting-style）で、
+                    // 表示された瞬間の不透明度から0へトランジションさせる
+                    None if removing.is_some() && !reduce_motion => rsx! {
+                        if let Some(player) = removing {
+                            PlayerMark {
+                                player,
+                                theme,
+                                mark_style,
+                                class: "block w-full h-full opacity-0 starting:opacity-100 transition-opacity duration-300 motion-reduce:hidden",
+                                glyph_class,
+                                alt: ""
+                            }
+                        }
+                    },
+                    // ゲーム終了後の空きセル：鍵アイコンで置けないことを示す
+                    None if is_locked => rsx! {
+                        span {
+                            class: "text-xl opacity-70 select-none",
+                            "aria-hidden": "true",
+                            "🔒"
+                        }
+                    },
+                    // 空のセル（または隠された駒）の場合：箱だけを表示
+                    _ => rsx! {}
+                }
             }
         }
     }
 }
 
+// セルの読み上げ：位置・中身（X / O / 空き、ブラインドモードで隠した駒は「伏せた駒」）・補足
+// 学習ポイント: ボタンのaria-labelは中の駒の代替テキストより優先されるので、中身もここに含める
+fn cell_label(row: usize, col: usize, cell_value: Option<Player>, hidden: bool, note: Option<&str>) -> String {
+    let content = match cell_value {
+        Some(_) if hidden => "伏せた駒",
+        Some(player) => player.symbol(),
+        None => "空き",
+    };
+    match note {
+        Some(note) => format!("{}行{}列 {}（{}）", row + 1, col + 1, content, note),
+        None => format!("{}行{}列 {}", row + 1, col + 1, content),
+    }
+}
+
+// 長押しとみなすまでの時間（ミリ秒）
+const LONG_PRESS_MS: u64 = 500;
+
+// 押していた時間が長押しか（短いタップは通常の着手として扱う）
+fn is_long_press(held_ms: u64) -> bool {
+    held_ms >= LONG_PRESS_MS
+}
+
+// 矢印キー・Home・Endで、表示上のフォーカス位置（0〜8）を動かす（端では止まる、対象外のキーはNone）
+// 学習ポイント: ARIAのグリッドと同じく折り返さず、Home・Endは同じ行の端へ移る
+fn move_focus(index: usize, key: &Key) -> Option<usize> {
+    let (row, col) = (index / 3, index % 3);
+    let (row, col) = match key {
+        Key::ArrowUp => (row.saturating_sub(1), col),
+        Key::ArrowDown => ((row + 1).min(2), col),
+        Key::ArrowLeft => (row, col.saturating_sub(1)),
+        Key::ArrowRight => (row, (col + 1).min(2)),
+        Key::Home => (row, 0),
+        Key::End => (row, 2),
+        _ => return None,
+    };
+    Some(row * 3 + col)
+}
+
 // ============================================================================
 // GameBoard コンポーネント: ゲーム盤面
 // ============================================================================
@@ -102,34 +636,251 @@ pub fn GameBoard(
     // 現在のゲーム状態（セルの有効/無効判定に使用）
     game_state: GameState,
     // セルクリック時のイベントハンドラー（子コンポーネントに透過的に渡す）
-    onclick: EventHandler<(usize, usize)>
+    onclick: EventHandler<(usize, usize)>,
+    // 駒を隠すセルのマスク（ブラインドモード用、省略時はすべて表示）
+    #[props(default)]
+    hidden: [[bool; 3]; 3],
+    // 「一手戻す」で取り除かれた直後の駒（フェードアウト表示用、省略時はなし）
+    #[props(default)]
+    removing: [[Option<Player>; 3]; 3],
+    // セル間の間隔（Tailwindのgap段階 0-4、省略時は通常2・コンパクト時1）
+    #[props(default)]
+    gap: Option<u8>,
+    // 余白を詰めたコンパクト表示（サイドバーのプレビュー等に使用）
+    #[props(default)]
+    compact: bool,
+    // ハイコントラスト表示（子のGameCellにも透過的に渡す）
+    #[props(default)]
+    high_contrast: bool,
+    // アニメーションを減らす（子のGameCellにも透過的に渡す）
+    #[props(default)]
+    reduce_motion: bool,
+    // 現在のプレイヤー（プレイ中は盤面の枠線をテーマカラーにする、省略時は中立色）
+    #[props(default)]
+    current_player: Option<Player>,
+    // 駒の記号テーマ（子のGameCellにも透過的に渡す）
+    #[props(default)]
+    theme: SymbolTheme,
+    // タップで確定するときの選択中のマス（省略時はなし）
+    #[props(default)]
+    selected: Option<(usize, usize)>,
+    // クラシックテーマの描画方法（子のGameCellにも透過的に渡す）
+    #[props(default)]
+    mark_style: MarkStyle,
+    // リセット直後の演出中か（子のGameCellにも透過的に渡す）
+    #[props(default)]
+    resetting: bool,
+    // セルの大きさ（子のGameCellにも透過的に渡す）
+    #[props(default)]
+    size: CellSize,
+    // 座標ラベル（列A〜C・行1〜3）を盤面の外側に表示するか
+    #[props(default)]
+    show_coordinates: bool,
+    // おすすめの手として強調するセル（コーチモードの最初の一手、省略時はなし）
+    #[props(default)]
+    suggested: Option<(usize, usize)>,
+    // セルのホバー時のイベントハンドラー（子のGameCellにも透過的に渡す）
+    #[props(default)]
+    onhover: Option<EventHandler<Option<(usize, usize)>>>,
+    // ホバー中のセルと、そこに置いた場合の評価値（分析モード用、省略時はなし）
+    #[props(default)]
+    preview: Option<((usize, usize), i32)>,
+    // AIがこれから置くマス（手の見える化、省略時はなし）
+    #[props(default)]
+    intent: Option<(usize, usize)>,
+    // 最善手順の番号（line_markersで作る、コーチ・分析モード用、省略時はなし）
+    #[props(default)]
+    markers: [[Option<usize>; 3]; 3],
+    // 表示の向き（見た目だけ回転し、セルには盤面上の座標を渡す、省略時は回転なし）
+    #[props(default)]
+    rotation: BoardRotation,
+    // 盤面編集モードのイベントハンドラー（子のGameCellにも透過的に渡す、省略時は通常の対局）
+    #[props(default)]
+    onedit: Option<EventHandler<((usize, usize), bool)>>,
+    // 空きセルの長押しで着手のヒントを見せるか（コーチモード用、手番のプレイヤーで判定）
+    #[props(default)]
+    hints: bool,
+    // ヒントの判定に使う勝利ラインのルール（省略時は標準）
+    #[props(default)]
+    rule: WinRule,
+    // 共有された盤面を読み込んだ直後に強調するセル（子のGameCellに渡す、省略時はなし）
+    #[props(default)]
+    loaded: [[bool; 3]; 3]
 ) -> Element {
-    rsx! {
+    // フォーカスを持つセルの表示上の位置（0〜8、左上から行優先）
+    // 学習ポイント: ローヴィングtabindex。Tabで盤面に入るとこのセルに止まり、盤面の中は矢印キーで移る
+    let mut focused = use_signal(|| 0usize);
+
+    // 間隔クラスの決定
+    // 学習ポイント: TailwindCSSは完全なクラス名を検出するため、
+    // format!で組み立てずにmatch式でリテラルのクラス名を選ぶ
+    let gap_class = match gap.unwrap_or(if compact { 1 } else { 2 }) {
+        0 => "gap-0",
+        1 => "gap-1",
+        2 => "gap-2",
+        3 => "gap-3",
+        _ => "gap-4",
+    };
+
+    // 余白・サイズのクラス（デフォルトは従来の見た目そのまま）
+    // 大きいセルでは余白を詰め、画面に収まらない分はカード側でスクロールさせる
+    let layout_class = match (compact, size) {
+        (true, _) => "mb-2 p-1 rounded-lg w-full max-w-[min(80vw,80vh)]",
+        (false, CellSize::Small) => "mb-4 p-3 rounded-xl w-64 max-w-[min(80vw,80vh)]",
+        (false, CellSize::Medium) => "mb-4 p-3 rounded-xl w-80 max-w-[min(80vw,80vh)]",
+        (false, CellSize::Large) => "mb-2 p-2 rounded-xl w-[22rem]",
+    };
+
+    // 配色クラス（ハイコントラスト時は単色・太枠）
+    let color_class = if high_contrast {
+        "border-4 bg-black border-black"
+    } else {
+        "border-2 bg-gradient-to-br from-slate-100 to-slate-200 dark:from-slate-300 dark:to-slate-400 contrast-more:bg-none contrast-more:bg-black contrast-more:border-black"
+    };
+
+    // 枠線の色：プレイ中は手番のプレイヤーのテーマカラー、終了後は中立色
+    // 学習ポイント: 複数の値をタプルにまとめたmatch
+    let turn_border_class = match (high_contrast, game_state, current_player) {
+        (true, _, _) => "",
+        (false, GameState::Playing, Some(Player::X)) => "border-red-400",
+        (false, GameState::Playing, Some(Player::O)) => "border-blue-400",
+        _ => "border-slate-400",
+    };
+
+    let grid = rsx! {
         // ゲーム盤面のコンテナ
         // 学習ポイント: CSS Grid + TailwindCSSによるレスポンシブレイアウト
         div {
-            class: "grid grid-cols-3 gap-2 mb-4 mx-auto aspect-square p-3 rounded-xl shadow-lg border-2 bg-gradient-to-br from-slate-100 to-slate-200 border-slate-400 w-80 max-w-[min(80vw,80vh)]",
+            class: format!(
+                "grid grid-cols-3 mx-auto aspect-square shadow-lg {} {} {} {}",
+                gap_class, layout_class, color_class, turn_border_class
+            ),
+            // 支援技術には3行3列の表（grid → row → gridcell）として伝える
+            role: "grid",
+            "aria-label": "盤面",
+
+            // 矢印キーによるセル間の移動（ロービングタブインデックス）
+            // 学習ポイント: 矢印キーは表示上の位置で動かし、フォーカスはDOM上の同じ順番のセルに移す
+            "data-roving-grid": true,
+            onkeydown: move |evt: KeyboardEvent| {
+                if let Some(next) = move_focus(focused(), &evt.key()) {
+                    evt.prevent_default();
+                    focused.set(next);
+                    document::eval(&format!(
+                        "document.activeElement?.closest('[data-roving-grid]')?.querySelectorAll('[role=gridcell]')[{next}]?.focus()"
+                    ));
+                }
+            },
 
             // ネストしたループによる9個のセル生成
             // 学習ポイント:
-            // - Rustのrange記法（0..3）
+            // - Rustのrange記法（0..9を行・列に分解）
             // - 2次元配列のインデックスアクセス
             // - コンポーネントの動的生成
-            for row in 0..3 {
-                for col in 0..3 {
-                    GameCell {
-                        row,                           // 行インデックス
-                        col,                           // 列インデックス
-                        cell_value: board[row][col],   // 該当セルの値
-                        game_state,                    // ゲーム状態（透過的に渡す）
-                        onclick                        // イベントハンドラー（透過的に渡す）
+            // - 表示位置を盤面上の位置に変換してから描くので、クリックは本来のマスに届く
+            // - 行ごとにrole="row"の要素で囲む（display: contentsなのでグリッドの配置は変わらない）
+            for display_row in 0..3 {
+                div {
+                    role: "row",
+                    class: "contents",
+                    for (index, (row, col)) in (display_row * 3..display_row * 3 + 3)
+                        .map(|index| (index, rotation.to_logical(index / 3, index % 3)))
+                    {
+                        GameCell {
+                            row,                           // 行インデックス
+                            col,                           // 列インデックス
+                            cell_value: board[row][col],   // 該当セルの値
+                            game_state,                    // ゲーム状態（透過的に渡す）
+                            status: GameLogic::move_status(board, game_state, row, col),  // 置けるかと、その理由
+                            onclick,                       // イベントハンドラー（透過的に渡す）
+                            hidden: hidden[row][col],      // 駒を隠すかどうか
+                            high_contrast,                 // ハイコントラスト表示（透過的に渡す）
+                            reduce_motion,                 // アニメーション設定（透過的に渡す）
+                            theme,                         // 記号テーマ（透過的に渡す）
+                            selected: selected == Some((row, col)),  // 確定待ちの選択中のマス
+                            mark_style,                    // 描画方法（透過的に渡す）
+                            resetting,                     // リセット演出（透過的に渡す）
+                            size,                          // セルの大きさ（透過的に渡す）
+                            removing: removing[row][col],  // 取り消した駒の残像
+                            current_player,                // フォーカス枠の色（透過的に渡す）
+                            suggested: suggested == Some((row, col)),  // おすすめの手
+                            onhover,                       // ホバー（透過的に渡す）
+                            preview: preview.filter(|&(cell, _)| cell == (row, col)).map(|(_, e)| e),  // 評価のプレビュー
+                            intent: intent == Some((row, col)),  // AIが置こうとしているマス
+                            marker: markers[row][col],     // 最善手順の番号
+                            onedit,                        // 盤面編集（透過的に渡す）
+                            loaded: loaded[row][col],      // 読み込んだ直後の強調
+                            tabindex: if index == focused() { 0 } else { -1 },  // フォーカスを持つセルだけTabで止まる
+                            onfocus: move |_| focused.set(index),  // クリック・Tabで移った位置を記録
+                            hint: current_player
+                                .filter(|_| hints && game_state == GameState::Playing)
+                                .and_then(|player| GameLogic::explain_move_with(board, row, col, player, rule))  // 長押しのヒント
+                        }
                     }
                 }
             }
         }
+    };
+
+    if !show_coordinates {
+        return grid;
+    }
+
+    // 座標ラベルを盤面の余白（padding・下マージン）に合わせてずらすクラス
+    // 学習ポイント: ラベルは表示専用（pointer-events-none）でクリックを妨げない
+    let (column_pad, row_pad) = match (compact, size) {
+        (true, _) => ("px-1", "pt-1 pb-3"),
+        (false, CellSize::Large) => ("px-2", "pt-2 pb-4"),
+        (false, _) => ("px-3", "pt-3 pb-7"),
+    };
+    let (column_labels, row_labels) = coordinate_labels(rotation);
+
+    rsx! {
+        div {
+            class: "grid grid-cols-[auto_1fr] gap-x-1 w-fit mx-auto",
+
+            // 左上の空き
+            div {}
+
+            // 上側のラベル（回転なしでは列A〜C）
+            div {
+                class: "grid grid-cols-3 {column_pad} text-xs text-center text-slate-400 select-none pointer-events-none",
+                "aria-hidden": "true",
+                for label in column_labels {
+                    span { "{label}" }
+                }
+            }
+
+            // 左側のラベル（回転なしでは行1〜3）
+            div {
+                class: "grid grid-rows-3 items-center {row_pad} text-xs text-slate-400 select-none pointer-events-none",
+                "aria-hidden": "true",
+                for label in row_labels {
+                    span { "{label}" }
+                }
+            }
+
+            {grid}
+        }
     }
 }
 
+// 盤面の外側に並べる座標ラベル（上側・左側、表示の向きに合わせて並べ替える）
+// 学習ポイント: 90度・270度では表示上の列が盤面上の行になるので、数字と文字が入れ替わる
+fn coordinate_labels(rotation: BoardRotation) -> ([&'static str; 3], [&'static str; 3]) {
+    const COLUMNS: [&str; 3] = ["A", "B", "C"];
+    const ROWS: [&str; 3] = ["1", "2", "3"];
+    let top = std::array::from_fn(|col| {
+        let (r, c) = rotation.to_logical(0, col);
+        if rotation.swaps_axes() { ROWS[r] } else { COLUMNS[c] }
+    });
+    let left = std::array::from_fn(|row| {
+        let (r, c) = rotation.to_logical(row, 0);
+        if rotation.swaps_axes() { COLUMNS[c] } else { ROWS[r] }
+    });
+    (top, left)
+}
+
 // ============================================================================
 // GameStatus コンポーネント: ゲーム状態表示
 // ============================================================================
@@ -146,13 +897,107 @@ pub fn GameStatus(
     // 現在のプレイヤー（ターン表示に使用）
     current_player: Player,
     // ゲーム状態（表示内容の分岐に使用）
-    game_state: GameState
+    game_state: GameState,
+    // ハイコントラスト表示（単色背景・太い枠線・濃い文字色）
+    #[props(default)]
+    high_contrast: bool,
+    // 理論上勝ちが決まったプレイヤー（「勝勢」の表示に使用）
+    #[props(default)]
+    decided: Option<Player>,
+    // アニメーションを減らす（勝利時のバウンス等を無効化）
+    #[props(default)]
+    reduce_motion: bool,
+    // 駒の記号テーマ
+    #[props(default)]
+    theme: SymbolTheme,
+    // クラシックテーマの描画方法
+    #[props(default)]
+    mark_style: MarkStyle,
+    // 対戦AIが担当するプレイヤー（2人対戦ではNone）
+    #[props(default)]
+    ai_player: Option<Player>,
+    // コンパクト表示（案内文とターン切り替えバーを省き、アイコンだけを小さく表示）
+    #[props(default)]
+    compact: bool,
+    // 対局結果の累計（ターン切り替えバーに各プレイヤーの勝利数と引き分け数を表示）
+    #[props(default)]
+    score: Score
 ) -> Element {
+    // 手番・勝敗の案内文（色に頼らず記号の文字も含める）と文字サイズ（AI対戦時は大きく表示）
+    let status = status_text(game_state, current_player, ai_player, theme);
+    let turn_size = if ai_player.is_some() { "text-xl font-bold" } else { "text-lg font-semibold" };
+
+    // AIの思考中は案内文を点滅させる
+    let thinking_class = if ai_player == Some(current_player) {
+        motion_class(reduce_motion, "animate-pulse motion-reduce:animate-none")
+    } else {
+        ""
+    };
+
+    // コンパクト表示：手番か勝者の記号（引き分けは🤝）だけを出し、案内文は読み上げ用のラベルに回す
+    // 学習ポイント: 見た目の文字を消しても、role="status"とaria-labelで支援技術には伝える
+    if compact {
+        let icon_player = match game_state {
+            GameState::Playing => Some(current_player),
+            GameState::Won(player) => Some(player),
+            GameState::Draw => None,
+        };
+        return rsx! {
+            div {
+                class: "mb-1 flex items-center justify-center",
+                role: "status",
+                "aria-label": "{status}",
+                title: "{status}",
+                if let Some(player) = icon_player {
+                    PlayerMark {
+                        player,
+                        theme,
+                        mark_style,
+                        class: format!("w-6 h-6 {}",
+                            if game_state == GameState::Playing { thinking_class }
+                            else { motion_class(reduce_motion, "animate-bounce motion-reduce:animate-none") }
+                        ),
+                        glyph_class: "text-xl",
+                        alt: "{status}"
+                    }
+                } else {
+                    span { class: "text-xl", "aria-hidden": "true", "🤝" }
+                }
+            }
+        };
+    }
+
+    // パネルの配色クラス（結果に応じて背景を変える：勝者のテーマカラー／引き分けはグレー）
+    // 学習ポイント: (状態, 設定)のタプルでmatchし、リテラルのクラス名を選ぶ
+    let panel_class = match (game_state, high_contrast) {
+        (_, true) => "border-2 bg-white border-black",
+        (GameState::Playing, false) => "border bg-gradient-to-br from-blue-50 to-indigo-50 border-indigo-200 contrast-more:bg-none contrast-more:bg-white contrast-more:border-2 contrast-more:border-black",
+        (GameState::Won(Player::X), false) => "border bg-gradient-to-br from-red-50 to-rose-100 border-red-300 contrast-more:bg-none contrast-more:bg-white contrast-more:border-2 contrast-more:border-black",
+        (GameState::Won(Player::O), false) => "border bg-gradient-to-br from-blue-100 to-sky-200 border-blue-300 contrast-more:bg-none contrast-more:bg-white contrast-more:border-2 contrast-more:border-black",
+        (GameState::Draw, false) => "border bg-gradient-to-br from-gray-50 to-gray-200 border-gray-300 contrast-more:bg-none contrast-more:bg-white contrast-more:border-2 contrast-more:border-black",
+    };
+
     rsx! {
+        // ターン切り替えバー（X / O を横並びで表示し、間に引き分け数を置く）
+        // 学習ポイント: 同じコンポーネントをプレイヤーだけ変えて並べる対称的なUI
+        div {
+            class: "mb-2 flex items-stretch justify-center gap-2",
+
+            TurnSwitcherSlot { player: Player::X, current_player, game_state, high_contrast, reduce_motion, theme, mark_style, wins: score.x_wins }
+            span {
+                class: format!("self-center text-xs tabular-nums {}",
+                    if high_contrast { "text-black" } else { "text-slate-500 contrast-more:text-black" }
+                ),
+                title: "引き分け",
+                "🤝 {score.draws}"
+            }
+            TurnSwitcherSlot { player: Player::O, current_player, game_state, high_contrast, reduce_motion, theme, mark_style, wins: score.o_wins }
+        }
+
         // ステータス表示のコンテナ
         // 学習ポイント: カード風スタイリング、中央揃えレイアウト
         div {
-            class: "mb-3 p-2 rounded-lg flex items-center justify-center gap-2 border bg-gradient-to-br from-blue-50 to-indigo-50 border-indigo-200",
+            class: format!("mb-3 p-2 rounded-lg flex items-center justify-center gap-2 {}", panel_class),
 
             // ゲーム状態に応じた表示内容の分岐
             // 学習ポイント:
@@ -162,19 +1007,32 @@ pub fn GameStatus(
             match game_state {
                 // ゲーム進行中：現在のプレイヤーを表示
                 GameState::Playing => rsx! {
-                    img {
-                        src: current_player.icon(),              // 現在プレイヤーのアイコン
-                        class: "object-contain w-8 h-8",        // 画像フィット調整
+                    PlayerMark {
+                        player: current_player,                  // 現在プレイヤーの記号
+                        theme,
+                        mark_style,
+                        class: "w-8 h-8",                        // 表示サイズ
+                        glyph_class: "text-2xl",
                         alt: format!("Player {}", current_player.symbol())
                     }
                     span {
-                        class: format!("text-lg font-semibold {}",
-                            match current_player {
-                                Player::X => "text-red-500",
-                                Player::O => "text-blue-500",
-                            }
+                        class: format!("{} {} {}",
+                            turn_size,
+                            player_text_class(current_player, high_contrast),
+                            thinking_class
                         ),
-                        "現在のプレイヤー"
+                        "{status}"
+                    }
+
+                    // 勝勢の通知（最善を尽くしても負けが避けられない局面）
+                    // 学習ポイント: if letによるOptionの条件付きレンダリング
+                    if let Some(leader) = decided {
+                        span {
+                            class: format!("ml-2 px-2 py-0.5 rounded-full text-xs font-bold bg-amber-100 {}",
+                                player_text_class(leader, high_contrast)
+                            ),
+                            {format!("{} 勝勢", leader.symbol())}
+                        }
                     }
                 },
 
@@ -185,31 +1043,35 @@ pub fn GameStatus(
                 // - animate-ping: 拡大パルス効果
                 // - animate-spin: 回転アニメーション
                 GameState::Won(player) => rsx! {
-                    img {
-                        src: player.icon(),                      // 勝者のアイコン
-                        class: "object-contain animate-bounce w-8 h-8",
+                    PlayerMark {
+                        player,                                  // 勝者の記号
+                        theme,
+                        mark_style,
+                        class: format!("w-8 h-8 {}",
+                            motion_class(reduce_motion, "animate-bounce motion-reduce:animate-none")
+                        ),
                         // 他のアニメーション例:
-                        // class: "object-contain animate-pulse w-8 h-8",
-                        // class: "object-contain animate-ping w-8 h-8",
-                        // class: "object-contain animate-spin w-8 h-8",
+                        // class: "w-8 h-8 animate-pulse",
+                        // class: "w-8 h-8 animate-ping",
+                        // class: "w-8 h-8 animate-spin",
+                        glyph_class: "text-2xl",
                         alt: format!("Winner {}", player.symbol())
                     }
                     span {
                         class: format!("text-xl font-bold {}",   // 強調フォントと勝者テーマカラー
-                            match player {
-                                Player::X => "text-red-500",
-                                Player::O => "text-blue-500",
-                            }
+                            player_text_class(player, high_contrast)
                         ),
-                        "勝利！"
+                        "{status}"
                     }
                 },
 
                 // 引き分け状態：中立的な表示
                 GameState::Draw => rsx! {
                     span {
-                        class: "text-xl font-bold text-gray-500", // 強調フォントとグレー色（中立）
-                        "🤝 引き分け!"                         // 絵文字で親しみやすく
+                        class: format!("text-xl font-bold {}",    // 強調フォントとグレー色（中立）
+                            if high_contrast { "text-black" } else { "text-gray-500 contrast-more:text-black" }
+                        ),
+                        "{status}"                             // 絵文字で親しみやすく（🤝 引き分け!）
                     }
                 }
             }
@@ -217,42 +1079,1670 @@ pub fn GameStatus(
     }
 }
 
+// 状態表示の案内文（手番・勝者の記号を必ず含め、色の違いだけに頼らない）
+// 学習ポイント: ガード付きmatchでOption<Player>と現在の手番を比較
+fn status_text(game_state: GameState, current_player: Player, ai_player: Option<Player>, theme: SymbolTheme) -> String {
+    let glyph = theme.glyph(current_player);
+    match (game_state, ai_player) {
+        (GameState::Playing, Some(ai)) if ai == current_player => format!("AIの番です（{}）", glyph),
+        (GameState::Playing, Some(_)) => format!("あなたの番です（{}）", glyph),
+        (GameState::Playing, None) => format!("{}の番です", glyph),
+        (GameState::Won(player), _) => format!("{}の勝利！", theme.glyph(player)),
+        (GameState::Draw, _) => "🤝 引き分け!".to_string(),
+    }
+}
+
 // ============================================================================
-// ResetButton コンポーネント: ゲームリセットボタン
+// TurnSwitcherSlot コンポーネント: ターン切り替えバーの1枠
 // ============================================================================
-// ゲームを初期状態にリセットするためのアクションボタン
+// ターン切り替えバー内で1人のプレイヤーを表示するコンポーネント
 //
 // 学習ポイント:
-// - シンプルなアクションコンポーネントパターン
-// - EventHandler<()>による引数なしイベント処理
-// - アクションボタンのUI/UXデザイン
-// - アイコン + テキストの組み合わせレイアウト
-// - ホバー効果（CSS）との連携
+// - 複数の状態（手番・勝敗）から見た目を導出するパターン
+// - match式のガード条件による状態の組み合わせ判定
 #[component]
-pub fn ResetButton(
-    // クリック時のイベントハンドラー（引数なし）
-    onclick: EventHandler<()>
+fn TurnSwitcherSlot(
+    // この枠が表すプレイヤー
+    player: Player,
+    // 現在のプレイヤー（ハイライト判定に使用）
+    current_player: Player,
+    // ゲーム状態（終了時は勝者を強調、敗者を減光）
+    game_state: GameState,
+    // ハイコントラスト表示
+    high_contrast: bool,
+    // アニメーションを減らす
+    reduce_motion: bool,
+    // 駒の記号テーマ
+    theme: SymbolTheme,
+    // クラシックテーマの描画方法
+    mark_style: MarkStyle,
+    // このプレイヤーの勝利数
+    wins: u32
 ) -> Element {
+    // 枠の強調状態を判定
+    // 学習ポイント: ifガードで「勝者かどうか」「手番かどうか」を分岐
+    let emphasis = match game_state {
+        GameState::Playing if player == current_player => "scale-110 opacity-100 shadow-md",
+        GameState::Playing => "scale-95 opacity-50",
+        GameState::Won(winner) if winner == player => "scale-110 opacity-100 shadow-md",
+        GameState::Won(_) => "scale-90 opacity-30 grayscale",
+        GameState::Draw => "scale-95 opacity-70",
+    };
+
+    // プレイヤーテーマカラーの枠線
+    let border = match (player, high_contrast) {
+        (_, true) => "border-black",
+        (Player::X, false) => "border-red-400 contrast-more:border-black",
+        (Player::O, false) => "border-blue-400 contrast-more:border-black",
+    };
+
     rsx! {
-        // リセットボタン
-        // 学習ポイント:
-        // - フルワイズレイアウト（w-full）
-        // - カスタムCSSクラス（reset-button）との連携
-        // - TailwindCSSとインラインスタイルのハイブリッド
-        button {
-            class: "w-full text-white font-bold py-2 px-4 rounded-lg mt-4 flex items-center justify-center gap-2 text-base bg-gradient-to-br from-blue-700 to-indigo-800 shadow-blue-500/30 shadow-lg transition-all duration-200 hover:from-blue-800 hover:to-indigo-900 hover:shadow-blue-500/50 hover:shadow-xl border-none",
+        div {
+            class: format!(
+                "flex-1 flex items-center justify-center gap-1 py-1 rounded-lg border-2 bg-white {} {} {}",
+                motion_class(reduce_motion, "transition-all duration-200 motion-reduce:transition-none"),
+                border, emphasis
+            ),
 
-            // クリックイベントハンドリング
-            // 学習ポイント: move closureによるイベント処理、引数の無視（_）
-            onclick: move |_| onclick.call(()),
+            PlayerMark {
+                player,
+                theme,
+                mark_style,
+                class: "w-6 h-6",
+                glyph_class: "text-lg",
+                alt: format!("Player {}", player.symbol())
+            }
+            span {
+                class: format!("text-sm font-bold {}",
+                    player_text_class(player, high_contrast)
+                ),
+                {player.symbol()}
+            }
+            span {
+                class: format!("text-xs tabular-nums {}",
+                    if high_contrast { "text-black" } else { "text-slate-600 contrast-more:text-black" }
+                ),
+                "{wins}勝"
+            }
+        }
+    }
+}
 
-            // ボタン内容：アイコン + テキスト
-            // 学習ポイント:
-            // - 絵文字アイコンの活用
-            // - flexレイアウトによる要素配置
-            // - セマンティックな構造（span要素の使い分け）
-            span { "🔄" }                    // リセットアイコン（絵文字）
-            span { "新しいゲーム" }          // ボタンテキスト
+/// 評価値（正ならX有利）を形勢の言葉で表す
+fn evaluation_label(evaluation: i32) -> &'static str {
+    match evaluation {
+        e if e > 0 => "X有利",
+        e if e < 0 => "O有利",
+        _ => "互角",
+    }
+}
+
+// 評価値をXの優勢の割合（0〜100%）に換算する
+// 学習ポイント: 評価値の最大（最短の5手目で勝った場合）を100%とし、
+// ハンディキャップの局面ではより早く勝てて最大を超えるので、0〜100%に収める
+fn evaluation_share(evaluation: i32) -> i32 {
+    (50 + evaluation * 50 / GameLogic::MAX_EVALUATION).clamp(0, 100)
+}
+
+// ============================================================================
+// EvaluationBar コンポーネント: 形勢（評価値）バー
+// ============================================================================
+// 最善手順での評価値をXの割合（左・赤）とOの割合（右・青）で表示する横棒
+//
+// 学習ポイント:
+// - 数値から幅（%）を計算し、インラインスタイルで指定する
+// - transitionで幅の変化をアニメーションさせる
+#[component]
+pub fn EvaluationBar(
+    // 評価値（GameLogic::evaluate、正ならX有利）
+    evaluation: i32,
+    // アニメーションを減らす
+    #[props(default)]
+    reduce_motion: bool
+) -> Element {
+    let x_share = evaluation_share(evaluation);
+    let label = evaluation_label(evaluation);
+
+    rsx! {
+        div {
+            class: "mb-3 flex items-center gap-2 text-xs font-semibold text-slate-600",
+            span { "評価" }
+            div {
+                class: "flex-1 h-3 rounded-full overflow-hidden bg-blue-500 contrast-more:bg-blue-900",
+                role: "meter",
+                "aria-label": "評価: {label}",
+                "aria-valuemin": 0,
+                "aria-valuemax": 100,
+                "aria-valuenow": x_share,
+                div {
+                    class: format!("h-full bg-red-500 contrast-more:bg-red-800 {}",
+                        motion_class(reduce_motion, "transition-all duration-500 motion-reduce:transition-none")
+                    ),
+                    style: "width: {x_share}%"
+                }
+            }
+            span { class: "w-12 text-right", "{label}" }
+        }
+    }
+}
+
+// ============================================================================
+// PrincipalVariation コンポーネント: 最善手順（読み筋）
+// ============================================================================
+// 現在の局面から両者が最善を尽くした手順と、その結末を1行で表示する
+//
+// 学習ポイント:
+// - 重い探索は呼び出し側（use_memo）で行い、表示だけを受け持つ
+// - 文字列の組み立てを関数に分けてテストしやすくする
+#[component]
+pub fn PrincipalVariation(
+    // 最善手順（GameLogic::principal_variation）
+    line: Vec<(usize, usize)>,
+    // 最初に指すプレイヤー
+    to_move: Player,
+    // 手順を最後まで進めたときのゲーム状態
+    result: GameState
+) -> Element {
+    if line.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        p {
+            class: "mb-3 text-xs text-slate-600 tabular-nums",
+            span { class: "font-semibold", "最善手順: " }
+            {format_line(&line, to_move, result)}
+        }
+    }
+}
+
+// 最善手順を盤面上の番号（1始まり）に並べ替える（手順にないマスはNone）
+// 学習ポイント: 手順の文字列と盤面の番号を同じ手順から作るので、両者が食い違わない
+pub fn line_markers(line: &[(usize, usize)]) -> [[Option<usize>; 3]; 3] {
+    let mut markers = [[None; 3]; 3];
+    for (step, &(row, col)) in line.iter().enumerate() {
+        markers[row][col] = Some(step + 1);
+    }
+    markers
+}
+
+// 手順を「X: (1,1), O: (0,0), …, 引き分け」の形式の文字列にする
+fn format_line(line: &[(usize, usize)], to_move: Player, result: GameState) -> String {
+    let mut player = to_move;
+    let mut parts: Vec<String> = line
+        .iter()
+        .map(|&(row, col)| {
+            let part = format!("{}: ({},{})", player.symbol(), row, col);
+            player = player.next();
+            part
+        })
+        .collect();
+
+    match result {
+        GameState::Won(winner) => parts.push(format!("{}の勝ち", winner.symbol())),
+        GameState::Draw => parts.push("引き分け".to_string()),
+        GameState::Playing => {}
+    }
+    parts.join(", ")
+}
+
+// ============================================================================
+// SymmetryBadge コンポーネント: 盤面の対称性
+// ============================================================================
+// 盤面を変えない対称変換（恒等変換を除く）を「対称: 縦軸・横軸」の形で表示する小さなバッジ
+//
+// 学習ポイント: 表示する対称性がなければ何も描画しない
+#[component]
+pub fn SymmetryBadge(
+    // 盤面を変えない対称変換（GameLogic::symmetries）
+    symmetries: Vec<Symmetry>
+) -> Element {
+    let labels: Vec<&str> = symmetries
+        .iter()
+        .filter(|&&symmetry| symmetry != Symmetry::Identity)
+        .map(|symmetry| symmetry.label())
+        .collect();
+    if labels.is_empty() {
+        return rsx! {};
+    }
+    let text = labels.join("・");
+
+    rsx! {
+        p {
+            class: "mb-3 flex justify-center",
+            span {
+                class: "px-2 py-0.5 rounded-full text-xs font-semibold bg-violet-100 text-violet-800 border border-violet-200",
+                "対称: {text}"
+            }
         }
     }
 }
+
+// ============================================================================
+// ScoreBadge コンポーネント: ヘッダーのスコア表示
+// ============================================================================
+// タイトル横に表示する小さなスコアバッジ（まだ対局がなければ非表示）
+//
+// 学習ポイント:
+// - 早期リターン（rsx! {}）による非表示パターン
+// - 構造体プロパティの受け取り
+#[component]
+pub fn ScoreBadge(
+    // 対局結果の累計
+    score: Score,
+    // 直前に増えたカウンターと通し番号（「+1」の演出用、省略時は演出なし）
+    // 学習ポイント: 通し番号をkeyにすると、同じカウンターが続けて増えても要素が作り直されて再生される
+    #[props(default)]
+    bump: Option<(Outcome, u32)>
+) -> Element {
+    if score.is_empty() {
+        return rsx! {};
+    }
+
+    // カウンターごとの（結果, 表示, 色クラス）
+    let counters = [
+        (Outcome::Win(Player::X), format!("X: {}", score.x_wins), "text-red-500"),
+        (Outcome::Win(Player::O), format!("O: {}", score.o_wins), "mx-1 text-blue-500"),
+        (Outcome::Draw, format!("引分: {}", score.draws), ""),
+    ];
+
+    rsx! {
+        span {
+            class: "px-2 py-0.5 rounded-full text-xs font-semibold tabular-nums bg-slate-100 text-slate-600 border border-slate-200",
+            for (outcome, text, color) in counters {
+                span {
+                    key: "{outcome:?}",
+                    class: "relative {color}",
+                    "{text}"
+                    if let Some((_, serial)) = bump.filter(|&(bumped, _)| bumped == outcome) {
+                        span {
+                            key: "{serial}",
+                            class: "absolute -top-3 right-0 text-[10px] font-bold text-emerald-600 pointer-events-none animate-bounce motion-reduce:hidden",
+                            "aria-hidden": "true",
+                            "+1"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// ResetButton コンポーネント: ゲームリセットボタン
+// ============================================================================
+// ゲームを初期状態にリセットするためのアクションボタン
+//
+// 学習ポイント:
+// - シンプルなアクションコンポーネントパターン
+// - EventHandler<()>による引数なしイベント処理
+// - アクションボタンのUI/UXデザイン
+// - アイコン + テキストの組み合わせレイアウト
+// - ホバー効果（CSS）との連携
+#[component]
+pub fn ResetButton(
+    // クリック時のイベントハンドラー（引数なし）
+    onclick: EventHandler<()>,
+    // 初めての終局後に、ボタンを指す案内を表示するか
+    #[props(default)]
+    pointer: bool,
+    // アニメーションを減らす（案内の矢印を動かさない）
+    #[props(default)]
+    reduce_motion: bool
+) -> Element {
+    rsx! {
+        // 初回の案内（ボタンの直前に通常の流れで置くので、結果バナー等と重ならない）
+        if pointer {
+            p {
+                class: "mt-3 -mb-2 flex items-center justify-center gap-1 text-xs font-semibold text-indigo-700",
+                role: "status",
+                span {
+                    class: motion_class(reduce_motion, "animate-bounce motion-reduce:animate-none"),
+                    "aria-hidden": "true",
+                    "👇"
+                }
+                "ここから新しいゲームを始められます"
+            }
+        }
+
+        // リセットボタン
+        // 学習ポイント:
+        // - フルワイズレイアウト（w-full）
+        // - カスタムCSSクラス（reset-button）との連携
+        // - TailwindCSSとインラインスタイルのハイブリッド
+        button {
+            class: "w-full text-white font-bold py-2 px-4 rounded-lg mt-4 flex items-center justify-center gap-2 text-base bg-gradient-to-br from-blue-700 to-indigo-800 shadow-blue-500/30 shadow-lg transition-all duration-200 hover:from-blue-800 hover:to-indigo-900 hover:shadow-blue-500/50 hover:shadow-xl border-none",
+
+            // クリックイベントハンドリング
+            // 学習ポイント: move closureによるイベント処理、引数の無視（_）
+            onclick: move |_| onclick.call(()),
+
+            // ボタン内容：アイコン + テキスト
+            // 学習ポイント:
+            // - 絵文字アイコンの活用
+            // - flexレイアウトによる要素配置
+            // - セマンティックな構造（span要素の使い分け）
+            span { "🔄" }                    // リセットアイコン（絵文字）
+            span { "新しいゲーム" }          // ボタンテキスト
+        }
+    }
+}
+
+// ============================================================================
+// 操作ヘルパー: フォーカストラップ
+// ============================================================================
+// ダイアログ等の中でTab・Shift+Tabを押したとき、フォーカスをその中だけで循環させる
+// 学習ポイント:
+// - フォーカスできる要素の一覧はDOMから取るため、document::evalでJavaScriptを実行する
+// - 末尾から先頭（Shift+Tabでは先頭から末尾）へ折り返す
+pub fn trap_focus(container_id: &str, backwards: bool) {
+    document::eval(&format!(
+        r#"(() => {{
+            const root = document.getElementById("{container_id}");
+            if (!root) return;
+            const items = [...root.querySelectorAll("button, select, input, [tabindex]:not([tabindex='-1'])")]
+                .filter((el) => !el.disabled);
+            if (items.length === 0) return;
+            const index = items.indexOf(document.activeElement);
+            const step = {step};
+            items[(index + step + items.length) % items.length].focus();
+        }})()"#,
+        step = if backwards { -1 } else { 1 },
+    ));
+}
+
+// ============================================================================
+// SettingsDrawer コンポーネント: 設定のドロワー
+// ============================================================================
+// 歯車ボタンから開く、画面右端から滑り出る設定パネル
+// 中身（各設定のトグル等）は呼び出し側からchildrenとして渡す
+//
+// 学習ポイント:
+// - children: Elementで任意の中身を受け取るコンテナコンポーネント
+// - 背景のクリック・Escapeキー・閉じるボタンのどれでも閉じられる
+// - 開いたらパネルにフォーカスを移し、Tabはパネル内で循環させ（trap_focus）、
+//   閉じたら開いたボタンにフォーカスを戻す
+// - starting:バリアントで、表示された瞬間の位置（画面外）から滑り込ませる
+#[component]
+pub fn SettingsDrawer(
+    // 開いているか
+    open: bool,
+    // 閉じるときのイベントハンドラー
+    onclose: EventHandler<()>,
+    // 閉じた後にフォーカスを戻す要素のid（歯車ボタン）
+    // 学習ポイント: &'static strにすると、下のcloseクロージャがCopyになり複数のハンドラーで使える
+    return_focus: &'static str,
+    // アニメーションを減らす
+    #[props(default)]
+    reduce_motion: bool,
+    // ドロワーの中身
+    children: Element
+) -> Element {
+    if !open {
+        return rsx! {};
+    }
+
+    // 閉じてから、開いたボタンにフォーカスを戻す
+    let close = move || {
+        onclose.call(());
+        document::eval(&format!("document.getElementById(\"{}\")?.focus()", return_focus));
+    };
+
+    rsx! {
+        // 背景（クリックで閉じる）
+        div {
+            class: format!(
+                "fixed inset-0 z-40 bg-slate-900/40 {}",
+                motion_class(reduce_motion, "transition-opacity duration-300 starting:opacity-0 motion-reduce:transition-none")
+            ),
+            "aria-hidden": "true",
+            onclick: move |_| close()
+        }
+
+        // パネル本体
+        aside {
+            id: SETTINGS_DRAWER_ID,
+            class: format!(
+                "fixed inset-y-0 right-0 z-50 w-72 max-w-[85vw] overflow-y-auto p-4 bg-white dark:bg-slate-200 shadow-2xl outline-none {}",
+                motion_class(reduce_motion, "transition-transform duration-300 starting:translate-x-full motion-reduce:transition-none")
+            ),
+            role: "dialog",
+            "aria-modal": "true",
+            "aria-label": "設定",
+            tabindex: -1,
+            onmounted: move |evt| async move {
+                let _ = evt.set_focus(true).await;
+            },
+            // ゲーム側のキー操作（数字キーでの着手など）に伝えない
+            onkeydown: move |evt: KeyboardEvent| {
+                evt.stop_propagation();
+                match evt.key() {
+                    Key::Escape => {
+                        evt.prevent_default();
+                        close();
+                    }
+                    Key::Tab => {
+                        evt.prevent_default();
+                        trap_focus(SETTINGS_DRAWER_ID, evt.modifiers().shift());
+                    }
+                    _ => {}
+                }
+            },
+
+            div {
+                class: "mb-3 flex items-center justify-between",
+                h2 { class: "text-base font-bold text-slate-700", "設定" }
+                button {
+                    class: "px-2 py-1 rounded-full text-sm text-slate-500 hover:bg-slate-100",
+                    "aria-label": "設定を閉じる",
+                    onclick: move |_| close(),
+                    "✕"
+                }
+            }
+            {children}
+        }
+    }
+}
+
+/// 設定ドロワーのid（フォーカストラップの対象）
+const SETTINGS_DRAWER_ID: &str = "settings-drawer";
+
+// ============================================================================
+// DrawOffer コンポーネント: 引き分けの提案
+// ============================================================================
+// 2人対戦で、どちらかが引き分けを提案し、相手が受けるか断るかを選ぶ操作欄
+//
+// 学習ポイント:
+// - Option<Player>の有無で「提案ボタン」と「返事のボタン」を切り替える
+// - 返事はbool（受ける=true）の1つのイベントにまとめる
+#[component]
+pub fn DrawOffer(
+    // 引き分けを提案しているプレイヤー（提案がなければNone）
+    offer: Option<Player>,
+    // 提案時のイベントハンドラー（提案したプレイヤーを送信）
+    onoffer: EventHandler<Player>,
+    // 返事のイベントハンドラー（受けるならtrue、断るならfalse）
+    onrespond: EventHandler<bool>
+) -> Element {
+    let button_class = "flex-1 px-3 py-1 rounded-lg border border-slate-300 bg-white text-sm text-slate-700 hover:bg-slate-100";
+
+    rsx! {
+        div {
+            class: "mb-3 flex flex-wrap items-center gap-2",
+            match offer {
+                Some(player) => rsx! {
+                    p {
+                        class: "w-full text-sm font-semibold text-slate-700",
+                        role: "status",
+                        "{player.symbol()}が引き分けを提案しています（{player.next().symbol()}の返事）"
+                    }
+                    button {
+                        class: "flex-1 px-3 py-1 rounded-lg bg-emerald-600 text-sm font-semibold text-white hover:bg-emerald-700",
+                        onclick: move |_| onrespond.call(true),
+                        "受ける"
+                    }
+                    button {
+                        class: button_class,
+                        onclick: move |_| onrespond.call(false),
+                        "断る"
+                    }
+                },
+                None => rsx! {
+                    for player in [Player::X, Player::O] {
+                        button {
+                            class: button_class,
+                            onclick: move |_| onoffer.call(player),
+                            "{player.symbol()}が引き分けを提案"
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+// ============================================================================
+// LiveRegion コンポーネント: 読み上げ専用のお知らせ
+// ============================================================================
+// 画面には表示せず、スクリーンリーダーにだけメッセージを読み上げさせる領域
+//
+// 学習ポイント:
+// - aria-live="polite"で、読み上げ中の内容を遮らずに変化を伝える
+// - sr-onlyで見た目からは隠し、支援技術からは読める状態にする
+// - 同じ文言が続いても読み上げられるよう、serialをkeyにして要素ごと差し替える
+#[component]
+pub fn LiveRegion(
+    // 読み上げるメッセージ（空なら何も読み上げない）
+    message: String,
+    // お知らせの通し番号（変わるたびに読み上げ直す）
+    #[props(default)]
+    serial: u32
+) -> Element {
+    rsx! {
+        div {
+            class: "sr-only",
+            role: "status",
+            "aria-live": "polite",
+            "aria-atomic": "true",
+            span { key: "{serial}", "{message}" }
+        }
+    }
+}
+
+// ============================================================================
+// SettingToggle コンポーネント: 設定切り替えボタン
+// ============================================================================
+// ON/OFFを切り替えるモード設定用の小さなトグルボタン
+//
+// 学習ポイント:
+// - bool値プロパティによる見た目の切り替え
+// - aria-pressed属性によるトグル状態のアクセシビリティ対応
+// - 汎用コンポーネントとしての再利用
+#[component]
+pub fn SettingToggle(
+    // ボタンに表示するラベル
+    label: String,
+    // 現在ON（有効）かどうか
+    enabled: bool,
+    // 切り替えられない状態か（省略時は切り替え可能）
+    #[props(default)]
+    disabled: bool,
+    // クリック時のイベントハンドラー（引数なし）
+    onclick: EventHandler<()>
+) -> Element {
+    rsx! {
+        button {
+            class: format!(
+                "px-3 py-1 rounded-full text-xs font-semibold border transition-colors duration-150 disabled:opacity-40 {}",
+                if enabled {
+                    "bg-indigo-600 text-white border-indigo-700"
+                } else {
+                    "bg-white text-slate-600 border-slate-300 hover:bg-slate-100"
+                }
+            ),
+            "aria-pressed": enabled,
+            disabled,
+            onclick: move |_| onclick.call(()),
+
+            // ON/OFF状態をテキストでも表示
+            span { "{label}: " }
+            span { if enabled { "ON" } else { "OFF" } }
+        }
+    }
+}
+
+// ============================================================================
+// MusicControls コンポーネント: BGMの設定
+// ============================================================================
+// BGMのON/OFFと音量スライダーをまとめたコンポーネント
+//
+// 学習ポイント:
+// - 既存のSettingToggleの再利用
+// - input type="range"の値（文字列）を数値に変換して通知
+#[component]
+pub fn MusicControls(
+    // BGMがONかどうか
+    enabled: bool,
+    // 音量（0〜100）
+    volume: u8,
+    // ON/OFF切り替えのイベントハンドラー
+    ontoggle: EventHandler<()>,
+    // 音量変更のイベントハンドラー
+    onvolume: EventHandler<u8>
+) -> Element {
+    rsx! {
+        div {
+            class: "flex items-center gap-1",
+            SettingToggle {
+                label: "BGM",
+                enabled,
+                onclick: move |_| ontoggle.call(())
+            }
+            input {
+                class: "w-20 accent-indigo-600 disabled:opacity-40",
+                r#type: "range",
+                min: "0",
+                max: "100",
+                value: "{volume}",
+                disabled: !enabled,
+                "aria-label": "BGMの音量",
+                oninput: move |evt: FormEvent| {
+                    if let Ok(volume) = evt.value().parse::<u8>() {
+                        onvolume.call(volume);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// PuzzleSelector コンポーネント: 練習問題の選択
+// ============================================================================
+// プリセット局面をドロップダウンで選び、目標と結果を表示するコンポーネント
+//
+// 学習ポイント:
+// - select/option要素とonchangeイベント（FormEvent）
+// - 文字列からの数値パース（parse::<usize>()）とOption化
+// - 進行状況enumに応じたメッセージの出し分け
+#[component]
+pub fn PuzzleSelector(
+    // 選択中の問題（PUZZLESのインデックス）
+    selected: Option<usize>,
+    // 選択中の問題の進行状況
+    progress: Option<PuzzleProgress>,
+    // 選択変更時のイベントハンドラー（「選ばない」はNone）
+    onselect: EventHandler<Option<usize>>
+) -> Element {
+    rsx! {
+        div {
+            class: "mt-3 flex flex-col gap-1",
+
+            select {
+                class: "w-full px-2 py-1 rounded-lg border border-slate-300 bg-white text-sm",
+                // 学習ポイント: valueが空文字なら「選択なし」としてNoneになる
+                onchange: move |evt: FormEvent| onselect.call(evt.value().parse::<usize>().ok()),
+
+                option { value: "", selected: selected.is_none(), "練習問題を選ぶ…" }
+                for (index, puzzle) in PUZZLES.iter().enumerate() {
+                    option {
+                        value: "{index}",
+                        selected: selected == Some(index),
+                        {puzzle.title}
+                    }
+                }
+            }
+
+            // 目標の説明
+            if let Some(index) = selected {
+                p {
+                    class: "text-sm text-center text-slate-700",
+                    {PUZZLES[index].goal_text()}
+                }
+            }
+
+            // 結果メッセージ
+            match progress {
+                Some(PuzzleProgress::Solved) => rsx! {
+                    p { class: "text-sm text-center font-bold text-green-600", "🎉 正解！" }
+                },
+                Some(PuzzleProgress::Failed) => rsx! {
+                    p { class: "text-sm text-center font-bold text-red-600", "❌ 目標を達成できませんでした" }
+                },
+                _ => rsx! {}
+            }
+        }
+    }
+}
+
+// ============================================================================
+// AiSelector コンポーネント: 対戦AIの設定
+// ============================================================================
+// AIが担当するプレイヤー（なし＝2人対戦）と、AIの戦略を選ぶコンポーネント
+//
+// 学習ポイント:
+// - 文字列のvalueとOption<Player>の相互変換
+// - Vecプロパティから選択肢を生成し、インデックスで選択結果を返す
+// - 値を持つ戦略（腕前）はバリアントの種類で選択中かを判定し、値はスライダーで変える
+#[component]
+pub fn AiSelector(
+    // AIが担当するプレイヤー（Noneは2人対戦）
+    ai_player: Option<Player>,
+    // 現在の戦略
+    strategy: AiStrategy,
+    // 選択できる戦略の一覧
+    strategies: Vec<AiStrategy>,
+    // 担当プレイヤー変更時のイベントハンドラー
+    onplayer: EventHandler<Option<Player>>,
+    // 戦略変更時のイベントハンドラー
+    onstrategy: EventHandler<AiStrategy>
+) -> Element {
+    // 学習ポイント: クロージャに渡すためにVecを複製しておく
+    let choices = strategies.clone();
+
+    rsx! {
+        label {
+            class: "flex items-center gap-1 text-xs font-semibold text-slate-600",
+            "AI:"
+            select {
+                class: "px-2 py-1 rounded-full border border-slate-300 bg-white",
+                onchange: move |evt: FormEvent| onplayer.call(match evt.value().as_str() {
+                    "X" => Some(Player::X),
+                    "O" => Some(Player::O),
+                    _ => None,
+                }),
+
+                option { value: "", selected: ai_player.is_none(), "なし（2人対戦）" }
+                for player in [Player::X, Player::O] {
+                    option {
+                        value: "{player.symbol()}",
+                        selected: ai_player == Some(player),
+                        "{player.symbol()}を担当"
+                    }
+                }
+            }
+        }
+
+        if ai_player.is_some() {
+            label {
+                class: "flex items-center gap-1 text-xs font-semibold text-slate-600",
+                "強さ:"
+                select {
+                    class: "px-2 py-1 rounded-full border border-slate-300 bg-white",
+                    onchange: move |evt: FormEvent| {
+                        if let Some(selected) = evt.value().parse::<usize>().ok().and_then(|i| choices.get(i)) {
+                            onstrategy.call(selected.clone());
+                        }
+                    },
+
+                    for (index, choice) in strategies.iter().enumerate() {
+                        option {
+                            value: "{index}",
+                            selected: std::mem::discriminant(choice) == std::mem::discriminant(&strategy),
+                            {choice.label()}
+                        }
+                    }
+                }
+            }
+
+            // 腕前：最善手を指す割合（10%刻み）
+            if let AiStrategy::Skill(percent) = strategy {
+                label {
+                    class: "flex items-center gap-1 text-xs font-semibold text-slate-600",
+                    input {
+                        class: "w-20 accent-indigo-600",
+                        r#type: "range",
+                        min: "0",
+                        max: "100",
+                        step: "10",
+                        value: "{percent}",
+                        "aria-label": "最善手を指す割合",
+                        oninput: move |evt: FormEvent| {
+                            if let Ok(percent) = evt.value().parse::<u8>() {
+                                onstrategy.call(AiStrategy::Skill(percent));
+                            }
+                        }
+                    }
+                    "{percent}%"
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// AdaptiveDifficulty コンポーネント: 強さの自動調整
+// ============================================================================
+// 自動調整のON/OFFと、行き来する強さの範囲（最弱・最強）を選ぶコンポーネント
+//
+// 学習ポイント:
+// - 既存のSettingToggleの再利用
+// - 範囲の下限が上限を超えないよう、選んだ側に合わせてもう一方を寄せる
+#[component]
+pub fn AdaptiveDifficulty(
+    // 自動調整がONかどうか
+    enabled: bool,
+    // 現在の強さ
+    level: AiStrategy,
+    // 行き来する範囲（ADAPTIVE_LEVELSのインデックス、両端を含む）
+    bounds: (usize, usize),
+    // ON/OFF切り替えのイベントハンドラー
+    ontoggle: EventHandler<()>,
+    // 範囲変更のイベントハンドラー
+    onbounds: EventHandler<(usize, usize)>
+) -> Element {
+    let (min, max) = bounds;
+
+    rsx! {
+        div {
+            class: "flex flex-wrap items-center gap-1 text-xs font-semibold text-slate-600",
+            SettingToggle {
+                label: "強さの自動調整",
+                enabled,
+                onclick: move |_| ontoggle.call(())
+            }
+
+            if enabled {
+                span {
+                    "aria-live": "polite",
+                    "現在: {level.label()}"
+                }
+                select {
+                    class: "px-2 py-1 rounded-full border border-slate-300 bg-white",
+                    "aria-label": "自動調整の最弱",
+                    onchange: move |evt: FormEvent| {
+                        if let Ok(index) = evt.value().parse::<usize>() {
+                            onbounds.call((index, max.max(index)));
+                        }
+                    },
+                    for (index, choice) in ADAPTIVE_LEVELS.iter().enumerate() {
+                        option { value: "{index}", selected: index == min, {choice.label()} }
+                    }
+                }
+                "〜"
+                select {
+                    class: "px-2 py-1 rounded-full border border-slate-300 bg-white",
+                    "aria-label": "自動調整の最強",
+                    onchange: move |evt: FormEvent| {
+                        if let Ok(index) = evt.value().parse::<usize>() {
+                            onbounds.call((min.min(index), index));
+                        }
+                    },
+                    for (index, choice) in ADAPTIVE_LEVELS.iter().enumerate() {
+                        option { value: "{index}", selected: index == max, {choice.label()} }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// ResultBanner コンポーネント: 対局結果のバナー
+// ============================================================================
+// 対局終了時にカード下部へ固定表示し、再戦・共有の操作をまとめたバナー
+//
+// 学習ポイント:
+// - sticky配置でスクロールしてもカード下端に表示し、盤面は覆わない
+// - コンポーネント内のローカル状態（「コピーしました」の表示）
+#[component]
+pub fn ResultBanner(
+    // 終了したゲーム状態
+    game_state: GameState,
+    // 「もう一度」ボタンのイベントハンドラー
+    onrematch: EventHandler<()>,
+    // 共有（結果のコピー）ボタンのイベントハンドラー
+    onshare: EventHandler<()>,
+    // 閉じるボタンのイベントハンドラー
+    ondismiss: EventHandler<()>
+) -> Element {
+    let mut copied = use_signal(|| false);
+
+    let message = match game_state {
+        GameState::Won(player) => format!("🎉 {}の勝ち！", player.symbol()),
+        GameState::Draw => "🤝 引き分け".to_string(),
+        GameState::Playing => String::new(),
+    };
+
+    rsx! {
+        div {
+            class: "sticky bottom-0 mt-3 p-3 rounded-lg shadow-lg bg-indigo-700 text-white flex flex-wrap items-center justify-center gap-2 landscape:col-span-2 contrast-more:bg-black",
+            role: "status",
+
+            span { class: "text-lg font-bold", "{message}" }
+
+            button {
+                class: "px-3 py-1 rounded-full bg-white text-indigo-700 text-sm font-semibold hover:bg-indigo-100",
+                onclick: move |_| onrematch.call(()),
+                "もう一度"
+            }
+            button {
+                class: "px-3 py-1 rounded-full border border-white text-sm font-semibold hover:bg-indigo-600",
+                onclick: move |_| {
+                    onshare.call(());
+                    copied.set(true);
+                },
+                if copied() { "コピーしました" } else { "結果を共有" }
+            }
+            button {
+                class: "px-2 py-1 rounded-full text-sm hover:bg-indigo-600",
+                "aria-label": "閉じる",
+                onclick: move |_| ondismiss.call(()),
+                "✕"
+            }
+        }
+    }
+}
+
+// ============================================================================
+// RecordingControls コンポーネント: 手順の記録
+// ============================================================================
+// 片方のプレイヤーの着手を記録し、「過去の自分」との対戦に使うためのコンポーネント
+//
+// 学習ポイント:
+// - Option<Player>による「記録中かどうか・どちらを記録中か」の表現
+// - タプル型のプロパティ（記録済みの側と手数）
+#[component]
+pub fn RecordingControls(
+    // 記録中のプレイヤー（記録していなければNone）
+    recording: Option<Player>,
+    // 記録済みの手順（記録したプレイヤーと手数）
+    saved: Option<(Player, usize)>,
+    // 記録の開始（Some）・中止（None）のイベントハンドラー
+    onrecord: EventHandler<Option<Player>>
+) -> Element {
+    rsx! {
+        div {
+            class: "mt-3 flex flex-wrap items-center justify-center gap-2 text-xs font-semibold text-slate-600",
+
+            match recording {
+                Some(player) => rsx! {
+                    span { class: "text-red-600", "● {player.symbol()}の手順を記録中" }
+                    button {
+                        class: "px-3 py-1 rounded-full border border-slate-300 bg-white hover:bg-slate-100",
+                        onclick: move |_| onrecord.call(None),
+                        "中止"
+                    }
+                },
+                None => rsx! {
+                    for player in [Player::X, Player::O] {
+                        button {
+                            class: "px-3 py-1 rounded-full border border-slate-300 bg-white hover:bg-slate-100",
+                            onclick: move |_| onrecord.call(Some(player)),
+                            "{player.symbol()}の手順を記録"
+                        }
+                    }
+                }
+            }
+
+            // 記録済みの手順
+            if let Some((player, moves)) = saved {
+                span { "記録済み: {player.symbol()}・{moves}手" }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// ReplayViewer コンポーネント: 対局の再生
+// ============================================================================
+// 記録された着手列を1手ずつ進めたり戻したりして確認するコンポーネント
+//
+// 学習ポイント:
+// - コンポーネント内部のローカル状態（表示中の手数）
+// - 着手列から盤面を導出して既存のGameBoardで描画する
+#[component]
+pub fn ReplayViewer(
+    // 再生する対局の開始局面と最初の手番
+    start: (Board, Player),
+    // 再生する着手列
+    moves: Vec<(usize, usize)>,
+    // 駒の記号テーマ
+    #[props(default)]
+    theme: SymbolTheme,
+    // クラシックテーマの描画方法
+    #[props(default)]
+    mark_style: MarkStyle,
+    // ハイコントラスト表示
+    #[props(default)]
+    high_contrast: bool,
+    // 各局面の終局判定に使う勝利ラインのルール（省略時は標準）
+    #[props(default)]
+    rule: WinRule,
+    // GIFで保存ボタンのイベントハンドラー（Noneならボタンを表示しない）
+    #[props(default)]
+    onexport: Option<EventHandler<()>>,
+    // 閉じるボタンのイベントハンドラー
+    onclose: EventHandler<()>
+) -> Element {
+    // 表示中の手数（最初は最終局面）
+    // 学習ポイント: 表示中に別の対局に差し替わっても範囲外にならないようmin()で丸める
+    let mut step = use_signal(|| moves.len());
+    let total = moves.len();
+    let current = step().min(total);
+    let board = board_after(start, &moves, current);
+
+    // 操作ボタン（ラベルと移動先の手数）
+    let controls = [("⏮", 0), ("◀", current.saturating_sub(1)), ("▶", (current + 1).min(total)), ("⏭", total)];
+
+    rsx! {
+        div {
+            class: "mt-3 p-2 rounded-lg border border-slate-300 bg-slate-50 dark:border-slate-400 dark:bg-slate-100",
+
+            p {
+                class: "mb-1 text-sm text-center font-semibold text-slate-700",
+                "前の対局 {current}/{total}手目"
+            }
+
+            GameBoard {
+                board,
+                game_state: GameLogic::check_game_state_with(board, rule),
+                onclick: move |_| {},
+                compact: true,
+                rule,
+                high_contrast,
+                theme,
+                mark_style
+            }
+
+            // タイムライン：各手の局面のサムネイル（クリックでその手へ移動）
+            div {
+                class: "flex gap-1 mb-2 overflow-x-auto",
+                for target in 1..=total {
+                    button {
+                        class: if target == current { "rounded ring-2 ring-indigo-400" } else { "rounded opacity-70 hover:opacity-100" },
+                        "aria-label": "{target}手目",
+                        onclick: move |_| step.set(target),
+                        MiniBoard { board: board_after(start, &moves, target), theme, mark_style }
+                    }
+                }
+            }
+
+            div {
+                class: "flex justify-center gap-2",
+                for (label, target) in controls {
+                    button {
+                        class: "px-3 py-1 rounded-full border border-slate-300 bg-white text-sm hover:bg-slate-100 disabled:opacity-40",
+                        disabled: target == current,
+                        onclick: move |_| step.set(target),
+                        "{label}"
+                    }
+                }
+                if let Some(onexport) = onexport {
+                    button {
+                        class: "px-3 py-1 rounded-full border border-slate-300 bg-white text-sm hover:bg-slate-100",
+                        onclick: move |_| onexport.call(()),
+                        "GIFで保存"
+                    }
+                }
+                button {
+                    class: "px-3 py-1 rounded-full border border-slate-300 bg-white text-sm hover:bg-slate-100",
+                    onclick: move |_| onclose.call(()),
+                    "閉じる"
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// RecentGamesPanel コンポーネント: 最近の対局履歴
+// ============================================================================
+// 直近の対局結果を新しい順に一覧表示する折りたたみパネル
+//
+// 学習ポイント:
+// - details/summary要素によるJavaScript不要の折りたたみ
+// - iter().rev()で新しい順に並べ替えて表示
+#[component]
+pub fn RecentGamesPanel(
+    // 最近の対局（古い順）
+    games: VecDeque<GameRecord>,
+    // 履歴クリアボタンのイベントハンドラー
+    onclear: EventHandler<()>
+) -> Element {
+    rsx! {
+        details {
+            class: "mt-3 p-2 rounded-lg border border-slate-300 bg-slate-50 dark:border-slate-400 dark:bg-slate-100 text-sm text-slate-700",
+            summary {
+                class: "cursor-pointer font-semibold",
+                "最近の対局（{games.len()}件）"
+            }
+
+            if games.is_empty() {
+                p { class: "mt-1 text-xs text-slate-500 dark:text-slate-600", "まだ対局がありません" }
+            } else {
+                ul {
+                    class: "mt-1 flex flex-col gap-0.5",
+                    for game in games.iter().rev() {
+                        li {
+                            class: "flex justify-between",
+                            span { class: "font-semibold", {game.label()} }
+                            span { class: "text-xs text-slate-500 dark:text-slate-600", "{game.moves}手・{game.duration_secs}秒" }
+                        }
+                    }
+                }
+                button {
+                    class: "mt-2 px-3 py-1 rounded-full border border-slate-300 bg-white dark:bg-slate-100 text-xs hover:bg-slate-100 dark:hover:bg-slate-200",
+                    onclick: move |_| onclear.call(()),
+                    "履歴クリア"
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// FairMatchPanel コンポーネント: 公平な対戦モードの成績
+// ============================================================================
+// 今回の先手と、先手・後手それぞれの勝利数を表示するパネル
+//
+// 学習ポイント:
+// - 集計済みの値（FairnessTally）を受け取って表示に専念する
+// - 勝ち越し数の符号で表示する文言を切り替える
+#[component]
+pub fn FairMatchPanel(
+    // 先手・後手の成績
+    tally: FairnessTally,
+    // 今回の対局で人間が先手か
+    human_starts: bool,
+    // AIが担当するプレイヤー（2人対戦ではNone）
+    ai_player: Option<Player>,
+    // 3局勝負の状況（対戦していなければNone）
+    #[props(default)]
+    status: Option<MatchStatus>
+) -> Element {
+    // 対戦する側の呼び名（Aは1局目の先手）
+    let side_name = |side: MatchSide| match (ai_player, side) {
+        (Some(_), MatchSide::A) => "あなた",
+        (Some(_), MatchSide::B) => "AI",
+        (None, MatchSide::A) => "プレイヤーA",
+        (None, MatchSide::B) => "プレイヤーB",
+    };
+
+    // 今回の先手（X）を担当する側
+    let starter = match (ai_player, human_starts) {
+        (Some(_), true) => "あなた",
+        (Some(_), false) => "AI",
+        (None, true) => "プレイヤーA",
+        (None, false) => "プレイヤーB",
+    };
+
+    // 先手・後手の有利不利の傾向
+    let trend = match tally.starter_lead() {
+        0 => "先手・後手の差はありません".to_string(),
+        lead if lead > 0 => format!("先手が{}勝多く勝っています", lead),
+        lead => format!("後手が{}勝多く勝っています", -lead),
+    };
+
+    rsx! {
+        div {
+            class: "mt-3 p-2 rounded-lg border border-slate-300 bg-slate-50 dark:border-slate-400 dark:bg-slate-100 text-sm text-slate-700",
+            p { class: "font-semibold", "この対局の先手（X）: {starter}" }
+            p {
+                class: "mt-1 tabular-nums",
+                "先手の勝ち {tally.starter_wins}・後手の勝ち {tally.second_wins}・引き分け {tally.draws}"
+            }
+            if tally.games() > 0 {
+                p { class: "text-xs text-slate-500", "{trend}" }
+            }
+
+            // 3局勝負の状況（同点なら延長戦、決着したら勝者）
+            match status {
+                Some(MatchStatus::InProgress) => rsx! {
+                    p { class: "mt-1 text-xs text-slate-500 tabular-nums", "{MATCH_GAMES}局勝負: {tally.games()}局目まで終了" }
+                },
+                Some(MatchStatus::SuddenDeath) => rsx! {
+                    p { class: "mt-1 font-semibold text-amber-700", role: "status", "同点のためサドンデス（次に勝った側の勝ち）" }
+                },
+                Some(MatchStatus::Decided(side)) => rsx! {
+                    p { class: "mt-1 font-semibold text-emerald-700", role: "status", "🏆 {side_name(side)}の勝ち越し" }
+                },
+                None => rsx! {},
+            }
+        }
+    }
+}
+
+// ============================================================================
+// PieceCountBadges コンポーネント: プレイヤーごとの駒の数
+// ============================================================================
+// X・Oがそれぞれ何個置いたかを2つのバッジで表示する（手番の偶奇を学ぶ教材用）
+//
+// 学習ポイント:
+// - 盤面から導出した値（GameLogic::counts）だけを表示し、別の状態を持たない
+// - そのためリセットや一手戻すでも自動的に正しい値になる
+#[component]
+pub fn PieceCountBadges(
+    // X・Oそれぞれの駒の数（GameLogic::counts）
+    counts: (usize, usize),
+    // ハイコントラスト表示（単色の枠線・濃い文字色）
+    #[props(default)]
+    high_contrast: bool
+) -> Element {
+    let (x_count, o_count) = counts;
+    let (x_class, o_class) = if high_contrast {
+        ("border-2 border-black bg-white text-black", "border-2 border-black bg-white text-black")
+    } else {
+        ("border border-red-200 bg-red-50 text-red-600", "border border-blue-200 bg-blue-50 text-blue-600")
+    };
+
+    rsx! {
+        div {
+            class: "mb-3 flex items-center justify-center gap-2 text-xs font-semibold tabular-nums",
+            "aria-label": "置いた駒の数: X {x_count}個、O {o_count}個",
+            span { class: "px-2 py-0.5 rounded-full {x_class}", "X: {x_count}個" }
+            span { class: "px-2 py-0.5 rounded-full {o_class}", "O: {o_count}個" }
+            span { class: "text-slate-500", "計{x_count + o_count}手" }
+        }
+    }
+}
+
+// ============================================================================
+// BlitzClocks コンポーネント: ブリッツの対局時計
+// ============================================================================
+// X・Oそれぞれの残り時間を並べ、手番の側を強調し、時間切れを示す
+//
+// 学習ポイント:
+// - 時計の計算はclockモジュール、ここは表示だけを受け持つ
+// - tabular-numsで数字の幅をそろえ、時間が減っても表示が揺れないようにする
+#[component]
+pub fn BlitzClocks(
+    // 対局時計
+    clock: BlitzClock,
+    // 現在のプレイヤー（時計が動いている側）
+    current_player: Player,
+    // 現在のゲーム状態（終局後は強調しない）
+    game_state: GameState
+) -> Element {
+    let flagged = clock.flagged();
+
+    rsx! {
+        div {
+            class: "mb-3 grid grid-cols-2 gap-2 text-sm font-semibold tabular-nums",
+            role: "timer",
+            for player in [Player::X, Player::O] {
+                div {
+                    key: "{player:?}",
+                    class: match (flagged == Some(player), game_state == GameState::Playing && player == current_player) {
+                        (true, _) => "px-2 py-1 rounded-lg text-center border-2 border-rose-500 bg-rose-50 text-rose-700",
+                        (false, true) => "px-2 py-1 rounded-lg text-center border-2 border-slate-700 bg-white text-slate-900",
+                        (false, false) => "px-2 py-1 rounded-lg text-center border border-slate-300 bg-slate-50 text-slate-500",
+                    },
+                    "{player.symbol()}  {format_clock(clock.remaining_ms(player))}"
+                    if flagged == Some(player) {
+                        span { class: "ml-1 text-xs", "時間切れ" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// AutoResetSelector コンポーネント: 自動リセット設定
+// ============================================================================
+// 対局終了後に自動で新しいゲームを始めるまでの秒数を選ぶコンポーネント
+//
+// 学習ポイント:
+// - 選択肢を配列で定義してforループで生成
+// - Option<u32>と文字列のvalueの相互変換
+#[component]
+pub fn AutoResetSelector(
+    // 現在の設定（Noneはオフ）
+    secs: Option<u32>,
+    // 設定変更時のイベントハンドラー
+    onchange: EventHandler<Option<u32>>
+) -> Element {
+    rsx! {
+        label {
+            class: "flex items-center gap-1 text-xs font-semibold text-slate-600",
+            "自動リセット:"
+            select {
+                class: "px-2 py-1 rounded-full border border-slate-300 bg-white",
+                onchange: move |evt: FormEvent| onchange.call(evt.value().parse::<u32>().ok()),
+
+                option { value: "", selected: secs.is_none(), "オフ" }
+                for choice in [3, 5, 10] {
+                    option {
+                        value: "{choice}",
+                        selected: secs == Some(choice),
+                        "{choice}秒"
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// ThemePicker コンポーネント: 記号テーマの選択
+// ============================================================================
+// 駒の見た目（記号セット）を切り替えるドロップダウン
+//
+// 学習ポイント:
+// - enumの全バリアント（SymbolTheme::ALL）から選択肢を生成
+// - インデックスを介したenumと文字列valueの相互変換
+#[component]
+pub fn ThemePicker(
+    // 現在のテーマ
+    theme: SymbolTheme,
+    // テーマ変更時のイベントハンドラー
+    onchange: EventHandler<SymbolTheme>
+) -> Element {
+    rsx! {
+        label {
+            class: "flex items-center gap-1 text-xs font-semibold text-slate-600",
+            "記号:"
+            select {
+                class: "px-2 py-1 rounded-full border border-slate-300 bg-white",
+                onchange: move |evt: FormEvent| {
+                    if let Some(selected) = evt.value().parse::<usize>().ok().and_then(|i| SymbolTheme::ALL.get(i)) {
+                        onchange.call(*selected);
+                    }
+                },
+
+                for (index, choice) in SymbolTheme::ALL.iter().enumerate() {
+                    option {
+                        value: "{index}",
+                        selected: *choice == theme,
+                        {choice.label()}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// CellSizePicker コンポーネント: セルの大きさの選択
+// ============================================================================
+// クリック対象（セル）の大きさを切り替えるドロップダウン
+//
+// 学習ポイント: ThemePickerと同じ「ALL定数＋インデックス」のパターン
+#[component]
+pub fn CellSizePicker(
+    // 現在の大きさ
+    size: CellSize,
+    // 変更時のイベントハンドラー
+    onchange: EventHandler<CellSize>
+) -> Element {
+    rsx! {
+        label {
+            class: "flex items-center gap-1 text-xs font-semibold text-slate-600",
+            "セルの大きさ:"
+            select {
+                class: "px-2 py-1 rounded-full border border-slate-300 bg-white",
+                onchange: move |evt: FormEvent| {
+                    if let Some(selected) = evt.value().parse::<usize>().ok().and_then(|i| CellSize::ALL.get(i)) {
+                        onchange.call(*selected);
+                    }
+                },
+
+                for (index, choice) in CellSize::ALL.iter().enumerate() {
+                    option {
+                        value: "{index}",
+                        selected: *choice == size,
+                        {choice.label()}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// MarkStylePicker コンポーネント: 駒の描画方法の選択
+// ============================================================================
+// クラシックテーマの駒をSVGアイコンで描くか、CSSで描くかを選ぶドロップダウン
+//
+// 学習ポイント: CellSizePickerと同じ「ALL定数＋インデックス」のパターン
+#[component]
+pub fn MarkStylePicker(
+    // 現在の描画方法
+    style: MarkStyle,
+    // 変更時のイベントハンドラー
+    onchange: EventHandler<MarkStyle>
+) -> Element {
+    rsx! {
+        label {
+            class: "flex items-center gap-1 text-xs font-semibold text-slate-600",
+            "駒の描画:"
+            select {
+                class: "px-2 py-1 rounded-full border border-slate-300 bg-white",
+                onchange: move |evt: FormEvent| {
+                    if let Some(selected) = evt.value().parse::<usize>().ok().and_then(|i| MarkStyle::ALL.get(i)) {
+                        onchange.call(*selected);
+                    }
+                },
+
+                for (index, choice) in MarkStyle::ALL.iter().enumerate() {
+                    option {
+                        value: "{index}",
+                        selected: *choice == style,
+                        {choice.label()}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// RotationPicker コンポーネント: 盤面の向きの選択
+// ============================================================================
+// 盤面を見た目だけ90度ずつ回転させる角度を選ぶドロップダウン
+//
+// 学習ポイント: CellSizePickerと同じ「ALL定数＋インデックス」のパターン
+#[component]
+pub fn RotationPicker(
+    // 現在の向き
+    rotation: BoardRotation,
+    // 変更時のイベントハンドラー
+    onchange: EventHandler<BoardRotation>
+) -> Element {
+    rsx! {
+        label {
+            class: "flex items-center gap-1 text-xs font-semibold text-slate-600",
+            "盤面の向き:"
+            select {
+                class: "px-2 py-1 rounded-full border border-slate-300 bg-white",
+                onchange: move |evt: FormEvent| {
+                    if let Some(selected) = evt.value().parse::<usize>().ok().and_then(|i| BoardRotation::ALL.get(i)) {
+                        onchange.call(*selected);
+                    }
+                },
+
+                for (index, choice) in BoardRotation::ALL.iter().enumerate() {
+                    option {
+                        value: "{index}",
+                        selected: *choice == rotation,
+                        {choice.label()}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// HandicapPicker コンポーネント: ハンディキャップの選択
+// ============================================================================
+// 対局開始時に駒を置いておくプレイヤーと個数を選ぶドロップダウン
+//
+// 学習ポイント: MarkStylePickerと同じ「定数の配列＋インデックス」のパターン
+#[component]
+pub fn HandicapPicker(
+    // 現在のハンディキャップ
+    handicap: Handicap,
+    // 変更時のイベントハンドラー
+    onchange: EventHandler<Handicap>
+) -> Element {
+    rsx! {
+        label {
+            class: "flex items-center gap-1 text-xs font-semibold text-slate-600",
+            "ハンディ:"
+            select {
+                class: "px-2 py-1 rounded-full border border-slate-300 bg-white",
+                onchange: move |evt: FormEvent| {
+                    if let Some(selected) = evt.value().parse::<usize>().ok().and_then(|i| Handicap::CHOICES.get(i)) {
+                        onchange.call(*selected);
+                    }
+                },
+
+                for (index, choice) in Handicap::CHOICES.iter().enumerate() {
+                    option {
+                        value: "{index}",
+                        selected: *choice == handicap,
+                        {choice.label()}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// DebugPanel コンポーネント: シグナルの値の表示（開発用）
+// ============================================================================
+// 主要なシグナルの現在値をDebug形式で表示する折りたたみパネル
+// `debug`フィーチャー有効時のみコンパイルされる（本番ビルドには含まれない）
+//
+// 学習ポイント:
+// - #[cfg(feature = "...")]によるコンポーネント単位の条件付きコンパイル
+// - {:?}（Debugトレイト）による値の整形表示
+// - details/summary要素による折りたたみUI
+#[cfg(feature = "debug")]
+#[component]
+pub fn DebugPanel(
+    // ゲーム盤面
+    board: Board,
+    // 現在のプレイヤー
+    current_player: Player,
+    // ゲーム状態
+    game_state: GameState,
+    // 理論上の勝者
+    decided: Option<Player>,
+    // 対局結果の累計
+    score: Score
+) -> Element {
+    // 導出値：手数（盤面上の駒の数）
+    let (x_count, o_count) = GameLogic::counts(board);
+    let move_count = x_count + o_count;
+
+    // 盤面を「X . O」形式の3行に整形
+    let board_rows: Vec<String> = board
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| cell.map_or(".", |player| player.symbol()))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect();
+
+    rsx! {
+        details {
+            class: "mt-3 p-2 rounded-lg border border-dashed border-slate-400 bg-slate-50 text-xs font-mono text-slate-700",
+            summary { class: "cursor-pointer font-semibold", "🐛 デバッグ: シグナルの値" }
+
+            pre { class: "mt-1", {board_rows.join("\n")} }
+            p { "current_player: {current_player:?}" }
+            p { "game_state: {game_state:?}" }
+            p { "decided: {decided:?}" }
+            p { "score: {score:?}" }
+            p { "move_count: {move_count}" }
+        }
+    }
+}
+
+// ============================================================================
+// テスト: 描画ヘルパーの検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mark_kind_falls_back_when_icon_fails() {
+        // クラシックテーマは通常SVGアイコン、読み込み失敗時はX/Oの文字
+        let classic = |player, failed| mark_kind(SymbolTheme::Classic, MarkStyle::Svg, player, failed);
+        assert_eq!(classic(Player::X, false), MarkKind::Icon);
+        assert_eq!(classic(Player::X, true), MarkKind::Text("X"));
+        assert_eq!(classic(Player::O, true), MarkKind::Text("O"));
+
+        // 絵文字テーマはアイコンを使わないので常にテキスト
+        assert_eq!(mark_kind(SymbolTheme::Animals, MarkStyle::Svg, Player::X, false), MarkKind::Text("🐱"));
+    }
+
+    #[test]
+    fn test_mark_kind_css_style() {
+        // CSS描画ではアセットを使わないので、読み込み失敗の影響を受けない
+        for failed in [false, true] {
+            assert_eq!(mark_kind(SymbolTheme::Classic, MarkStyle::Css, Player::X, failed), MarkKind::Css);
+        }
+        assert_eq!(mark_kind(SymbolTheme::Weather, MarkStyle::Css, Player::O, false), MarkKind::Text("❄️"));
+
+        // Xは赤い2本の交差線、Oは青い輪1つ
+        let x = css_mark_parts(Player::X);
+        assert_eq!(x.len(), 2);
+        assert!(x[0].contains(" rotate-45") && x[1].contains("-rotate-45"));
+        assert!(x.iter().all(|part| part.contains("bg-red-500")));
+
+        let o = css_mark_parts(Player::O);
+        assert_eq!(o.len(), 1);
+        assert!(o[0].contains("rounded-full") && o[0].contains("blue-500"));
+    }
+
+    #[test]
+    fn test_format_line() {
+        let line = [(1, 1), (0, 0), (0, 2)];
+        assert_eq!(
+            format_line(&line, Player::X, GameState::Playing),
+            "X: (1,1), O: (0,0), X: (0,2)"
+        );
+        assert_eq!(format_line(&[(2, 2)], Player::O, GameState::Won(Player::O)), "O: (2,2), Oの勝ち");
+        assert_eq!(format_line(&[], Player::X, GameState::Draw), "引き分け");
+    }
+
+    #[test]
+    fn test_line_markers_follow_move_list() {
+        let line = [(1, 1), (0, 0), (0, 2)];
+        let markers = line_markers(&line);
+
+        // 手順のn手目のマスにnが付き、それ以外のマスは空
+        for (step, &(row, col)) in line.iter().enumerate() {
+            assert_eq!(markers[row][col], Some(step + 1));
+        }
+        assert_eq!(markers.iter().flatten().flatten().count(), line.len());
+        assert_eq!(line_markers(&[]), [[None; 3]; 3]);
+    }
+
+    #[test]
+    fn test_status_text_includes_symbol() {
+        let classic = SymbolTheme::Classic;
+        assert_eq!(status_text(GameState::Playing, Player::X, None, classic), "Xの番です");
+        assert_eq!(status_text(GameState::Playing, Player::O, Some(Player::O), classic), "AIの番です（O）");
+        assert_eq!(status_text(GameState::Playing, Player::X, Some(Player::O), classic), "あなたの番です（X）");
+        assert_eq!(status_text(GameState::Won(Player::O), Player::O, None, classic), "Oの勝利！");
+
+        // 絵文字テーマでも、色ではなく記号そのもので誰のことかわかる
+        let text = status_text(GameState::Won(Player::X), Player::X, None, SymbolTheme::Animals);
+        assert!(text.contains("🐱"));
+    }
+
+    #[test]
+    fn test_cell_label_includes_content() {
+        assert_eq!(cell_label(0, 2, Some(Player::X), false, None), "1行3列 X");
+        assert_eq!(cell_label(1, 1, None, false, Some("ここから始めるのがおすすめ")), "2行2列 空き（ここから始めるのがおすすめ）");
+        // ブラインドモードでは駒があることだけを伝える
+        assert_eq!(cell_label(2, 0, Some(Player::O), true, None), "3行1列 伏せた駒");
+    }
+
+    #[test]
+    fn test_evaluation_share_spans_the_bar() {
+        // 最短で勝つ局面がちょうど端になり、引き分けは真ん中
+        assert_eq!(evaluation_share(GameLogic::MAX_EVALUATION), 100);
+        assert_eq!(evaluation_share(-GameLogic::MAX_EVALUATION), 0);
+        assert_eq!(evaluation_share(0), 50);
+        assert_eq!(evaluation_share(GameLogic::MAX_EVALUATION + 1), 100);
+    }
+
+    #[test]
+    fn test_long_press_classification() {
+        // 短いタップは着手、長押しはヒントだけ（境界ちょうどは長押し）
+        assert!(!is_long_press(0));
+        assert!(!is_long_press(120));
+        assert!(!is_long_press(LONG_PRESS_MS - 1));
+        assert!(is_long_press(LONG_PRESS_MS));
+        assert!(is_long_press(2_000));
+    }
+
+    #[test]
+    fn test_move_focus_with_arrow_keys() {
+        // 中央から上下左右へ1マスずつ
+        assert_eq!(move_focus(4, &Key::ArrowUp), Some(1));
+        assert_eq!(move_focus(4, &Key::ArrowDown), Some(7));
+        assert_eq!(move_focus(4, &Key::ArrowLeft), Some(3));
+        assert_eq!(move_focus(4, &Key::ArrowRight), Some(5));
+
+        // 端では折り返さずに止まる
+        assert_eq!(move_focus(0, &Key::ArrowUp), Some(0));
+        assert_eq!(move_focus(0, &Key::ArrowLeft), Some(0));
+        assert_eq!(move_focus(8, &Key::ArrowDown), Some(8));
+        assert_eq!(move_focus(2, &Key::ArrowRight), Some(2));
+
+        // Home・Endは同じ行の端へ、それ以外のキーは扱わない
+        assert_eq!(move_focus(4, &Key::Home), Some(3));
+        assert_eq!(move_focus(3, &Key::End), Some(5));
+        assert_eq!(move_focus(4, &Key::Enter), None);
+    }
+
+    #[test]
+    fn test_coordinate_labels_follow_rotation() {
+        assert_eq!(coordinate_labels(BoardRotation::None), (["A", "B", "C"], ["1", "2", "3"]));
+        assert_eq!(coordinate_labels(BoardRotation::Half), (["C", "B", "A"], ["3", "2", "1"]));
+        // 90度：上側は盤面の行（下の行が左に来る）、左側は盤面の列
+        assert_eq!(coordinate_labels(BoardRotation::Quarter), (["3", "2", "1"], ["A", "B", "C"]));
+        assert_eq!(coordinate_labels(BoardRotation::ThreeQuarter), (["1", "2", "3"], ["C", "B", "A"]));
+    }
+}
@@ -0,0 +1,82 @@
+// ============================================================================
+// Dioxus学習プロジェクト: タップで確定する着手
+// ============================================================================
+// このファイルは「1回目のタップでマスを選び、同じマスをもう一度タップしたら置く」という
+// 誤タップ防止の入力を、UIに依存しない小さな状態として定義します。
+//
+// 学習ポイント:
+// - 選択中のマス（まだ盤面に反映していない入力）を盤面とは別に持つ
+// - 取り消し（Escapeキー等）は選択だけを消し、適用済みの着手には触れない
+
+// ============================================================================
+// 型定義: 確定待ちの選択
+// ============================================================================
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct TapConfirm {
+    pub selected: Option<(usize, usize)>,  // 選択中のマス（確定待ち、なければNone）
+}
+
+impl TapConfirm {
+    /// マスをタップする（選択中のマスをもう一度タップしたら、選択を解除してそのマスを返す）
+    /// 学習ポイント: 別のマスをタップしたら選択を移すだけで、まだ置かない
+    pub fn tap(&mut self, cell: (usize, usize)) -> Option<(usize, usize)> {
+        if self.selected == Some(cell) {
+            self.selected = None;
+            Some(cell)
+        } else {
+            self.selected = Some(cell);
+            None
+        }
+    }
+
+    /// 選択を取り消す（取り消す選択があったらtrue）
+    pub fn cancel(&mut self) -> bool {
+        self.selected.take().is_some()
+    }
+}
+
+// ============================================================================
+// テスト: タップで確定する着手の検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GameLogic, Player};
+
+    #[test]
+    fn test_tap_selects_then_confirms() {
+        let mut confirm = TapConfirm::default();
+
+        // 1回目は選択だけ、別のマスをタップしたら選択を移す
+        assert_eq!(confirm.tap((0, 0)), None);
+        assert_eq!(confirm.tap((1, 1)), None);
+        assert_eq!(confirm.selected, Some((1, 1)));
+
+        // 同じマスをもう一度タップしたら確定し、選択は消える
+        assert_eq!(confirm.tap((1, 1)), Some((1, 1)));
+        assert_eq!(confirm.selected, None);
+    }
+
+    #[test]
+    fn test_cancel_clears_selection_but_not_applied_moves() {
+        let mut board = GameLogic::empty_board();
+        let mut confirm = TapConfirm::default();
+
+        // 確定した着手を盤面に反映してから、次のマスを選ぶ
+        confirm.tap((1, 1));
+        let (row, col) = confirm.tap((1, 1)).unwrap();
+        assert!(GameLogic::try_place(&mut board, row, col, Player::X));
+        confirm.tap((0, 0));
+
+        // 取り消すと選択だけが消え、適用済みの着手は盤面に残る
+        let before = board;
+        assert!(confirm.cancel());
+        assert_eq!(confirm.selected, None);
+        assert_eq!(board, before);
+        assert_eq!(board[1][1], Some(Player::X));
+
+        // 選択がなければ取り消すものはない（次のタップは選択からやり直し）
+        assert!(!confirm.cancel());
+        assert_eq!(confirm.tap((0, 0)), None);
+    }
+}
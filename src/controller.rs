@@ -0,0 +1,472 @@
+// ============================================================================
+// Dioxus学習プロジェクト: ゲームの進行管理
+// ============================================================================
+// このファイルは盤面・手番・ゲーム状態・履歴・スコアを1つにまとめ、
+// 着手・リセット・一手戻す・やり直しの遷移を提供します。
+// Dioxusのシグナルに依存しないので、UIなしでテストしたり別のフロントエンドで使えます。
+//
+// 学習ポイント:
+// - 関連する状態を1つの構造体にまとめ、遷移をメソッドに閉じ込める
+// - 「一手戻す」で取り消した手をスタックに積み、「やり直し」で積み直す
+// - 開始局面と着手列から盤面を組み立て直す（状態の二重管理をしない）
+// - 引き分けの提案は相手が受けるか断るまで保留し、受けたら盤面に関係なく引き分けで終局する
+
+use crate::history::MoveHistory;
+use crate::types::{Board, GameLogic, GameState, Player, Score, WinRule};
+
+// ============================================================================
+// 型定義: ゲームの進行管理
+// ============================================================================
+#[derive(Clone, PartialEq, Debug)]
+pub struct GameController {
+    pub board: Board,             // 現在の盤面
+    pub current_player: Player,   // 現在の手番
+    pub game_state: GameState,    // 現在のゲーム状態
+    pub history: MoveHistory,     // 着手履歴（進行中の対局と直前の対局）
+    pub score: Score,             // 対局結果の累計
+    pub draw_offer: Option<Player>,  // 引き分けを提案しているプレイヤー（提案がなければNone）
+    pub rule: WinRule,            // 勝利ラインのルール（終局判定に使う）
+    pub unscored: bool,           // 終局してもスコア・履歴に記録しない対局か（練習問題・観戦）
+    redo: Vec<(usize, usize)>,    // 取り消した手（最後に取り消した手が末尾）
+}
+
+impl GameController {
+    /// 空の盤面・Xの手番から始める
+    pub fn new() -> Self {
+        GameController {
+            board: GameLogic::empty_board(),
+            current_player: Player::X,
+            game_state: GameState::Playing,
+            history: MoveHistory::default(),
+            score: Score::default(),
+            draw_offer: None,
+            rule: WinRule::Standard,
+            unscored: false,
+            redo: Vec::new(),
+        }
+    }
+
+    /// 指定した局面から始める（到達できない盤面ならエラー）
+    /// 学習ポイント: 検証と手番の推定はGameLogic::load_boardに任せる
+    pub fn from_board(board: Board) -> Result<Self, &'static str> {
+        Self::from_board_with(board, WinRule::Standard)
+    }
+
+    /// 指定したルールで、指定した局面から始める
+    pub fn from_board_with(board: Board, rule: WinRule) -> Result<Self, &'static str> {
+        Self::from_start_with((GameLogic::empty_board(), Player::X), board, rule)
+    }
+
+    /// 指定したルールで、開始局面（ハンディキャップ等）から指し進めた局面から始める（保存したセッションの復元用）
+    pub fn from_start_with(start: (Board, Player), board: Board, rule: WinRule) -> Result<Self, &'static str> {
+        let mut game = GameController { rule, ..Self::new() };
+        game.load_from(start, board)?;
+        game.history.last_start = game.history.start;
+        Ok(game)
+    }
+
+    /// 手番のプレイヤーの駒を置き、着手後のゲーム状態を返す
+    /// 学習ポイント: 新しい手を指したら、やり直し用に積んだ手は無効になる
+    pub fn play(&mut self, row: usize, col: usize) -> Result<GameState, &'static str> {
+        self.place(row, col)?;
+        self.redo.clear();
+        Ok(self.game_state)
+    }
+
+    /// 駒を置いて履歴・スコア・手番を更新する（やり直しスタックには触れない）
+    fn place(&mut self, row: usize, col: usize) -> Result<(), &'static str> {
+        if self.game_state != GameState::Playing {
+            return Err("ゲームは終了しています");
+        }
+
+        self.board = GameLogic::make_move(self.board, row, col, self.current_player)?;
+        self.history.record(row, col);
+        self.draw_offer = None;
+        self.game_state = GameLogic::check_after_move_with(self.board, row, col, self.rule);
+
+        match self.game_state {
+            GameState::Playing => self.current_player = self.current_player.next(),
+            state => self.finish(state),
+        }
+        Ok(())
+    }
+
+    /// 終局させ、結果をスコアと履歴に記録する（記録しない対局では状態だけ変える）
+    fn finish(&mut self, state: GameState) {
+        self.game_state = state;
+        self.draw_offer = None;
+        if let (Some(outcome), false) = (state.outcome(), self.unscored) {
+            self.score.record(outcome);
+            self.history.finish_game();
+        }
+    }
+
+    /// 空の盤面から新しい対局を始める（スコアと直前の対局の記録は残す）
+    pub fn reset(&mut self) {
+        self.restart(GameLogic::empty_board(), Player::X);
+    }
+
+    /// 指定した開始局面・最初の手番から新しい対局を始める（スコアと直前の対局の記録は残す）
+    /// 学習ポイント: ハンディキャップの局面は駒数が通常の対局と合わないので、検証せずにそのまま使う
+    pub fn restart(&mut self, board: Board, first: Player) {
+        self.history.reset(board, first);
+        self.board = board;
+        self.current_player = first;
+        self.game_state = GameLogic::check_game_state_with(board, self.rule);
+        self.draw_offer = None;
+        self.unscored = false;
+        self.redo.clear();
+    }
+
+    /// 盤面を検証してから、その局面で新しい対局を始める（到達できない盤面ならエラーで、何も変えない）
+    pub fn load(&mut self, board: Board) -> Result<(), &'static str> {
+        self.load_from((GameLogic::empty_board(), Player::X), board)
+    }
+
+    /// 開始局面から指し進めた盤面として検証してから、その局面で新しい対局を始める
+    /// 学習ポイント:
+    /// - 検証と手番の推定はGameLogic::load_board_fromに任せる
+    /// - 着手の順番は分からないので、読み込んだ盤面を新しい開始局面にする（最初の手番は次に置くプレイヤー）
+    pub fn load_from(&mut self, start: (Board, Player), board: Board) -> Result<(), &'static str> {
+        let (game_state, current_player) = GameLogic::load_board_from(start, board, self.rule)?;
+        let to_move = if game_state == GameState::Playing { current_player } else { current_player.next() };
+        self.restart(board, to_move);
+        self.current_player = current_player;
+        self.game_state = game_state;
+        Ok(())
+    }
+
+    /// 直前の1手を取り消す（対局中のみ、取り消せたらtrue）
+    /// 学習ポイント: 終局後に戻すとスコアとの整合が崩れるため、対局中に限る
+    pub fn undo(&mut self) -> bool {
+        self.undo_turn(None) > 0
+    }
+
+    /// 手を取り消し、取り消した手数を返す（AI対戦ではAIの応手とあわせて人間の手番まで戻す）
+    /// 学習ポイント: 取り消す手数はMoveHistory::undo_countが決める
+    pub fn undo_turn(&mut self, ai_player: Option<Player>) -> usize {
+        if self.game_state != GameState::Playing {
+            return 0;
+        }
+        let played = self.history.moves.clone();
+        let count = self.history.undo(ai_player);
+
+        // 取り消した手を新しい順に積む（やり直しは末尾、つまり先に指した手から取り出す）
+        self.redo.extend(played[played.len() - count..].iter().rev());
+        self.rebuild();
+        count
+    }
+
+    /// 手番のプレイヤーの負け（時間切れ）で終局する（対局中でなければfalse）
+    pub fn forfeit(&mut self) -> bool {
+        if self.game_state != GameState::Playing {
+            return false;
+        }
+        self.finish(GameState::Won(self.current_player.opponent()));
+        true
+    }
+
+    /// 引き分けを提案する（対局中で、まだ提案がなければtrue）
+    /// 学習ポイント: 提案は着手すると取り下げられる（相手が指せば断ったのと同じ）
+    pub fn offer_draw(&mut self, player: Player) -> bool {
+        if self.game_state != GameState::Playing || self.draw_offer.is_some() {
+            return false;
+        }
+        self.draw_offer = Some(player);
+        true
+    }
+
+    /// 提案への返事（受けたら引き分けで終局、断ったら提案を取り消すだけ、提案がなければfalse）
+    pub fn respond_to_draw(&mut self, accept: bool) -> bool {
+        if self.draw_offer.take().is_none() {
+            return false;
+        }
+        if accept {
+            self.finish(GameState::Draw);
+        }
+        true
+    }
+
+    /// 取り消した手を1手だけ指し直す（指し直せたらtrue）
+    pub fn redo(&mut self) -> bool {
+        let Some((row, col)) = self.redo.pop() else {
+            return false;
+        };
+        self.place(row, col).is_ok()
+    }
+
+    /// やり直せる手があるか
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// 開始局面（履歴が覚えている）に履歴の着手を置き直して、盤面・手番・状態を組み立て直す
+    fn rebuild(&mut self) {
+        let (board, player) = self.history.position();
+        self.board = board;
+        self.current_player = player;
+        self.game_state = GameLogic::check_game_state_with(board, self.rule);
+    }
+}
+
+impl Default for GameController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// テスト: ゲームの進行管理の検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BoardBuilder;
+
+    /// 操作後の盤面・ゲーム状態・手番が矛盾していないことを確かめる
+    #[track_caller]
+    fn check(game: &GameController) {
+        GameLogic::assert_invariants(game.board, game.game_state, game.current_player);
+    }
+
+    #[test]
+    fn test_full_game_through_controller() {
+        let mut game = GameController::new();
+        check(&game);
+
+        // Xが上段を揃えて勝つ
+        for (row, col) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            assert_eq!(game.play(row, col), Ok(GameState::Playing));
+            check(&game);
+        }
+        assert_eq!(game.current_player, Player::X);
+        assert_eq!(game.play(0, 2), Ok(GameState::Won(Player::X)));
+        check(&game);
+
+        // 終局するとスコアと直前の対局が記録され、それ以上は置けない
+        assert_eq!(game.score.wins(Player::X), 1);
+        assert_eq!(game.history.last_game.as_ref().map(Vec::len), Some(5));
+        assert!(game.play(2, 2).is_err());
+        check(&game);
+        assert!(!game.undo());
+        check(&game);
+
+        // リセットしてもスコアは残り、空の盤面・Xの手番から始まる
+        game.reset();
+        check(&game);
+        assert_eq!(game.board, GameLogic::empty_board());
+        assert_eq!((game.current_player, game.game_state), (Player::X, GameState::Playing));
+        assert_eq!(game.score.wins(Player::X), 1);
+        assert!(game.history.last_game.is_some());
+    }
+
+    #[test]
+    fn test_play_rejects_occupied_cell() {
+        let mut game = GameController::new();
+        check(&game);
+        game.play(1, 1).unwrap();
+        check(&game);
+
+        assert!(game.play(1, 1).is_err());
+        check(&game);
+        assert!(game.play(3, 0).is_err());
+        check(&game);
+        assert_eq!(game.current_player, Player::O);
+        assert_eq!(game.history.moves, vec![(1, 1)]);
+    }
+
+    #[test]
+    fn test_undo_and_redo() {
+        let mut game = GameController::new();
+        check(&game);
+        game.play(0, 0).unwrap();
+        check(&game);
+        game.play(1, 1).unwrap();
+        check(&game);
+
+        // 戻すと盤面・手番が1手前になり、やり直すと元に戻る
+        assert!(game.undo());
+        check(&game);
+        assert_eq!(game.board, BoardBuilder::new().x(0, 0).build());
+        assert_eq!(game.current_player, Player::O);
+        assert!(game.can_redo());
+        assert!(game.redo());
+        check(&game);
+        assert_eq!(game.board, BoardBuilder::new().x(0, 0).o(1, 1).build());
+        assert!(!game.redo());
+        check(&game);
+
+        // 戻した後に別の手を指すと、やり直しはできなくなる
+        assert!(game.undo());
+        check(&game);
+        game.play(2, 2).unwrap();
+        check(&game);
+        assert!(!game.can_redo());
+
+        // 最初まで戻すと、それ以上は戻せない
+        assert!(game.undo());
+        check(&game);
+        assert!(game.undo());
+        check(&game);
+        assert!(!game.undo());
+        check(&game);
+        assert_eq!(game.board, GameLogic::empty_board());
+    }
+
+    #[test]
+    fn test_undo_stops_at_start_position() {
+        // 開始局面から指した手だけを戻し、開始局面の駒は残る
+        let start = BoardBuilder::new().x(0, 0).o(1, 1).build();
+        let mut game = GameController::from_board(start).unwrap();
+        check(&game);
+        game.play(2, 2).unwrap();
+        check(&game);
+
+        assert!(game.undo());
+        check(&game);
+        assert_eq!((game.board, game.current_player), (start, Player::X));
+        assert!(!game.undo());
+        check(&game);
+
+        // やり直しで指した手も通常の着手と同じく終局判定される
+        let mut game = GameController::from_board(BoardBuilder::new().x(0, 0).x(0, 1).o(1, 0).o(1, 1).build()).unwrap();
+        check(&game);
+        game.play(2, 2).unwrap();
+        check(&game);
+        assert!(game.undo());
+        check(&game);
+        assert!(game.redo());
+        check(&game);
+        game.play(2, 1).unwrap();
+        check(&game);
+        assert_eq!(game.play(0, 2), Ok(GameState::Won(Player::X)));
+        check(&game);
+        assert_eq!(game.score.wins(Player::X), 1);
+    }
+
+    #[test]
+    fn test_accepting_draw_offer_ends_in_draw() {
+        let mut game = GameController::new();
+        game.play(1, 1).unwrap();
+        check(&game);
+
+        // Xが提案し、Oが受けると引き分けで終局する（盤面の判定とは関係なく終わるのでcheckは使わない）
+        assert!(game.offer_draw(Player::X));
+        assert!(!game.offer_draw(Player::O));
+        assert!(game.respond_to_draw(true));
+        assert_eq!(game.game_state, GameState::Draw);
+        assert_eq!(game.draw_offer, None);
+        assert_eq!(game.score.draws, 1);
+        assert!(game.history.last_game.is_some());
+
+        // 終局後は置けず、新しい提案もできない
+        assert!(game.play(0, 0).is_err());
+        assert!(!game.offer_draw(Player::O));
+    }
+
+    #[test]
+    fn test_declined_or_withdrawn_draw_offer() {
+        let mut game = GameController::new();
+        check(&game);
+
+        // 断ると提案だけが消えて対局は続く
+        assert!(game.offer_draw(Player::O));
+        assert!(game.respond_to_draw(false));
+        check(&game);
+        assert_eq!((game.game_state, game.draw_offer), (GameState::Playing, None));
+        assert!(!game.respond_to_draw(true));
+
+        // 提案中に着手すると提案は取り下げられる
+        assert!(game.offer_draw(Player::X));
+        game.play(0, 0).unwrap();
+        check(&game);
+        assert_eq!(game.draw_offer, None);
+        assert_eq!(game.score, Score::default());
+    }
+
+    #[test]
+    fn test_undo_turn_against_ai() {
+        // AI（O）の応手とあわせて2手戻し、やり直しは先に指した手から積み直す
+        let mut game = GameController::new();
+        for (row, col) in [(0, 0), (1, 1), (2, 2), (0, 2)] {
+            game.play(row, col).unwrap();
+        }
+        assert_eq!(game.undo_turn(Some(Player::O)), 2);
+        check(&game);
+        assert_eq!(game.board, BoardBuilder::new().x(0, 0).o(1, 1).build());
+        assert!(game.redo());
+        assert_eq!(game.board, BoardBuilder::new().x(0, 0).o(1, 1).x(2, 2).build());
+        assert!(game.redo());
+        check(&game);
+        assert_eq!(game.current_player, Player::X);
+    }
+
+    #[test]
+    fn test_restart_from_handicap_and_load() {
+        // ハンディキャップの局面は駒数が合わなくてもそのまま開始局面にする
+        let mut game = GameController::new();
+        let start = BoardBuilder::new().o(1, 1).build();
+        game.restart(start, Player::X);
+        game.play(0, 0).unwrap();
+        assert!(game.undo());
+        assert_eq!((game.board, game.current_player), (start, Player::X));
+
+        // loadは検証に失敗したら何も変えない
+        assert!(game.load(BoardBuilder::new().o(0, 0).build()).is_err());
+        assert_eq!(game.board, start);
+        game.load(BoardBuilder::new().x(0, 0).build()).unwrap();
+        check(&game);
+        assert_eq!(game.current_player, Player::O);
+
+        // 保存したセッションの復元：開始局面から指し進めた盤面なら、駒数が合わなくても再開できる
+        let board = BoardBuilder::new().o(1, 1).x(0, 0).o(2, 2).build();
+        assert!(GameController::from_board(board).is_err());
+        let resumed = GameController::from_start_with((start, Player::X), board, WinRule::Standard).unwrap();
+        assert_eq!((resumed.board, resumed.current_player), (board, Player::X));
+        assert_eq!(resumed.history.start, (board, Player::X));
+    }
+
+    #[test]
+    fn test_forfeit_and_unscored_games() {
+        // 時間切れは手番のプレイヤーの負け
+        let mut game = GameController::new();
+        game.play(1, 1).unwrap();
+        assert!(game.forfeit());
+        assert_eq!(game.game_state, GameState::Won(Player::X));
+        assert_eq!(game.score.wins(Player::X), 1);
+        assert!(!game.forfeit());
+
+        // 記録しない対局（練習問題・観戦）は終局してもスコアと直前の対局を変えない
+        game.reset();
+        game.unscored = true;
+        for (row, col) in [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2)] {
+            game.play(row, col).unwrap();
+        }
+        assert_eq!(game.game_state, GameState::Won(Player::X));
+        assert_eq!(game.score.wins(Player::X), 1);
+        assert_eq!(game.history.last_game.as_ref().map(Vec::len), Some(1));
+
+        // 新しい対局では記録する対局に戻る
+        game.reset();
+        assert!(!game.unscored);
+    }
+
+    #[test]
+    fn test_diagonal_does_not_end_game_without_diagonals() {
+        let mut game = GameController::from_board_with(GameLogic::empty_board(), WinRule::NoDiagonals).unwrap();
+        for (row, col) in [(0, 0), (0, 1), (1, 1), (1, 0)] {
+            game.play(row, col).unwrap();
+        }
+        assert_eq!(game.play(2, 2), Ok(GameState::Playing));
+        assert_eq!(game.current_player, Player::O);
+
+        // リセットしてもルールは変わらない
+        game.reset();
+        assert_eq!(game.rule, WinRule::NoDiagonals);
+    }
+
+    #[test]
+    fn test_from_board_rejects_invalid_position() {
+        assert!(GameController::from_board(BoardBuilder::new().o(0, 0).build()).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+// ============================================================================
+// Dioxus学習プロジェクト: 再現性のテスト
+// ============================================================================
+// このファイルは乱数に依存する機能（ランダム・腕前のAI、最善手の選び方、観戦リンク、AI同士の対戦）が
+// 「同じシード＋同じ人間の入力なら同じ対局になる」ことを検証するテストだけを集めています。
+//
+// 学習ポイント:
+// - 乱数はすべてシード付きのRngを引数で受け取るので、テストから好きなシードを注入できる
+// - 1局を棋譜（GameLogic::to_transcript）に変換して比べると、途中の手まで一致を確かめられる
+// - 「違うシードなら違う対局になりうる」ことも確かめ、シードが実際に使われていることを保証する
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::ai::{self, AiStrategy, Rng};
+    use crate::controller::GameController;
+    use crate::spectate::SpectateLink;
+    use crate::types::{GameLogic, GameState, Player, WinRule};
+
+    /// 人間（X）が指したい順のセル（置けなければ次の候補、尽きたら空きセルの先頭）
+    const HUMAN_INPUTS: [(usize, usize); 9] =
+        [(1, 1), (0, 0), (2, 2), (0, 2), (2, 0), (0, 1), (1, 0), (1, 2), (2, 1)];
+
+    /// AI（O）をseedのRngで指させ、人間の入力と合わせて終局まで進めた棋譜を返す
+    fn play(strategy: &AiStrategy, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut game = GameController::new();
+        let mut inputs = HUMAN_INPUTS.iter().copied();
+
+        while game.game_state == GameState::Playing {
+            let (board, player) = (game.board, game.current_player);
+            let cell = match player {
+                Player::O => strategy.choose_move(board, player, WinRule::Standard, &mut rng),
+                Player::X => inputs
+                    .find(|&(row, col)| GameLogic::is_valid_move(board, row, col))
+                    .or_else(|| GameLogic::empty_cells(board).first().copied()),
+            };
+            let Some((row, col)) = cell else {
+                break;
+            };
+            game.play(row, col).unwrap();
+        }
+
+        let moves = game.history.last_game.unwrap_or(game.history.moves);
+        GameLogic::to_transcript(&moves, Player::X)
+    }
+
+    /// 乱数を使う戦略（定石も含め、シードを渡しても結果が変わらないことを確かめる）
+    fn strategies() -> [AiStrategy; 4] {
+        [AiStrategy::Random, AiStrategy::Heuristic, AiStrategy::Minimax, AiStrategy::Skill(50)]
+    }
+
+    #[test]
+    fn test_same_seed_same_transcript() {
+        for strategy in strategies() {
+            for seed in [1, 42, 2024] {
+                assert_eq!(play(&strategy, seed), play(&strategy, seed), "{strategy:?}, seed={seed}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_different_seeds_can_differ() {
+        // ランダム・腕前のAIは、シードを変えれば違う対局になることがある
+        for strategy in [AiStrategy::Random, AiStrategy::Skill(50)] {
+            let games: HashSet<String> = (0..10).map(|seed| play(&strategy, seed)).collect();
+            assert!(games.len() > 1, "{strategy:?}");
+        }
+
+        // 定石は乱数を使わないので、シードによらず同じ対局になる
+        let games: HashSet<String> = (0..10).map(|seed| play(&AiStrategy::Heuristic, seed)).collect();
+        assert_eq!(games.len(), 1);
+    }
+
+    #[test]
+    fn test_seeded_sources_replay() {
+        // 観戦リンク・最善手同士の対局・AI同士の対戦も、同じシードなら同じ結果
+        let link = SpectateLink { seed: 7, x: AiStrategy::Random, o: AiStrategy::Minimax };
+        assert_eq!(link.moves(), link.clone().moves());
+        assert_eq!(ai::play_optimal(99), ai::play_optimal(99));
+        assert_eq!(
+            GameLogic::tournament(AiStrategy::Random, AiStrategy::Heuristic, 6, 3),
+            GameLogic::tournament(AiStrategy::Random, AiStrategy::Heuristic, 6, 3)
+        );
+
+        // 乱数の状態はCopyできるので、途中から同じ乱数列を2本に分けられる
+        let mut rng = Rng::new(5);
+        rng.next_u64();
+        let mut copy = rng;
+        assert_eq!(rng.next_u64(), copy.next_u64());
+    }
+}
@@ -0,0 +1,41 @@
+// ============================================================================
+// Dioxus学習プロジェクト: ファイルのダウンロード
+// ============================================================================
+// このファイルは作ったバイト列（対局のGIF等）をファイルとして保存させる関数を提供します。
+// Web（wasm32）でのみ動作します。
+//
+// 学習ポイント:
+// - バイト列からBlobを作り、オブジェクトURLをリンクに設定してクリックする
+// - 使い終わったオブジェクトURLはrevokeして解放する
+// - #[cfg]属性で、Web以外では同じAPIでエラーを返す
+
+/// バイト列をファイル名fileで保存させる（失敗したら表示用のエラー文）
+pub fn save_bytes(file: &str, mime: &str, bytes: &[u8]) -> Result<(), String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use web_sys::wasm_bindgen::JsCast;
+
+        let failed = |_| "ファイルを保存できませんでした".to_string();
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or("ページを取得できませんでした")?;
+
+        let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(mime);
+        let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options).map_err(failed)?;
+        let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(failed)?;
+
+        let link: web_sys::HtmlAnchorElement = document.create_element("a").map_err(failed)?.unchecked_into();
+        link.set_href(&url);
+        link.set_download(file);
+        link.click();
+        web_sys::Url::revoke_object_url(&url).map_err(failed)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (file, mime, bytes);
+        Err("ファイルの保存はWeb版のみ対応しています".to_string())
+    }
+}
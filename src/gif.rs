@@ -0,0 +1,186 @@
+// ============================================================================
+// Dioxus学習プロジェクト: 対局のGIF書き出し
+// ============================================================================
+// このファイルは盤面をピクセルに描き、コマを並べたアニメーションGIFのバイト列を作ります。
+// 外部のエンコーダーを使わず、GIFの仕様のうち必要な部分だけを実装しています。
+//
+// 学習ポイント:
+// - 盤面（データ）→ ピクセル（パレット番号の配列）→ ファイル（バイト列）の段階に分ける
+// - GIFのLZW圧縮は「圧縮しない」書き方ができる（辞書が育つ前にクリアコードを挟む）
+// - ファイル形式はリトルエンディアンの数値とブロックの並びで組み立てる
+
+use crate::types::{Board, Player};
+
+/// 1コマの一辺のピクセル数
+pub const FRAME_SIZE: usize = 96;
+
+/// 1マスの一辺のピクセル数
+const CELL_PX: usize = FRAME_SIZE / 3;
+
+/// 駒とマスの縁の余白（ピクセル）
+const MARK_MARGIN: usize = 6;
+
+/// パレット（背景・罫線・X・O、RGBの順）
+const PALETTE: [[u8; 3]; 4] = [
+    [0xff, 0xff, 0xff],  // 背景: 白
+    [0x94, 0xa3, 0xb8],  // 罫線: slate-400
+    [0x4f, 0x46, 0xe5],  // X: indigo-600
+    [0xe1, 0x1d, 0x48],  // O: rose-600
+];
+
+/// LZWの最小コードサイズ（7にするとコードがちょうど8ビット＝1バイトになる）
+const MIN_CODE_SIZE: u8 = 7;
+
+/// 盤面を1コマのピクセル（パレット番号、行優先でFRAME_SIZE×FRAME_SIZE個）に描く
+/// 学習ポイント: 各ピクセルが「罫線か・どのマスのどの位置か」を座標の計算だけで決める
+pub fn render_frame(board: Board) -> Vec<u8> {
+    let mut pixels = vec![0; FRAME_SIZE * FRAME_SIZE];
+    for (index, pixel) in pixels.iter_mut().enumerate() {
+        let (y, x) = (index / FRAME_SIZE, index % FRAME_SIZE);
+        let (row, col) = (y / CELL_PX, x / CELL_PX);
+        let (dy, dx) = (y % CELL_PX, x % CELL_PX);
+
+        // 罫線（マスの境目の2ピクセル、外枠は描かない）
+        if (row > 0 && dy < 2) || (col > 0 && dx < 2) {
+            *pixel = 1;
+            continue;
+        }
+
+        *pixel = match board[row][col] {
+            Some(Player::X) if on_cross(dx, dy) => 2,
+            Some(Player::O) if on_ring(dx, dy) => 3,
+            _ => 0,
+        };
+    }
+    pixels
+}
+
+/// マスの中の位置(dx, dy)がXの線（2本の対角線）の上か
+fn on_cross(dx: usize, dy: usize) -> bool {
+    let inside = (MARK_MARGIN..CELL_PX - MARK_MARGIN).contains(&dx)
+        && (MARK_MARGIN..CELL_PX - MARK_MARGIN).contains(&dy);
+    inside && (dx.abs_diff(dy) <= 1 || (dx + dy).abs_diff(CELL_PX - 1) <= 1)
+}
+
+/// マスの中の位置(dx, dy)がOの輪の上か
+/// 学習ポイント: 座標を2倍して中心を整数にし、距離の2乗で比べる（浮動小数点を使わない）
+fn on_ring(dx: usize, dy: usize) -> bool {
+    let outer = CELL_PX - 2 * MARK_MARGIN;
+    let inner = outer - 6;
+    let distance = (2 * dx).abs_diff(CELL_PX - 1).pow(2) + (2 * dy).abs_diff(CELL_PX - 1).pow(2);
+    (inner * inner..=outer * outer).contains(&distance)
+}
+
+/// 盤面の並びをアニメーションGIFにする（delay_csは1コマの表示時間、1/100秒単位）
+/// 最後のコマは3倍の時間表示し、繰り返し再生する
+pub fn encode(frames: &[Board], delay_cs: u16) -> Vec<u8> {
+    let size = (FRAME_SIZE as u16).to_le_bytes();
+    let mut gif = Vec::new();
+
+    // ヘッダーと画面の情報（グローバルパレットあり・4色）
+    gif.extend_from_slice(b"GIF89a");
+    gif.extend_from_slice(&size);
+    gif.extend_from_slice(&size);
+    gif.extend_from_slice(&[0x91, 0, 0]);
+    gif.extend(PALETTE.iter().flatten());
+
+    // 繰り返し再生の指定（NETSCAPE2.0拡張、0は無限ループ）
+    gif.extend_from_slice(&[0x21, 0xff, 0x0b]);
+    gif.extend_from_slice(b"NETSCAPE2.0");
+    gif.extend_from_slice(&[0x03, 0x01, 0x00, 0x00, 0x00]);
+
+    for (index, &board) in frames.iter().enumerate() {
+        let delay = if index + 1 == frames.len() { delay_cs.saturating_mul(3) } else { delay_cs };
+
+        // 表示時間（グラフィック制御拡張）
+        gif.extend_from_slice(&[0x21, 0xf9, 0x04, 0x00]);
+        gif.extend_from_slice(&delay.to_le_bytes());
+        gif.extend_from_slice(&[0x00, 0x00]);
+
+        // 画像の位置と大きさ（画面全体）
+        gif.push(0x2c);
+        gif.extend_from_slice(&[0, 0, 0, 0]);
+        gif.extend_from_slice(&size);
+        gif.extend_from_slice(&size);
+        gif.push(0x00);
+
+        // 画像データ（255バイトごとのサブブロックに分け、長さ0で終える）
+        gif.push(MIN_CODE_SIZE);
+        for block in lzw_uncompressed(&render_frame(board)).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend_from_slice(block);
+        }
+        gif.push(0x00);
+    }
+
+    gif.push(0x3b);
+    gif
+}
+
+/// ピクセルを圧縮せずにLZWのコード列にする（1コード＝1バイト）
+/// 学習ポイント: 辞書が埋まってコードが9ビットに増える前にクリアコードを送り、常に8ビットに保つ
+fn lzw_uncompressed(pixels: &[u8]) -> Vec<u8> {
+    let clear = 1u8 << MIN_CODE_SIZE;
+    let end = clear + 1;
+    let run = (1 << MIN_CODE_SIZE) - 2;
+
+    let mut codes = Vec::with_capacity(pixels.len() + pixels.len() / run + 2);
+    for chunk in pixels.chunks(run) {
+        codes.push(clear);
+        codes.extend_from_slice(chunk);
+    }
+    codes.push(end);
+    codes
+}
+
+// ============================================================================
+// テスト: GIF書き出しの検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::GameLogic;
+
+    #[test]
+    fn test_render_frame_draws_marks_in_their_cells() {
+        let board = GameLogic::parse_board("X../.O./...").unwrap();
+        let pixels = render_frame(board);
+        let at = |x: usize, y: usize| pixels[y * FRAME_SIZE + x];
+
+        // 左上のマスの中央はXの線、中央のマスの中央はOの輪の内側（背景）
+        assert_eq!(at(CELL_PX / 2, CELL_PX / 2), 2);
+        assert_eq!(at(CELL_PX + CELL_PX / 2, CELL_PX + CELL_PX / 2), 0);
+        assert!(pixels[CELL_PX * FRAME_SIZE..2 * CELL_PX * FRAME_SIZE].contains(&3));
+
+        // 罫線は描き、空のマスには何も描かない
+        assert_eq!(at(CELL_PX, 5), 1);
+        assert!((2 * CELL_PX + 2..FRAME_SIZE).all(|x| at(x, FRAME_SIZE - 10) == 0));
+        assert!(render_frame(GameLogic::empty_board()).iter().all(|&pixel| pixel < 2));
+    }
+
+    #[test]
+    fn test_encode_layout() {
+        let frames = crate::history::replay_frames((GameLogic::empty_board(), Player::X), &[(1, 1), (0, 0)]);
+        let gif = encode(&frames, 80);
+
+        assert!(gif.starts_with(b"GIF89a"));
+        assert_eq!(&gif[6..10], &[FRAME_SIZE as u8, 0, FRAME_SIZE as u8, 0]);
+        assert_eq!(gif.last(), Some(&0x3b));
+
+        // 1コマの画像データは、コードの並びをサブブロックに分けたもの
+        let codes = lzw_uncompressed(&render_frame(frames[0])).len();
+        let frame_bytes = 8 + 10 + 1 + codes + codes.div_ceil(255) + 1;
+        let header_bytes = 13 + PALETTE.len() * 3 + 19;
+        assert_eq!(gif.len(), header_bytes + frames.len() * frame_bytes + 1);
+    }
+
+    #[test]
+    fn test_lzw_clears_before_code_size_grows() {
+        let codes = lzw_uncompressed(&[0; 300]);
+        let clear = 1 << MIN_CODE_SIZE;
+        assert_eq!(codes[0], clear);
+        assert_eq!(codes[127], clear);
+        assert_eq!(codes.last(), Some(&(clear + 1)));
+        assert_eq!(codes.iter().filter(|&&code| code == clear).count(), 3);
+    }
+}
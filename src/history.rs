@@ -0,0 +1,258 @@
+// ============================================================================
+// Dioxus学習プロジェクト: 着手履歴
+// ============================================================================
+// このファイルは対局中の着手履歴と、直前に終わった対局の記録を管理します。
+//
+// 学習ポイント:
+// - Vecによる可変長の履歴管理
+// - 「進行中のデータ」と「確定したデータ」を分けて持つ設計
+// - 着手列から盤面を再構築する純粋関数（リプレイ）
+
+use crate::types::{Board, GameLogic, Player};
+
+// ============================================================================
+// 型定義: 着手履歴
+// ============================================================================
+#[derive(Clone, PartialEq, Debug)]
+pub struct MoveHistory {
+    pub start: (Board, Player),                  // 進行中の対局の開始局面と最初の手番
+    pub moves: Vec<(usize, usize)>,              // 進行中の対局の着手（置いた順）
+    pub last_game: Option<Vec<(usize, usize)>>,  // 直前に終わった対局の着手
+    pub last_start: (Board, Player),             // 直前に終わった対局の開始局面と最初の手番
+}
+
+impl Default for MoveHistory {
+    fn default() -> Self {
+        MoveHistory::starting_at(GameLogic::empty_board(), Player::X)
+    }
+}
+
+impl MoveHistory {
+    /// 指定した局面（練習問題・ハンディキャップ・読み込んだ盤面等）から始まる履歴
+    pub fn starting_at(board: Board, first: Player) -> Self {
+        MoveHistory { start: (board, first), moves: Vec::new(), last_game: None, last_start: (board, first) }
+    }
+
+    /// 着手を記録する
+    pub fn record(&mut self, row: usize, col: usize) {
+        self.moves.push((row, col));
+    }
+
+    /// 対局が終わったときに、進行中の履歴を開始局面とあわせて「直前の対局」として確定する
+    pub fn finish_game(&mut self) {
+        self.last_game = Some(self.moves.clone());
+        self.last_start = self.start;
+    }
+
+    /// 新しい対局のために進行中の履歴を消し、開始局面を覚え直す（直前の対局の記録は残す）
+    pub fn reset(&mut self, board: Board, first: Player) {
+        self.start = (board, first);
+        self.moves.clear();
+    }
+
+    /// 開始局面に進行中の着手を置き直した盤面と、次の手番
+    pub fn position(&self) -> (Board, Player) {
+        replay(self.start, &self.moves)
+    }
+
+    /// 「一手戻す」で取り消す手数
+    /// 学習ポイント:
+    /// - 2人対戦では直前の1手だけ
+    /// - AI対戦では人間の直前の着手まで（AIの応手とあわせて2手）戻し、人間の手番に返す
+    /// - AIの初手しかなければ戻しても再び同じ局面になるので0
+    pub fn undo_count(&self, ai_player: Option<Player>) -> usize {
+        let Some(ai) = ai_player else {
+            return usize::from(!self.moves.is_empty());
+        };
+
+        (0..self.moves.len())
+            .rev()
+            .find(|&index| player_for_ply(self.start.1, index) != ai)
+            .map_or(0, |index| self.moves.len() - index)
+    }
+
+    /// 手を取り消し、取り消した手数を返す
+    pub fn undo(&mut self, ai_player: Option<Player>) -> usize {
+        let count = self.undo_count(ai_player);
+        self.moves.truncate(self.moves.len() - count);
+        count
+    }
+}
+
+/// index手目（0始まり）を指したプレイヤー（firstから交互）
+pub fn player_for_ply(first: Player, index: usize) -> Player {
+    if index.is_multiple_of(2) {
+        first
+    } else {
+        first.next()
+    }
+}
+
+/// 着手列の先頭からsteps手目までを開始局面に置き直す（最初の手番から交互）
+/// 学習ポイント: 練習問題・ハンディキャップ・読み込んだ盤面の対局も、開始局面があれば再生できる
+pub fn board_after(start: (Board, Player), moves: &[(usize, usize)], steps: usize) -> Board {
+    let (board, _) = replay(start, &moves[..steps.min(moves.len())]);
+    board
+}
+
+/// 開始局面と最初の手番に着手列を置き直し、盤面と次の手番を返す
+/// 学習ポイント: fold()で（盤面, 手番）の組を1手ずつ積み上げる
+fn replay((board, first): (Board, Player), moves: &[(usize, usize)]) -> (Board, Player) {
+    moves.iter().fold((board, first), |(mut board, player), &(row, col)| {
+        GameLogic::try_place(&mut board, row, col, player);
+        (board, player.next())
+    })
+}
+
+/// 再生の各コマの盤面（開始局面から最終局面まで、着手数+1枚）
+/// 学習ポイント: 書き出し（GIF等）の形式に依存しないので、コマの並びだけをテストできる
+pub fn replay_frames(start: (Board, Player), moves: &[(usize, usize)]) -> Vec<Board> {
+    (0..=moves.len()).map(|steps| board_after(start, moves, steps)).collect()
+}
+
+// ============================================================================
+// テスト: 着手履歴の検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BoardBuilder;
+
+    #[test]
+    fn test_finish_game_keeps_history_and_reset_clears_live() {
+        let mut history = MoveHistory::default();
+        history.record(0, 0);
+        history.record(1, 1);
+        history.record(0, 1);
+
+        history.finish_game();
+        assert_eq!(history.last_game, Some(vec![(0, 0), (1, 1), (0, 1)]));
+
+        // 新しい対局では進行中の履歴だけが消える
+        history.reset(GameLogic::empty_board(), Player::X);
+        assert!(history.moves.is_empty());
+        assert_eq!(history.last_game, Some(vec![(0, 0), (1, 1), (0, 1)]));
+    }
+
+    #[test]
+    fn test_board_after() {
+        let empty = (GameLogic::empty_board(), Player::X);
+        let moves = [(0, 0), (1, 1), (0, 1)];
+        assert_eq!(board_after(empty, &moves, 0), GameLogic::empty_board());
+        assert_eq!(board_after(empty, &moves, 2), BoardBuilder::new().x(0, 0).o(1, 1).build());
+        assert_eq!(board_after(empty, &moves, 9), BoardBuilder::new().x(0, 0).o(1, 1).x(0, 1).build());
+
+        // 開始局面（Xが中央に置いた局面、Oの手番）から置き直す
+        let start = (BoardBuilder::new().x(1, 1).build(), Player::O);
+        assert_eq!(board_after(start, &[(0, 0), (2, 2)], 1), BoardBuilder::new().x(1, 1).o(0, 0).build());
+    }
+
+    #[test]
+    fn test_finish_game_keeps_start_position() {
+        // 練習問題等の局面から始めた対局は、開始局面ごと「直前の対局」になる
+        let start = BoardBuilder::new().x(1, 1).build();
+        let mut history = MoveHistory::starting_at(start, Player::O);
+        history.record(0, 0);
+        history.finish_game();
+
+        // 新しい対局を空の盤面から始めても、直前の対局の開始局面は変わらない
+        history.reset(GameLogic::empty_board(), Player::X);
+        assert_eq!(history.last_start, (start, Player::O));
+        let last_game = history.last_game.clone().unwrap();
+        assert_eq!(
+            board_after(history.last_start, &last_game, last_game.len()),
+            BoardBuilder::new().x(1, 1).o(0, 0).build()
+        );
+    }
+
+    #[test]
+    fn test_replay_frames() {
+        let empty = (GameLogic::empty_board(), Player::X);
+        let moves = [(0, 0), (1, 1), (0, 1)];
+        let frames = replay_frames(empty, &moves);
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0], GameLogic::empty_board());
+        assert_eq!(frames[2], BoardBuilder::new().x(0, 0).o(1, 1).build());
+        assert_eq!(frames[3], board_after(empty, &moves, 3));
+
+        // 着手がなければ開始局面の1枚だけ
+        assert_eq!(replay_frames(empty, &[]), vec![GameLogic::empty_board()]);
+    }
+
+    #[test]
+    fn test_replay_frames_from_non_empty_start() {
+        // ハンディキャップ（Xが中央）の局面からOが先に指した対局：最初のコマは開始局面
+        let start = BoardBuilder::new().x(1, 1).build();
+        let frames = replay_frames((start, Player::O), &[(0, 0), (2, 2)]);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], start);
+        assert_eq!(frames[1], BoardBuilder::new().x(1, 1).o(0, 0).build());
+        assert_eq!(frames[2], BoardBuilder::new().x(1, 1).o(0, 0).x(2, 2).build());
+    }
+
+    #[test]
+    fn test_undo_single_ply_without_ai() {
+        let mut history = MoveHistory::default();
+        history.record(0, 0);
+        history.record(1, 1);
+
+        assert_eq!(history.undo(None), 1);
+        assert_eq!(history.moves, vec![(0, 0)]);
+        assert_eq!(history.undo(None), 1);
+        assert_eq!(history.undo(None), 0);
+    }
+
+    #[test]
+    fn test_undo_two_plies_against_ai() {
+        // 人間がX、AIがO：AIの応手と人間の着手をまとめて戻す
+        let mut history = MoveHistory::default();
+        for (row, col) in [(0, 0), (1, 1), (0, 1), (0, 2)] {
+            history.record(row, col);
+        }
+        assert_eq!(history.undo(Some(Player::O)), 2);
+        assert_eq!(history.moves, vec![(0, 0), (1, 1)]);
+        assert_eq!(history.position().0, BoardBuilder::new().x(0, 0).o(1, 1).build());
+
+        // AIの思考中（人間の着手が最後）は人間の1手だけ戻す
+        history.record(2, 2);
+        assert_eq!(history.undo(Some(Player::O)), 1);
+        assert_eq!(history.moves, vec![(0, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn test_undo_against_ai_that_moved_first() {
+        // AIがX：AIの初手しかなければ戻さない
+        let mut history = MoveHistory::default();
+        history.record(1, 1);
+        assert_eq!(history.undo_count(Some(Player::X)), 0);
+
+        history.record(0, 0);
+        history.record(2, 2);
+        assert_eq!(history.undo(Some(Player::X)), 2);
+        assert_eq!(history.moves, vec![(1, 1)]);
+    }
+
+    #[test]
+    fn test_undo_from_loaded_position() {
+        // 読み込んだ局面（Oの手番）から1手指して戻すと、読み込んだ局面に戻る
+        let start = BoardBuilder::new().x(1, 1).build();
+        let mut history = MoveHistory::starting_at(start, Player::O);
+        history.record(0, 0);
+        assert_eq!(history.position(), (BoardBuilder::new().x(1, 1).o(0, 0).build(), Player::X));
+
+        assert_eq!(history.undo(None), 1);
+        assert_eq!(history.position(), (start, Player::O));
+
+        // 人間がO・AIがX：Oから始まる局面でも、AIの応手と人間の着手をまとめて戻す
+        history.record(0, 0);
+        history.record(2, 2);
+        assert_eq!(player_for_ply(Player::O, 1), Player::X);
+        assert_eq!(history.undo_count(Some(Player::X)), 2);
+        assert_eq!(history.undo(Some(Player::X)), 2);
+        assert_eq!(history.position(), (start, Player::O));
+
+        // 新しい対局では開始局面も覚え直す
+        history.reset(GameLogic::empty_board(), Player::X);
+        assert_eq!(history.position(), (GameLogic::empty_board(), Player::X));
+    }
+}
@@ -0,0 +1,26 @@
+// ============================================================================
+// Dioxus学習プロジェクト: ゲームロジック（ライブラリ）
+// ============================================================================
+// このファイルはUIに依存しないゲームロジックをライブラリとしてまとめています。
+// Dioxusアプリ（main.rs）とターミナル版（bin/cli.rs）の両方から利用します。
+//
+// 学習ポイント:
+// - lib.rsとmain.rsを持つパッケージ（ライブラリ＋バイナリ）の構成
+// - src/bin/以下に置いたファイルは追加のバイナリになる
+// - ロジックをUIから切り離すことで、別のフロントエンドでも再利用できる
+
+pub mod types;
+pub mod puzzles;
+pub mod ai;
+pub mod session;
+pub mod confirm;
+pub mod history;
+pub mod stats;
+pub mod spectate;
+pub mod clock;
+pub mod controller;
+pub mod mode;
+pub mod gif;
+
+// テスト専用: 乱数に依存する機能の再現性
+mod determinism;
@@ -14,11 +14,62 @@ use dioxus::prelude::*;
 
 // モジュール定義とインポート
 // 学習ポイント: モジュラー設計により再利用性と保守性を向上
-mod types;
+// （UIに依存しないロジックはライブラリ（lib.rs）側に置き、ここでは使うだけ）
+mod clipboard;
 mod components;
+mod download;
+mod music;
+mod timer;
+use tic_tac_toe::{ai, clock, confirm, controller, gif, history, mode, puzzles, session, spectate, stats, types};
 
-use types::{Player, GameState, GameLogic};
-use components::{GameBoard, GameStatus, ResetButton};
+use types::{Board, Player, GameState, GameLogic, Handicap, Outcome, WinRule};
+use components::{
+    AdaptiveDifficulty, AiSelector, AutoResetSelector, BlitzClocks, CellSizePicker, DrawOffer, EvaluationBar, FairMatchPanel, GameBoard, GameStatus, HandicapPicker, line_markers, LiveRegion, MarkStylePicker, MusicControls, PieceCountBadges, PrincipalVariation, SymmetryBadge, PuzzleSelector, RecentGamesPanel, RecordingControls, ReplayViewer, ResetButton, ResultBanner, RotationPicker, ScoreBadge,
+    SettingToggle, SettingsDrawer, ThemePicker,
+};
+#[cfg(feature = "debug")]
+use components::DebugPanel;
+use puzzles::PUZZLES;
+use session::{Session, Settings};
+use confirm::TapConfirm;
+use ai::{AiStrategy, Rng, ADAPTIVE_LEVELS};
+use controller::GameController;
+use stats::GameRecord;
+use clock::BlitzClock;
+use mode::{AppMode, ModeCleanup};
+
+// ブラインドモードで駒を表示しておく時間（ミリ秒）
+const BLIND_REVEAL_MS: u64 = 1000;
+
+// リセット演出（対角線方向のワイプ）を表示しておく時間（ミリ秒）
+const RESET_WIPE_MS: u64 = 600;
+
+// 「一手戻す」で取り除いた駒をフェードアウト表示する時間（ミリ秒）
+const UNDO_FADE_MS: u64 = 300;
+
+// 共有された盤面を読み込んだ後、駒を強調しておく時間（ミリ秒）
+const LOAD_FLASH_MS: u64 = 1200;
+
+// 対局のGIFで1コマを表示する時間（1/100秒）
+const REPLAY_GIF_DELAY_CS: u16 = 80;
+
+// スコアの「+1」演出を表示しておく時間（ミリ秒）
+const SCORE_BUMP_MS: u64 = 900;
+
+// 「新しいゲーム」ボタンの案内を表示しておく時間（ミリ秒）
+const RESET_HINT_MS: u64 = 5000;
+
+// AIが着手するまでの待ち時間（ミリ秒、「考えている」ことが分かるように）
+const AI_THINK_MS: u64 = 500;
+
+// 設定ドロワーを開く歯車ボタンのid（閉じたときにフォーカスを戻す先）
+const SETTINGS_BUTTON_ID: &str = "settings-button";
+
+// 「手の見える化」でAIが選んだマスを光らせておく時間（ミリ秒、AI_THINK_MSの内数）
+const AI_INTENT_MS: u64 = 400;
+
+// ブリッツの時計を進める間隔（ミリ秒）
+const CLOCK_TICK_MS: u64 = 100;
 
 // ============================================================================
 // アセット定義（コンパイル時検証）
@@ -27,6 +78,7 @@ use components::{GameBoard, GameStatus, ResetButton};
 // これによりランタイムエラーを防ぎ、型安全性を確保
 const FAVICON: Asset = asset!("/assets/favicon.ico");
 const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
+const MUSIC: Asset = asset!("/assets/music.wav");
 
 
 
@@ -60,8 +112,9 @@ fn App() -> Element {
 
         // メインコンテナ
         // 学習ポイント: TailwindCSSのみによる完全なスタイリング
+        // 学習ポイント: dark:バリアントで暗い配色を指定し、transition-colorsで切り替え時に色がなめらかに変わる
         div {
-            class: "w-screen h-screen flex items-center justify-center p-1 bg-gradient-to-br from-blue-700 to-indigo-800",
+            class: "w-screen h-screen flex items-center justify-center p-1 bg-gradient-to-br from-blue-700 to-indigo-800 dark:from-slate-900 dark:to-indigo-950 transition-colors duration-300 motion-reduce:transition-none",
 
             // メインゲームコンポーネントを配置
             TicTacToe {}
@@ -78,22 +131,340 @@ fn App() -> Element {
 // - クロージャによるイベントハンドリング
 // - 純粋関数によるゲームロジック実装
 #[component]
-fn TicTacToe() -> Element {
+fn TicTacToe(
+    // コンパクト表示（タイトルと案内文を省き、アイコンだけで状態を示す。余白も詰める）
+    #[props(default)]
+    compact: bool
+) -> Element {
     // ============================================================================
     // 状態管理: Dioxusシグナルによるリアクティブ状態
     // ============================================================================
 
-    // ゲーム盤面の状態（3x3の2次元配列）
-    // 学習ポイント: use_signalによる状態の初期化、自動再レンダリング
-    let mut board = use_signal(|| GameLogic::empty_board());
+    // 前回保存したセッション（なければ初期状態）と、保存先が使えるかどうか
+    // 学習ポイント: use_hookで初回レンダリング時に一度だけ読み込む
+    let (saved, storage_ok) = use_hook(session::load);
+    let (saved_state, saved_player) = saved.game();
+
+    // 保存先（localStorage等）が使えるか（使えなければメモリ上だけで続ける）
+    let mut storage_available = use_signal(|| storage_ok);
+
+    // 対局の進行（盤面・手番・ゲーム状態・着手履歴・スコア・引き分けの提案・勝利ラインのルール）
+    // 学習ポイント: 着手・リセット・一手戻すなどの遷移はUIに依存しないGameControllerに任せ、
+    // コンポーネントはそのメソッドを呼ぶだけにする（ターミナル版と同じ遷移を使う）
+    let mut game = use_signal(|| {
+        // 読み込めない盤面はSession側で空の盤面に戻してあるので、失敗したときも空の盤面から始める
+        let mut game = GameController::from_start_with(saved.start, saved.board, saved.settings.win_rule).unwrap_or_default();
+        game.rule = saved.settings.win_rule;
+        game.score = saved.score;
+        game
+    });
+
+    // ゲーム盤面（3x3の2次元配列）・現在のプレイヤー・ゲームの現在状態
+    // 学習ポイント: use_memoは値が変わったときだけ読み取った側を再実行するので、
+    // 1つのシグナルにまとめても、盤面だけを読む副作用がスコアの変化などで動き直さない
+    let board = use_memo(move || game.read().board);
+    let current_player = use_memo(move || game.read().current_player);
+    let game_state = use_memo(move || game.read().game_state);
+
+    // ブラインドモード（置いた駒が一定時間後に見えなくなる）のON/OFF
+    // 学習ポイント: 描画のみに影響する設定値もシグナルで管理
+    let mut blind_mode = use_signal(|| saved.settings.blind_mode);
+
+    // ブラインドモードで現在表示中のセル（置いた直後のみtrue）
+    // 学習ポイント: 盤面（board）とは別に「見た目の状態」を管理する
+    let mut revealed = use_signal(|| [[false; 3]; 3]);
+
+    // ハイコントラスト表示のON/OFF（OSの「コントラストを上げる」設定はCSS側で自動対応）
+    let mut high_contrast = use_signal(|| saved.settings.high_contrast);
+
+    // アニメーションを減らす設定（OSのprefers-reduced-motionはCSS側で自動対応）
+    let mut reduce_motion = use_signal(|| saved.settings.reduce_motion);
+
+    // 駒の記号テーマ（デフォルトはX/OのSVGアイコン）
+    let mut symbol_theme = use_signal(|| saved.settings.theme);
+
+    // 座標ラベルの表示ON/OFF（教えるとき用）
+    let mut show_coordinates = use_signal(|| false);
+
+    // セル（クリック対象）の大きさ
+    let mut cell_size = use_signal(|| saved.settings.cell_size);
+
+    // クラシックテーマの駒の描画方法（SVG/CSS、デフォルトはSVG）
+    let mut mark_style = use_signal(|| saved.settings.mark_style);
+
+    // 勝利ラインのルール（「斜めなし」では横・縦だけで勝敗を判定する）
+    // 学習ポイント: 設定で選んだルール（rule_setting、保存する値）と、いまの対局が使うルール（win_rule）を分ける
+    // （観戦中はリンクの標準ルールで指すので、利用者の設定を上書きせず、対局に戻るときに設定から戻す）
+    let mut rule_setting = use_signal(|| saved.settings.win_rule);
+    let win_rule = use_memo(move || game.read().rule);
+
+    // 盤面の表示の向き（見た目だけの回転、盤面のデータとクリック座標は変わらない）
+    let mut rotation = use_signal(|| saved.settings.rotation);
+
+    // 理論上の勝者（「勝勢」になった時点で一度だけ記録する）
+    // 学習ポイント: Option<Player>による「まだ決まっていない」状態の表現
+    let mut decided = use_signal(|| match saved_state {
+        GameState::Playing => GameLogic::is_decided_with(saved.board, saved_player, saved.settings.win_rule),
+        _ => None,
+    });
+
+    // 選択中の練習問題（PUZZLESのインデックス、通常対局ではNone）
+    let mut active_puzzle = use_signal(|| None::<usize>);
+
+    // 対局結果の累計（リセットしても保持する）
+    let score = use_memo(move || game.read().score);
+
+    // 直前に見たスコアと、「+1」演出中のカウンター（結果と通し番号）
+    let mut last_score = use_signal(|| saved.score);
+    let mut score_bump = use_signal(|| None::<(Outcome, u32)>);
+
+    // 自動リセットまでの秒数設定（Noneはオフ、デフォルトはオフ）
+    let mut auto_reset_secs = use_signal(|| saved.settings.auto_reset_secs);
+
+    // 自動リセットまでの残り秒数（カウントダウン中のみSome）
+    let mut countdown = use_signal(|| None::<u32>);
+
+    // 実行中のカウントダウンタスク（キャンセル用に保持）
+    // 学習ポイント: spawnが返すTaskを保持して後からcancel()する
+    let mut auto_reset_task = use_signal(|| None::<Task>);
+
+    // 手順を記録中のプレイヤー（記録していなければNone）
+    let mut recording_side = use_signal(|| None::<Player>);
+
+    // 記録中の対局での、記録対象プレイヤーの着手
+    let mut recording_moves = use_signal(Vec::<(usize, usize)>::new);
+
+    // 記録済みの手順（対局が終わった時点で確定する）
+    // 学習ポイント: Copyでない値（Vec）もシグナルで管理できる
+    let mut recorded = use_signal(|| None::<(Player, Vec<(usize, usize)>)>);
+
+    // 対戦AIが担当するプレイヤー（2人対戦ではNone）
+    let mut ai_player = use_signal(|| saved.settings.ai_player);
+
+    // 対戦AIの戦略
+    let mut ai_strategy = use_signal(|| saved.settings.ai_strategy.clone());
+
+    // 強さの自動調整（ON/OFF・行き来する範囲・前回の調整以降の結果）
+    let mut adaptive = use_signal(|| false);
+    let mut adaptive_bounds = use_signal(|| (0, ADAPTIVE_LEVELS.len() - 1));
+    let mut adaptive_results = use_signal(Vec::<Outcome>::new);
+
+    // 対戦AIが使う疑似乱数（ランダム・最強の手の選択に使用）
+    let mut ai_rng = use_signal(|| Rng::new(0x5EED));
+
+    // 評価バーの表示ON/OFF
+    let mut show_evaluation = use_signal(|| false);
+
+    // タップで確定：1回目のタップでマスを選び、同じマスをもう一度タップしたら置くか
+    let mut tap_to_confirm = use_signal(|| false);
+
+    // タップで確定するときの選択中のマス（Escapeキーで取り消せる）
+    let mut confirm = use_signal(TapConfirm::default);
+
+    // 現在の局面の評価値（評価バーがONのときだけ計算）
+    // 学習ポイント: use_memoで盤面・手番が変わったときだけ再計算する（全探索は重いため）
+    let evaluation = use_memo(move || {
+        show_evaluation().then(|| GameLogic::evaluate_with(board(), current_player(), win_rule()))
+    });
+
+    // 手番のプレイヤーの必勝までの手数（評価バーがONで、必勝があるときだけSome）
+    let mate = use_memo(move || {
+        show_evaluation()
+            .then(|| GameLogic::mate_in_with(board(), current_player(), win_rule()))
+            .flatten()
+    });
+
+    // コーチモード（初心者向けの案内を表示する）
+    let mut coach_mode = use_signal(|| false);
+
+    // 直前の着手で起きた出来事の解説メモ（コーチモードのときだけ表示する）
+    let mut teaching_note = use_signal(|| None::<&'static str>);
+
+    // 現在の局面からの最善手順と、その結末（評価バーかコーチモードがONのときだけ計算）
+    // 学習ポイント: 盤面上の番号マーカーも同じ手順から作るので、表示と読み筋が食い違わない
+    let principal_variation = use_memo(move || {
+        (show_evaluation() || coach_mode()).then(|| {
+            let (snapshot, player) = (board(), current_player());
+            let line = GameLogic::principal_variation_with(snapshot, player, win_rule());
+            let result = GameLogic::apply_sequence_with(snapshot, &line, player, win_rule())
+                .map_or(GameState::Playing, |(_, state)| state);
+            (line, player, result)
+        })
+    });
+
+    // マウスが乗っている空きセル（分析モードの「ここに置いたら」の評価用）
+    let mut hovered = use_signal(|| None::<(usize, usize)>);
+
+    // ホバー中のセルに手番のプレイヤーが置いた場合の評価値（評価バーがON・対局中のときだけ計算）
+    // 学習ポイント: 駒は置かずに盤面のコピーを評価するので、盤面のシグナルは変わらない
+    let hover_preview = use_memo(move || {
+        let (row, col) = hovered()?;
+        if !show_evaluation() || game_state() != GameState::Playing {
+            return None;
+        }
+        GameLogic::evaluate_move_with(board(), row, col, current_player(), win_rule()).map(|e| ((row, col), e))
+    });
+
+    // 着手履歴（進行中の対局と、直前に終わった対局）
+    // 学習ポイント: 復元した盤面を開始局面として覚え、一手戻しても復元した局面より前には戻らない
+    let history = use_memo(move || game.read().history.clone());
+
+    // アプリのモード（対局・盤面編集・再生・観戦のどれか1つ、URLに観戦リンクがあれば観戦から始める）
+    // 学習ポイント: 複数のboolフラグの代わりに1つの列挙型で、同時に1つのモードだけを有効にする
+    let mut mode = use_signal(|| AppMode::from_spectate(spectate::from_location()));
+
+    // リセット直後の演出中かどうか（入力はブロックしない）
+    let mut resetting = use_signal(|| false);
+
+    // 最近の対局履歴（上限件数まで、セッションに保存）
+    let mut recent_games = use_signal(|| saved.recent_games.clone());
+
+    // 現在の対局を始めた時刻（所要時間の計算用、ミリ秒）
+    let mut game_started_ms = use_signal(timer::now_ms);
+
+    // 結果バナーを閉じたかどうか（新しい対局で再表示する）
+    let mut banner_dismissed = use_signal(|| false);
+
+    // 最善手での早送り中かどうか
+    let mut fast_forwarding = use_signal(|| false);
+
+    // 「一手戻す」で取り除いた駒の残像（セルごとに、駒と取り消しの通し番号）
+    // 学習ポイント: 通し番号で「自分が出した残像か」を見分け、連続で戻しても古いタイマーが新しい残像を消さない
+    let mut removing = use_signal(|| [[None::<(Player, u32)>; 3]; 3]);
+    let mut undo_serial = use_signal(|| 0u32);
+
+    // 共有された盤面を読み込んだ直後に強調するセルと、読み込みの通し番号（古いタイマーが新しい強調を消さないように）
+    let mut loaded_cells = use_signal(|| [[false; 3]; 3]);
+    let mut load_serial = use_signal(|| 0u32);
+
+    // 人間の入力のロック（AIの手番・思考中はtrue）
+    let mut input_locked = use_signal(|| false);
+
+    // BGMの再生ON/OFFと音量（0〜100）
+    let mut music_enabled = use_signal(|| false);
+    let mut music_volume = use_signal(|| 50u8);
+
+    // ユーザーの操作の回数（ブラウザの自動再生制限への対応、0ならまだ操作していない）
+    let mut interactions = use_signal(|| 0u32);
+
+    // BGMの音声要素（最初の描画で1つだけ作る）
+    // 学習ポイント: use_hookで作った値は再描画されても作り直されない
+    let music = use_hook(|| music::BackgroundMusic::new(&MUSIC.to_string()));
+
+    // 公平な対戦モード（対局ごとに先手を入れ替える）
+    let mut fair_match = use_signal(|| false);
+
+    // 公平な対戦モードでの対局ごとの先手と結果（先手・後手の集計用、ハンディキャップではOが先手）
+    let mut fair_outcomes = use_signal(Vec::<(Player, Outcome)>::new);
+
+    // 公平な対戦モードでの対局結果だけの一覧（1局目から順、3局勝負の判定用）
+    let fair_results = move || fair_outcomes().into_iter().map(|(_, outcome)| outcome).collect::<Vec<_>>();
+
+    // 先手交代の対戦（MATCH_GAMES局で勝ち数を競い、同点なら延長戦）
+    let mut match_mode = use_signal(|| false);
+
+    // 公平な対戦モードで、現在の対局の先手（X）を人間が持つか
+    let mut fair_human_starts = use_signal(|| true);
+
+    // ハンディキャップ（対局開始時に置いておく駒）
+    let mut handicap = use_signal(|| Handicap::NONE);
+
+    // 手の見える化：AIが置く前に選んだマスを光らせるか
+    let mut show_ai_intent = use_signal(|| false);
+
+    // AIが置こうとしているマス（光らせている間だけSome）
+    let mut ai_intent = use_signal(|| None::<(usize, usize)>);
+
+    // ゲームの世代（リセット・盤面の置き換え・一手戻すたびに増やし、待機中のAIの着手を無効にする）
+    let game_epoch = use_signal(|| 0u32);
+
+    // スクリーンリーダー向けのお知らせ（AIの着手を読み上げる、通し番号付き）
+    let mut announcement = use_signal(|| (String::new(), 0u32));
+
+    // 設定ドロワーが開いているか
+    let mut settings_open = use_signal(|| false);
+
+    // クリップボードからの読み込みに失敗した理由（表示用）
+    let mut import_error = use_signal(|| None::<String>);
+
+    // 対局のGIFを保存できなかった理由（表示用）
+    let mut export_error = use_signal(|| None::<String>);
+
+    // 引き分けを提案しているプレイヤー（2人対戦のみ、着手・リセットで取り消す）
+    let draw_offer = use_memo(move || game.read().draw_offer);
+
+    // 「新しいゲーム」ボタンの案内を表示済みか（保存して、二度と表示しない）
+    let mut reset_hint_seen = use_signal(|| saved.reset_hint_seen);
+
+    // 「新しいゲーム」ボタンの案内を表示中か
+    let mut show_reset_hint = use_signal(|| false);
+
+    // 案内の判定で前回見たゲーム状態（起動時に復元した終局済みの盤面では案内しないため）
+    let mut hint_last_state = use_signal(|| game.peek().game_state);
+
+    // 編集した盤面を反映できなかった理由（表示用）
+    let mut edit_error = use_signal(|| None::<&'static str>);
+
+    // ブリッツの対局時計（OFFならNone）
+    let mut blitz = use_signal(|| None::<BlitzClock>);
+
+    // ============================================================================
+    // イベントハンドラー: モードの切り替え
+    // ============================================================================
+    // 前のモードの後片付け（待機中の着手の無効化・選択の解除）をしてから切り替える
+    // 学習ポイント: 何を片付けるかはAppMode::transitionが決め、ここでは適用するだけ
+    let mut enter_mode = move |next: AppMode| {
+        let cleanup = switch_mode(mode, game_epoch, next);
+        if cleanup.clear_selection {
+            hovered.set(None);
+            ai_intent.set(None);
+            confirm.set(TapConfirm::default());
+        }
+    };
+
+    // ============================================================================
+    // イベントハンドラー: 対局結果の記録
+    // ============================================================================
+    // 終局した対局を最近の対局履歴・対戦の集計などに記録する（着手・時間切れ・引き分けの合意で共通）
+    // 学習ポイント:
+    // - スコアと着手履歴への記録はGameControllerが終局と同時に済ませている
+    // - シグナルはCopyなので、それだけを捕捉したクロージャもCopyになり複数の場所から呼べる
+    let mut record_outcome = move |outcome: Outcome| {
+        // 練習問題・観戦は対象外（GameControllerもスコアに記録していない）
+        if game.peek().unscored {
+            return;
+        }
+
+        // 最近の対局履歴に追加（記録の組み立てはstatsモジュールの純粋関数）
+        let record = GameRecord::new(outcome, ai_player(), board(), game_started_ms(), timer::now_ms());
+        recent_games.with_mut(|games| stats::push_record(games, record));
+
+        // 公平な対戦モードなら先手・後手の集計に加える
+        // 対戦の勝者が決まった後の対局は集計しない
+        let match_decided = match_mode()
+            && matches!(stats::match_status(&fair_results(), stats::MATCH_GAMES), stats::MatchStatus::Decided(_));
+        if fair_match() && !match_decided {
+            let starter = game.peek().history.start.1;
+            fair_outcomes.with_mut(|outcomes| outcomes.push((starter, outcome)));
+        }
 
-    // 現在のプレイヤー（Xから開始）
-    // 学習ポイント: enumを使った型安全な状態管理
-    let mut current_player = use_signal(|| Player::X);
+        // 強さの自動調整：連勝・連敗に応じて次の対局からAIの強さを1段変える
+        // （段階にない戦略「過去の自分」を選んでいるときは調整しない）
+        if let (true, Some(ai)) = (adaptive(), ai_player()) {
+            adaptive_results.with_mut(|results| results.push(outcome));
+            let level = ADAPTIVE_LEVELS.iter().position(|strategy| *strategy == ai_strategy());
+            if let Some(next) = level.and_then(|level| ai::adapt_level(level, &adaptive_results(), ai, adaptive_bounds())) {
+                ai_strategy.set(ADAPTIVE_LEVELS[next].clone());
+                adaptive_results.set(Vec::new());
+            }
+        }
 
-    // ゲームの現在状態（初期状態は「プレイ中」）
-    // 学習ポイント: 複合的な状態を表現するenum
-    let mut game_state = use_signal(|| GameState::Playing);
+        // 記録中なら手順を確定して記録を終える
+        if let Some(side) = recording_side() {
+            recorded.set(Some((side, recording_moves())));
+            recording_side.set(None);
+        }
+    };
 
     // ============================================================================
     // イベントハンドラー: セルクリック処理
@@ -103,27 +474,300 @@ fn TicTacToe() -> Element {
     // - クロージャによるイベントハンドリング
     // - 状態の不変性を保つ更新パターン
     // - ゲームロジックとUIの分離
-    let handle_cell_click = move |(row, col): (usize, usize)| {
-        // 無効なクリックをガード（ゲーム終了時または既に置かれたセル）
-        if game_state() != GameState::Playing || board()[row][col].is_some() {
+    let mut handle_cell_click = move |(row, col): (usize, usize)| {
+        // 無効なクリックをガード（AIの手番中・ゲーム終了時・盤面編集中や再生中）
+        if input_locked() || game_state() != GameState::Playing || !mode().allows_moves() {
             return;
         }
 
-        // 盤面を更新（with_mutによる安全な変更）
-        // 学習ポイント: with_mutによる状態の変更、借用チェッカーとの協調
-        board.with_mut(|b| {
-            b[row][col] = Some(current_player());
+        // 着手（駒の配置・履歴・勝敗判定・スコア・手番の交代）はGameControllerに任せる
+        // 学習ポイント:
+        // - with_mutによる状態の変更、借用チェッカーとの協調
+        // - 最新の盤面をwith_mut内で確認してから書き込むことで、
+        //   再描画前の連続クリック（ダブルクリック）でも二重配置や二重の手番交代を防ぐ
+        // - 置いたセルを通るラインだけを調べて勝敗判定し、引き分けの提案は着手で取り下げる
+        let placed = game.with_mut(|g| {
+            let player = g.current_player;
+            g.play(row, col).map(|state| (player, state))
         });
+        let Ok((player, new_game_state)) = placed else {
+            return;
+        };
+
+        // 確定待ちの選択は、どちらかが置いたら取り消す（選んだマスが埋まることもある）
+        confirm.set(TapConfirm::default());
+
+        // 読み込み直後の強調は、次の着手で通常の表示に戻す（着手した駒は強調しない）
+        loaded_cells.set([[false; 3]; 3]);
+
+        // 記録対象のプレイヤーの着手を記録
+        if recording_side() == Some(player) {
+            recording_moves.with_mut(|moves| moves.push((row, col)));
+        }
 
-        // 勝敗判定を実行
-        let new_game_state = GameLogic::check_game_state(board());
-        game_state.set(new_game_state);
+        // ラインがふさがったなどの出来事を解説メモにする（ルールには影響しない）
+        teaching_note.set(
+            GameLogic::events_after_move_with(board(), row, col, win_rule())
+                .first()
+                .map(|event| event.note()),
+        );
 
-        // ゲームが継続中なら次のプレイヤーに交代
-        // 学習ポイント: 条件付き状態更新、プレイヤー交代ロジック
+        // ブリッツ：着手を終えたプレイヤーに加算時間を足す
+        blitz.with_mut(|clock| {
+            if let Some(clock) = clock {
+                clock.complete_move(player);
+            }
+        });
+
+        // 通常対局が終了したら最近の対局履歴などに記録
+        if let Some(outcome) = new_game_state.outcome() {
+            record_outcome(outcome);
+        }
+
+        // ゲームが継続中なら（手番はGameControllerが交代済み）
         if new_game_state == GameState::Playing {
-            current_player.set(current_player().next());
+            // まだ勝勢になっていなければ、最善手順での勝者を判定
+            // 学習ポイント: 一度だけ発火するイベント（Noneの時だけ更新）
+            if decided().is_none() {
+                decided.set(GameLogic::is_decided_with(board(), current_player(), win_rule()));
+            }
+        }
+
+        // ブラインドモード：置いた駒を一定時間だけ表示し、その後隠す
+        // 学習ポイント:
+        // - spawnによる非同期タスクとタイマー処理
+        // - 表示時の世代を覚えておき、待機中にリセット等で対局が変わっていたら、新しい対局の駒を隠さない
+        if blind_mode() {
+            revealed.with_mut(|r| r[row][col] = true);
+            let epoch = *game_epoch.peek();
+            spawn(async move {
+                timer::sleep_ms(BLIND_REVEAL_MS).await;
+                if *game_epoch.peek() == epoch {
+                    revealed.with_mut(|r| r[row][col] = false);
+                }
+            });
+        }
+    };
+
+    // 人間の操作による着手（通常の対局中のみ、観戦中は盤面に触れない）
+    // タップで確定がONなら、同じマスを2回選んだときだけ置く
+    let mut human_move = move |cell: (usize, usize)| {
+        if !mode().is_play() {
+            return;
+        }
+        if !tap_to_confirm() {
+            handle_cell_click(cell);
+        } else if let Some(cell) = confirm.with_mut(|c| c.tap(cell)) {
+            handle_cell_click(cell);
+        }
+    };
+
+    // ============================================================================
+    // 副作用: 観戦リンクの対局の再生
+    // ============================================================================
+    // 起動時に一度だけ、リンクから再現した着手列を順番に指していく
+    // 学習ポイント:
+    // - use_hookで初回描画時に一度だけタスクを起動する
+    // - 途中で観戦をやめたら（モードが観戦でなくなったら）再生を打ち切る
+    use_hook(move || {
+        let Some(link) = mode.peek().spectate_link() else {
+            return;
+        };
+        // リンクの着手列は空の盤面・標準ルールで指されたものなので、復元した盤面やルールは使わない
+        // （設定のルールはrule_settingに残り、観戦をやめるとreset_gameが戻す）
+        game.with_mut(|g| {
+            g.rule = WinRule::Standard;
+            g.restart(GameLogic::empty_board(), Player::X);
+            g.unscored = true;  // 観戦の結果はスコアに数えない
+        });
+        spawn(async move {
+            for cell in link.moves() {
+                timer::sleep_ms(AI_THINK_MS).await;
+                if !mode().is_spectating() {
+                    return;
+                }
+                handle_cell_click(cell);
+            }
+        });
+    });
+
+    // ============================================================================
+    // 非同期処理: ブリッツの時計
+    // ============================================================================
+    // 一定間隔で手番のプレイヤーの時計を進め、時間切れなら相手の勝ちで終局させる
+    // 学習ポイント:
+    // - use_futureはコンポーネントの間ずっと動き続ける非同期タスク
+    // - 実際の経過時間（now_msの差）で進めるので、スリープの遅れが誤差にならない
+    // - peek()で読み、ループ内の読み取りが再実行の原因にならないようにする
+    use_future(move || async move {
+        let mut last_ms = timer::now_ms();
+        loop {
+            timer::sleep_ms(CLOCK_TICK_MS).await;
+            let now_ms = timer::now_ms();
+            let elapsed_ms = now_ms.saturating_sub(last_ms);
+            last_ms = now_ms;
+
+            // 対局中の通常対局だけ時計を進める（練習問題・観戦・編集・再生では止める）
+            if game.peek().game_state != GameState::Playing
+                || active_puzzle.peek().is_some()
+                || !mode.peek().is_play()
+            {
+                continue;
+            }
+
+            let player = game.peek().current_player;
+            let timed_out = blitz.with_mut(|clock| clock.as_mut().is_some_and(|c| c.tick(player, elapsed_ms)));
+            if timed_out && game.with_mut(|g| g.forfeit()) {
+                record_outcome(Outcome::Win(player.opponent()));
+            }
+        }
+    });
+
+    // ============================================================================
+    // 副作用: スコアの「+1」演出
+    // ============================================================================
+    // スコアが1局分増えたら、増えたカウンターに一定時間だけ「+1」を表示する
+    // 学習ポイント:
+    // - 直前の値（last_score）と比べて、どのカウンターが増えたかを判定する
+    // - 減った場合（リセット等）はincrement_fromがNoneを返すので演出しない
+    use_effect(move || {
+        let current = score();
+        let previous = *last_score.peek();
+        last_score.set(current);
+
+        let Some(outcome) = current.increment_from(previous) else {
+            return;
+        };
+        if *reduce_motion.peek() {
+            return;
+        }
+
+        let serial = score_bump.peek().map_or(1, |(_, serial)| serial + 1);
+        score_bump.set(Some((outcome, serial)));
+        spawn(async move {
+            timer::sleep_ms(SCORE_BUMP_MS).await;
+            if matches!(*score_bump.peek(), Some((_, s)) if s == serial) {
+                score_bump.set(None);
+            }
+        });
+    });
+
+    // ============================================================================
+    // 副作用: 「新しいゲーム」ボタンの案内
+    // ============================================================================
+    // 初めて対局が終わったときだけ、リセットボタンを指す案内を一定時間表示する
+    // 学習ポイント:
+    // - 表示済みのフラグはpeekで読み、終局（game_state）の変化だけで再実行する
+    // - 前回の状態と比べて「対局中から終局へ変わった」ときだけ案内する（終局済みの盤面の復元では出さない）
+    use_effect(move || {
+        let state = game_state();
+        let was_playing = *hint_last_state.peek() == GameState::Playing;
+        hint_last_state.set(state);
+        if !(was_playing && state.is_over()) || *reset_hint_seen.peek() {
+            return;
+        }
+        reset_hint_seen.set(true);
+        show_reset_hint.set(true);
+        spawn(async move {
+            timer::sleep_ms(RESET_HINT_MS).await;
+            show_reset_hint.set(false);
+        });
+    });
+
+    // ============================================================================
+    // 副作用: BGMの再生・一時停止
+    // ============================================================================
+    // 学習ポイント: ON/OFF・音量・操作の回数が変わったときだけ再実行される
+    // （盤面の更新などの再描画では実行されず、再生中のplay()は最初からにならないので、曲が鳴り直さない）
+    use_effect(move || {
+        if music_enabled() && interactions() > 0 {
+            music.play(music_volume());
+        } else {
+            music.pause();
+        }
+    });
+
+    // ============================================================================
+    // 副作用: AIの着手
+    // ============================================================================
+    // AIの手番になったら入力をロックし、少し待ってから着手する
+    // 学習ポイント:
+    // - 盤面・手番・AI設定の変化でuse_effectが再実行され、ロックも毎回決め直す
+    // - 待機中にリセット等でゲームが変わっていたら何もしない（ロックは再実行側で更新済み）
+    // - 予約時の世代と盤面を覚えておき、リセット後の同じ盤面に古い手を置かない
+    use_effect(move || {
+        let (snapshot, player) = (board(), current_player());
+        // 対局のモード以外（編集・再生・観戦）ではAIは指さない（対局に戻ると再実行される）
+        let locked = ai::is_ai_turn(ai_player(), player, game_state()) && mode().is_play();
+        input_locked.set(locked);
+        if !locked {
+            return;
         }
+        let pending = ai::PendingMove::new(*game_epoch.peek(), snapshot, player);
+
+        spawn(async move {
+            // 手の見える化：考える時間の後半で選んだマスを光らせるため、先に短く待つ
+            let show_intent = show_ai_intent();
+            let think_ms = if show_intent { AI_THINK_MS.saturating_sub(AI_INTENT_MS) } else { AI_THINK_MS };
+            timer::sleep_ms(think_ms).await;
+            if !pending.is_current(game_epoch(), board(), ai_player()) {
+                return;
+            }
+
+            let strategy = ai_strategy();
+            let choice = ai_rng.with_mut(|rng| strategy.choose_move(snapshot, player, win_rule(), rng));
+
+            // 選んだマスを一定時間光らせてから置く
+            // 学習ポイント: 待機の後で局面・設定を確かめ直し、変わっていたら光らせたマスだけ消して中止する
+            if let (true, Some(cell)) = (show_intent, choice) {
+                ai_intent.set(Some(cell));
+                timer::sleep_ms(AI_INTENT_MS).await;
+                if *ai_intent.peek() == Some(cell) {
+                    ai_intent.set(None);
+                }
+                if !pending.is_current(game_epoch(), board(), ai_player()) {
+                    return;
+                }
+            }
+
+            // 着手の直前にロックを外す（手を選べなかったときはロックしたままにする）
+            let Some(cell) = release_ai_lock(choice, input_locked) else {
+                dioxus::logger::tracing::warn!("AIが着手を選べませんでした（{}の手番）", player.symbol());
+                return;
+            };
+            handle_cell_click(cell);
+
+            // 置けたときだけAIの手を読み上げる（観戦の再生中は読み上げない）
+            let spectating = mode.peek().is_spectating();
+            if let (true, Some(message)) = (board() != snapshot, ai::move_announcement(ai_player(), player, cell, spectating)) {
+                announcement.with_mut(|(text, serial)| {
+                    *text = message;
+                    *serial += 1;
+                });
+            }
+        });
+    });
+
+    // ============================================================================
+    // イベントハンドラー: 自動リセットのキャンセル
+    // ============================================================================
+    // 学習ポイント: Option::take()で取り出してからTask::cancel()
+    let mut cancel_auto_reset = move || {
+        if let Some(task) = auto_reset_task.take() {
+            task.cancel();
+        }
+        countdown.set(None);
+    };
+
+    // ============================================================================
+    // イベントハンドラー: ユーザーの操作
+    // ============================================================================
+    // 操作があれば自動リセットを取り消し、操作の回数を数える
+    // 学習ポイント: 回数が変わるたびにBGMの副作用が再実行されるので、
+    // 自動再生が拒否された再生も次の操作で再試行される（再生中ならplay()は何もしない）
+    let mut note_interaction = move || {
+        cancel_auto_reset();
+        interactions += 1;
     };
 
     // ============================================================================
@@ -131,11 +775,445 @@ fn TicTacToe() -> Element {
     // ============================================================================
     // ゲームを初期状態にリセットする処理
     // 学習ポイント: 複数の状態を一括でリセットするパターン
-    let reset_game = move |_| {
-        board.set(GameLogic::empty_board());    // 盤面をクリア
-        current_player.set(Player::X);          // プレイヤーをXにリセット
-        game_state.set(GameState::Playing);     // ゲーム状態をプレイ中に
+    let mut reset_game = move |_: ()| {
+        // ハンディキャップがあれば駒を置いた局面から、なければ空の盤面からXが始める
+        let (start_board, start_player) = handicap()
+            .start()
+            .unwrap_or((GameLogic::empty_board(), Player::X));
+
+        cancel_auto_reset();                    // 保留中の自動リセットを取り消し
+        advance_epoch(game_epoch);              // 待機中のAIの着手を無効にする
+        show_reset_hint.set(false);             // 「新しいゲーム」の案内を消す
+        teaching_note.set(None);                // 解説メモを消す
+        game.with_mut(|g| {
+            g.rule = rule_setting();            // 観戦で変えたルールを設定のルールに戻す
+            g.restart(start_board, start_player);  // 開始局面・最初の手番から（スコアと前の対局は残す）
+        });
+        revealed.set([[false; 3]; 3]);          // ブラインド表示をクリア
+        removing.set([[None; 3]; 3]);           // 取り消した駒の残像をクリア
+        loaded_cells.set([[false; 3]; 3]);      // 読み込み直後の強調をクリア
+        enter_mode(AppMode::Play);              // 観戦・盤面編集・再生をやめて対局に戻る（下書きは破棄）
+        decided.set(None);                      // 勝勢の記録をクリア
+        active_puzzle.set(None);                // 練習問題を終了
+        recording_moves.set(Vec::new());        // 記録途中の手順を破棄
+        confirm.set(TapConfirm::default());     // 確定待ちの選択を消す
+        input_locked.set(false);                // 入力ロックを解除（AIの手番なら再びロックされる）
+        game_started_ms.set(timer::now_ms());   // 所要時間の計測を開始
+        banner_dismissed.set(false);            // 結果バナーを再び表示できるようにする
+        ai_intent.set(None);                    // AIの「手の見える化」の強調を消す
+        if blitz().is_some() {
+            blitz.set(Some(BlitzClock::default()));  // ブリッツの時計を持ち時間に戻す
+        }
+
+        // 公平な対戦モード：局数の偶奇で先手を決め、AI対戦ならAIの担当を入れ替える
+        if fair_match() {
+            let human_starts = stats::human_starts(fair_outcomes().len());
+            fair_human_starts.set(human_starts);
+            if ai_player().is_some() {
+                ai_player.set(Some(if human_starts { Player::O } else { Player::X }));
+            }
+        }
+
+        // リセット演出：一定時間だけ表示して元に戻す
+        resetting.set(true);
+        spawn(async move {
+            timer::sleep_ms(RESET_WIPE_MS).await;
+            resetting.set(false);
+        });
+    };
+
+    // ============================================================================
+    // イベントハンドラー: 手順の記録開始・中止
+    // ============================================================================
+    // 記録は新しい対局の最初から始める
+    let set_recording = move |side: Option<Player>| {
+        if side.is_some() {
+            reset_game(());
+        }
+        recording_side.set(side);
+        recording_moves.set(Vec::new());
+    };
+
+    // ============================================================================
+    // 副作用: 対局終了後の自動リセット
+    // ============================================================================
+    // game_stateがPlaying以外になったらカウントダウンを開始する
+    // 学習ポイント:
+    // - use_effectは読み取ったシグナル（game_state）の変化で再実行される
+    // - peek()で読んだシグナル（設定値）は依存関係に含まれない
+    use_effect(move || {
+        if game_state() == GameState::Playing {
+            return;
+        }
+        let Some(secs) = *auto_reset_secs.peek() else {
+            return;
+        };
+
+        countdown.set(Some(secs));
+        let task = spawn(async move {
+            for remaining in (0..secs).rev() {
+                timer::sleep_ms(1000).await;
+                countdown.set(Some(remaining));
+            }
+
+            // 自分自身をキャンセルしないよう、先にタスクの記録を外してからリセット
+            auto_reset_task.set(None);
+            reset_game(());
+        });
+        auto_reset_task.set(Some(task));
+    });
+
+    // ============================================================================
+    // 副作用: セッションの自動保存
+    // ============================================================================
+    // スコア・設定・盤面のいずれかが変わるたびに保存する
+    // 学習ポイント: 読み取ったシグナルすべてが依存関係になる
+    use_effect(move || {
+        // 観戦中の盤面とルールはリンクのものなので、利用者のセッションを上書きしない
+        if mode().is_spectating() {
+            return;
+        }
+        let mut session = Session {
+            score: score(),
+            settings: Settings {
+                blind_mode: blind_mode(),
+                high_contrast: high_contrast(),
+                reduce_motion: reduce_motion(),
+                theme: symbol_theme(),
+                auto_reset_secs: auto_reset_secs(),
+                cell_size: cell_size(),
+                mark_style: mark_style(),
+                win_rule: rule_setting(),
+                rotation: rotation(),
+                ai_strategy: ai_strategy(),
+                ai_player: ai_player(),
+            },
+            board: board(),
+            start: history().start,
+            recent_games: recent_games(),
+            reset_hint_seen: reset_hint_seen(),
+            ..Session::default()
+        };
+        // 練習問題の盤面は保存しない（次回起動時に通常の対局として復元されないよう、空の盤面にする）
+        if active_puzzle().is_some() {
+            session.board = GameLogic::empty_board();
+            session.start = (GameLogic::empty_board(), Player::X);
+        }
+        // 学習ポイント: 失敗しても状態はメモリ上に残るので、ログと注記だけで続ける
+        // （storage_availableは読み取らずに書き込むだけなので、この副作用は再実行されない）
+        if let Err(error) = session::save(&session) {
+            dioxus::logger::tracing::warn!("セッションを保存できませんでした: {}", error);
+            storage_available.set(false);
+        }
+    });
+
+    // ============================================================================
+    // イベントハンドラー: 盤面の一括設定
+    // ============================================================================
+    // 盤面全体を検証してから置き換える（練習問題の読み込み等で使用）
+    // 学習ポイント:
+    // - ?演算子でエラー時は状態を一切変更せずに返す（GameController::loadも検証に失敗したら何も変えない）
+    // - 盤面から手番・ゲーム状態を導出して整合性を保つ
+    let mut load_board_with = move |new_board: Board, rule: WinRule| -> Result<(), &'static str> {
+        game.with_mut(|g| {
+            g.rule = rule;
+            g.load(new_board)
+        })?;
+
+        cancel_auto_reset();
+        advance_epoch(game_epoch);
+        teaching_note.set(None);
+        revealed.set([[false; 3]; 3]);
+        decided.set(None);
+        confirm.set(TapConfirm::default());
+        game_started_ms.set(timer::now_ms());
+        banner_dismissed.set(false);
+        enter_mode(AppMode::Play);
+        ai_intent.set(None);
+        Ok(())
+    };
+
+    // 通常の盤面の読み込みは設定のルールで行う
+    let mut set_board = move |new_board: Board| load_board_with(new_board, rule_setting());
+
+    // ============================================================================
+    // イベントハンドラー: 一手戻す
+    // ============================================================================
+    // 履歴から手を取り消し、残った手順で盤面を組み立て直す
+    // 学習ポイント:
+    // - 取り消す手数はモード（AI対戦か）に応じてhistory側で決める
+    // - 履歴が覚えている開始局面（ハンディキャップ・読み込んだ盤面等）に置き直すのはGameControllerが行う
+    let undo_move = move |_: MouseEvent| {
+        if game_state() != GameState::Playing || active_puzzle().is_some() || !mode().is_play() {
+            return;
+        }
+        let old_board = board();
+        if game.with_mut(|g| g.undo_turn(ai_player())) == 0 {
+            return;
+        }
+
+        let remaining = history();
+        let (new_board, player) = (board(), current_player());
+
+        // 取り除いた駒の残像を表示し、一定時間後に自分の分だけ消す（入力はブロックしない）
+        if !reduce_motion() {
+            let removed = GameLogic::diff(old_board, new_board);
+            let serial = undo_serial() + 1;
+            undo_serial.set(serial);
+            removing.with_mut(|cells| {
+                for &(row, col) in &removed {
+                    cells[row][col] = old_board[row][col].map(|piece| (piece, serial));
+                }
+            });
+            spawn(async move {
+                timer::sleep_ms(UNDO_FADE_MS).await;
+                removing.with_mut(|cells| {
+                    for (row, col) in removed {
+                        if matches!(cells[row][col], Some((_, s)) if s == serial) {
+                            cells[row][col] = None;
+                        }
+                    }
+                });
+            });
+        }
+
+        advance_epoch(game_epoch);
+        teaching_note.set(None);
+        revealed.set([[false; 3]; 3]);
+        decided.set(GameLogic::is_decided_with(new_board, player, win_rule()));
+
+        // 記録中なら、残った手順から記録対象の着手を取り直す
+        if let Some(side) = recording_side() {
+            let kept = remaining
+                .moves
+                .iter()
+                .enumerate()
+                .filter(|&(index, _)| history::player_for_ply(remaining.start.1, index) == side)
+                .map(|(_, &cell)| cell)
+                .collect();
+            recording_moves.set(kept);
+        }
+    };
+
+    // ============================================================================
+    // イベントハンドラー: 最善手で最後まで早送り
+    // ============================================================================
+    // 両者の最善手を1手ずつ指して終局まで進める（アニメーションを減らす設定なら待たずに進める）
+    // 学習ポイント:
+    // - 毎手、その時点の盤面・手番からbest_moveを求め直す（途中でAIが指しても破綻しない）
+    // - 終局した・リセット等で世代（game_epoch）が変わったらループを抜ける（新しい対局は早送りしない）
+    let fast_forward = move |_: MouseEvent| {
+        if game_state() != GameState::Playing || fast_forwarding() {
+            return;
+        }
+        fast_forwarding.set(true);
+        let epoch = *game_epoch.peek();
+        spawn(async move {
+            while game_state() == GameState::Playing && mode().is_play() && *game_epoch.peek() == epoch {
+                if !reduce_motion() {
+                    timer::sleep_ms(AI_THINK_MS).await;
+                }
+                // AIの手番ならAIに任せる（ロック中の着手は無視される）
+                if input_locked() {
+                    timer::sleep_ms(AI_THINK_MS).await;
+                    continue;
+                }
+                if *game_epoch.peek() != epoch {
+                    break;
+                }
+                let Some(cell) = GameLogic::best_move_with(board(), current_player(), win_rule()) else {
+                    break;
+                };
+                handle_cell_click(cell);
+            }
+            fast_forwarding.set(false);
+        });
+    };
+
+    // ============================================================================
+    // イベントハンドラー: 結果の共有
+    // ============================================================================
+    // 結果と盤面（絵文字グリッド）をクリップボードにコピーする
+    // 学習ポイント:
+    // - document::evalでJavaScript（navigator.clipboard）を呼び出す
+    // - serde_jsonで文字列をJavaScriptの文字列リテラルとして安全にエスケープ
+    let share_result = move |_: ()| {
+        let headline = match game_state() {
+            GameState::Won(player) => format!("三目並べ: {}の勝ち！", player.symbol()),
+            _ => "三目並べ: 引き分け".to_string(),
+        };
+        let text = format!("{}\n{}", headline, GameLogic::emoji_grid(board()));
+        if let Ok(literal) = serde_json::to_string(&text) {
+            document::eval(&format!("navigator.clipboard.writeText({})", literal));
+        }
+    };
+
+    // ============================================================================
+    // イベントハンドラー: クリップボードからの読み込み
+    // ============================================================================
+    // 共有された盤面（絵文字グリッドまたは文字の表記）を貼り付けて、その局面から再開する
+    // 学習ポイント:
+    // - and_then()で「読み取り → 解析 → 検証・反映」を失敗したところで止める
+    // - 失敗したときはset_boardまで進まないので、盤面は一切変わらない
+    let import_from_clipboard = move |_: MouseEvent| {
+        spawn(async move {
+            let result = clipboard::read_text().await.and_then(|text| {
+                let new_board = GameLogic::parse_shared_board(&text)
+                    .map_err(|error| format!("盤面を読み取れませんでした: {}", error))?;
+                set_board(new_board).map_err(|error| format!("この盤面は読み込めません: {}", error))
+            });
+
+            match result {
+                Ok(()) => {
+                    active_puzzle.set(None);
+                    import_error.set(None);
+
+                    // 読み込んだ駒をしばらく強調し、自分の読み込みの強調だけを消す
+                    let serial = *load_serial.peek() + 1;
+                    load_serial.set(serial);
+                    loaded_cells.set(board().map(|row| row.map(|cell| cell.is_some())));
+                    timer::sleep_ms(LOAD_FLASH_MS).await;
+                    if *load_serial.peek() == serial {
+                        loaded_cells.set([[false; 3]; 3]);
+                    }
+                }
+                Err(error) => import_error.set(Some(error)),
+            }
+        });
+    };
+
+    // ============================================================================
+    // イベントハンドラー: 直前の対局のGIF書き出し
+    // ============================================================================
+    // 着手ごとの盤面をコマにしてGIFを作り、ファイルとして保存させる
+    // 学習ポイント: コマの並び（history::replay_frames）とGIFの組み立て（gif::encode）は純粋関数
+    let export_replay = move |_: ()| {
+        let Some(moves) = history().last_game else {
+            return;
+        };
+        let frames = history::replay_frames(history().last_start, &moves);
+        let bytes = gif::encode(&frames, REPLAY_GIF_DELAY_CS);
+        export_error.set(download::save_bytes("tic-tac-toe.gif", "image/gif", &bytes).err());
+    };
+
+    // ============================================================================
+    // イベントハンドラー: 引き分けの提案への返事
+    // ============================================================================
+    // 受けたら盤面に関係なく引き分けで終局し、断ったら提案を取り消すだけ
+    // 学習ポイント: 終局の記録は着手・時間切れと同じrecord_outcomeにまとめる
+    let respond_to_draw = move |accept: bool| {
+        if game.with_mut(|g| g.respond_to_draw(accept)) && accept {
+            record_outcome(Outcome::Draw);
+        }
+    };
+
+    // ============================================================================
+    // イベントハンドラー: 盤面編集
+    // ============================================================================
+    // 下書きの盤面でセルを切り替え、反映するときにset_boardでまとめて検証する
+    // 学習ポイント:
+    // - 左クリックは空→X→O、右クリックは逆順（GameLogic::cycle_cell）
+    // - 編集中は対局の盤面に触れないので、やめれば元の局面のまま続けられる
+    let edit_cell = move |((row, col), reverse): ((usize, usize), bool)| {
+        mode.with_mut(|mode| {
+            if let AppMode::Edit(draft) = mode {
+                draft[row][col] = GameLogic::cycle_cell(draft[row][col], reverse);
+            }
+        });
+        edit_error.set(None);
+    };
+
+    let apply_edit = move |_: MouseEvent| {
+        let Some(draft) = mode().edit_draft() else {
+            return;
+        };
+        // 反映できればset_boardが対局のモードに戻す
+        match set_board(draft) {
+            Ok(()) => active_puzzle.set(None),
+            Err(error) => edit_error.set(Some(error)),
+        }
+    };
+
+    // ============================================================================
+    // イベントハンドラー: 練習問題の選択
+    // ============================================================================
+    // 学習ポイント:
+    // - 他のハンドラー（load_board_with / reset_game）の組み合わせ
+    // - 練習問題は標準ルールで作ってあるので、設定が「斜めなし」でも標準ルールで解く（終了時にreset_gameが設定のルールに戻す）
+    let select_puzzle = move |selection: Option<usize>| match selection {
+        Some(index) => {
+            if load_board_with(PUZZLES[index].board, WinRule::Standard).is_ok() {
+                active_puzzle.set(Some(index));
+                game.with_mut(|g| g.unscored = true);  // 練習問題の結果はスコアに数えない
+            }
+        }
+        None => reset_game(()),
+    };
+
+    // 練習問題の進行状況（選択中のみ）
+    let puzzle_progress = active_puzzle()
+        .map(|index| PUZZLES[index].progress(board(), current_player(), game_state()));
+
+    // ============================================================================
+    // イベントハンドラー: キーボード操作（テンキー配列）
+    // ============================================================================
+    // 数字キー1〜9をテンキーと同じ並びでセルに対応付けて配置する
+    // 学習ポイント:
+    // - onkeydownによるキーボードイベント処理
+    // - key()（入力文字）とcode()（物理キー）の使い分け
+    // - クリックと同じハンドラーを再利用してガード条件を共有
+    let handle_keydown = move |evt: KeyboardEvent| {
+        // キー操作も「操作」とみなして自動リセットを取り消す
+        note_interaction();
+
+        // Escapeキー：確定待ちの選択（と、上で取り消した自動リセット）だけを取り消し、
+        // 盤面（適用済みの着手）には触れない
+        // 学習ポイント: Key enumとの比較で特殊キーを判定
+        if evt.key() == Key::Escape {
+            evt.prevent_default();
+            confirm.with_mut(|c| c.cancel());
+            return;
+        }
+
+        let cell = GameLogic::cell_from_key(&evt.key().to_string())
+            .or_else(|| GameLogic::cell_from_key(&evt.code().to_string()));
+
+        if let Some(cell) = cell {
+            evt.prevent_default();
+            human_move(cell);
+        }
+    };
+
+    // 選択できるAI戦略（腕前は70%から始めてスライダーで調整、記録済みの手順があれば「過去の自分」を追加）
+    let mut ai_strategies = vec![AiStrategy::Random, AiStrategy::Heuristic, AiStrategy::Minimax, AiStrategy::Skill(70)];
+    if let Some((_, moves)) = recorded() {
+        ai_strategies.push(AiStrategy::Recorded(moves));
+    }
+
+    // コーチモードで勧める最初の一手（空の盤面で人間が先手のときのみ）
+    let opening_suggestion = ai::opening_suggestion(coach_mode(), board(), ai_player());
+
+    // ブラインドモードで隠すセルのマスク
+    // 学習ポイント: 表示用の値を状態から導出（勝敗判定は常に本来のboardで行う）
+    let hidden_cells = {
+        let hide = blind_mode() && game_state() == GameState::Playing;
+        revealed().map(|row| row.map(|shown| hide && !shown))
+    };
+
+    // デバッグパネル（`debug`フィーチャー有効時のみ）
+    // 学習ポイント: cfg属性でlet文ごと切り替え、rsx!内では{debug_panel}で埋め込む
+    #[cfg(feature = "debug")]
+    let debug_panel = rsx! {
+        DebugPanel {
+            board: board(),
+            current_player: current_player(),
+            game_state: game_state(),
+            decided: decided(),
+            score: score()
+        }
     };
+    #[cfg(not(feature = "debug"))]
+    let debug_panel = rsx! {};
 
     // ============================================================================
     // UI描画: rsx!マクロによる宣言的UI定義
@@ -146,34 +1224,639 @@ fn TicTacToe() -> Element {
     // - TailwindCSSクラスとインラインスタイルの使い分け
     rsx! {
         // ゲームコンテナ（カードスタイル）
+        // 学習ポイント: tabindexでフォーカス可能にし、キー入力を受け取る
+        // 学習ポイント: landscape:バリアントで横向き画面のときだけ2カラムのグリッドにする
+        // （縦向き：上から順に積む / 横向き：左に盤面、右に状態表示と操作）
+        // 学習ポイント: 色の切り替え（ダークモード）はカード全体でフェードさせる
+        // （中の文字色は明るい背景向けなので、ダークモードでもカードは明るいまま少しだけ落ち着かせる）
+        // （セルの配置・勝利のアニメーションはセル側のトランジションのままで、ここでは遅らせない）
         div {
-            class: "w-full max-w-sm mx-auto rounded-lg shadow-2xl p-3 bg-white/95 backdrop-blur-sm",
+            class: format!(
+                "w-full max-w-sm max-h-full overflow-auto mx-auto rounded-lg shadow-2xl bg-white/95 dark:bg-slate-200/95 backdrop-blur-sm outline-none landscape:max-w-3xl landscape:grid landscape:grid-cols-[auto_minmax(0,1fr)] landscape:grid-rows-[auto_1fr] {} {}",
+                if compact { "p-1.5 landscape:gap-x-2" } else { "p-3 landscape:gap-x-4" },
+                if reduce_motion() { "" } else { "transition-colors duration-300 motion-reduce:transition-none" }
+            ),
+            tabindex: 0,
+            autofocus: true,
+            onkeydown: handle_keydown,
+            // 何らかの操作があれば自動リセットを取り消す
+            // 学習ポイント: onpointerdownはonclickより先に発火するため、
+            // 対局を終わらせたクリック自体ではカウントダウンが消えない
+            // 操作のたびにBGMの再生を試す（最初の操作で自動再生の制限が外れる）
+            onpointerdown: move |_| note_interaction(),
+
+            // 上部：タイトルと状態表示（横向き時は右カラムの上段）
+            div {
+                class: "landscape:col-start-2 landscape:row-start-1",
+
+                // ゲームタイトル
+                // 学習ポイント: TailwindCSSのグラデーションテキスト
+                // 学習ポイント: タイトルとスコアバッジを横並びにするflexレイアウト
+                // コンパクト表示ではタイトルを省く（スコアバッジは残す）
+                div {
+                    class: if compact { "flex items-center justify-center gap-2 mb-1" } else { "flex items-center justify-center gap-2 mb-3" },
+                    if !compact {
+                        h1 {
+                            class: "text-xl font-bold text-center bg-gradient-to-r from-blue-700 to-indigo-800 bg-clip-text text-transparent",
+                            "三目並べ"
+                        }
+                    }
+                    ScoreBadge { score: score(), bump: score_bump() }
+                }
+
+                // ゲーム状態表示コンポーネント
+                // 学習ポイント: プロパティによるデータの受け渡し
+                GameStatus {
+                    current_player: current_player(),
+                    game_state: game_state(),
+                    high_contrast: high_contrast(),
+                    decided: decided(),
+                    reduce_motion: reduce_motion(),
+                    theme: symbol_theme(),
+                    mark_style: mark_style(),
+                    ai_player: ai_player(),
+                    compact,
+                    score: score()
+                }
+
+                // プレイヤーごとの駒の数（盤面から導出するのでリセットで自動的に0になる）
+                PieceCountBadges {
+                    counts: GameLogic::counts(board()),
+                    high_contrast: high_contrast()
+                }
+
+                // 引き分けの提案（2人対戦の対局中のみ）
+                if game_state() == GameState::Playing
+                    && ai_player().is_none()
+                    && active_puzzle().is_none()
+                    && mode().is_play()
+                {
+                    DrawOffer {
+                        offer: draw_offer(),
+                        onoffer: move |player| {
+                            game.with_mut(|g| g.offer_draw(player));
+                        },
+                        onrespond: respond_to_draw
+                    }
+                }
+
+                // ブリッツの対局時計（ONのときのみ）
+                if let Some(clock) = blitz() {
+                    BlitzClocks {
+                        clock,
+                        current_player: current_player(),
+                        game_state: game_state()
+                    }
+                }
 
-            // ゲームタイトル
-            // 学習ポイント: TailwindCSSのグラデーションテキスト
-            h1 {
-                class: "text-xl font-bold text-center mb-3 bg-gradient-to-r from-blue-700 to-indigo-800 bg-clip-text text-transparent",
-                "三目並べ"
+                // 観戦中の表示（観戦リンクから開いたときのみ）
+                if let Some(link) = mode().spectate_link() {
+                    div {
+                        class: "mb-3 p-2 rounded-lg flex items-center justify-between gap-2 border border-amber-300 bg-amber-50 text-sm text-amber-900",
+                        span {
+                            class: "font-semibold",
+                            "観戦中: X（{link.x.label()}） 対 O（{link.o.label()}）"
+                        }
+                        button {
+                            class: "px-3 py-1 rounded-full border border-amber-300 bg-white text-xs hover:bg-amber-100",
+                            onclick: move |_| reset_game(()),
+                            "観戦をやめる"
+                        }
+                    }
+                }
+
+                // 評価バー（ONのときのみ）
+                if let Some(evaluation) = evaluation() {
+                    EvaluationBar { evaluation, reduce_motion: reduce_motion() }
+                }
+
+                // 最善手順（分析用、評価バーかコーチモードがONのときのみ）
+                if let Some((line, to_move, result)) = principal_variation() {
+                    PrincipalVariation { line, to_move, result }
+                }
+
+                // 盤面の対称性と早送り（分析用、評価バーがONのときのみ）
+                if show_evaluation() {
+                    if let Some(plies) = mate() {
+                        p {
+                            class: "mb-2 text-xs font-semibold text-amber-700",
+                            "{current_player().symbol()}は{plies}手で必勝"
+                        }
+                    }
+                    SymmetryBadge { symmetries: GameLogic::symmetries(board()) }
+                    button {
+                        class: "mb-3 w-full px-3 py-1 rounded-lg border border-slate-300 bg-white text-sm text-slate-700 hover:bg-slate-100 disabled:opacity-40 disabled:cursor-not-allowed",
+                        disabled: game_state() != GameState::Playing || fast_forwarding() || !mode().is_play(),
+                        onclick: fast_forward,
+                        "最善手で最後まで進める"
+                    }
+                }
             }
 
-            // ゲーム状態表示コンポーネント
-            // 学習ポイント: プロパティによるデータの受け渡し
-            GameStatus {
-                current_player: current_player(),
-                game_state: game_state()
+            // 盤面（横向き時は左カラムに2段分またがって配置）
+            div {
+                class: "landscape:col-start-1 landscape:row-start-1 landscape:row-span-2 landscape:self-center",
+
+                // ゲーム盤面コンポーネント
+                // 学習ポイント: イベントハンドラーの受け渡し
+                GameBoard {
+                    board: mode().edit_draft().unwrap_or(board()),
+                    game_state: game_state(),
+                    onclick: human_move,
+                    onedit: mode().edit_draft().is_some().then(|| EventHandler::new(edit_cell)),
+                    hidden: hidden_cells,
+                    high_contrast: high_contrast(),
+                    reduce_motion: reduce_motion(),
+                    current_player: current_player(),
+                    theme: symbol_theme(),
+                    selected: confirm().selected,
+                    mark_style: mark_style(),
+                    resetting: resetting(),
+                    size: cell_size(),
+                    show_coordinates: show_coordinates(),
+                    suggested: opening_suggestion,
+                    intent: ai_intent(),
+                    onhover: move |cell| hovered.set(cell),
+                    preview: hover_preview(),
+                    rotation: rotation(),
+                    hints: coach_mode(),
+                    rule: win_rule(),
+                    markers: principal_variation().map(|(line, _, _)| line_markers(&line)).unwrap_or_default(),
+                    loaded: loaded_cells(),
+                    removing: removing().map(|row| row.map(|cell| cell.map(|(player, _)| player)))
+                }
+
+                // コーチモードの案内（おすすめの手があるときのみ）
+                if opening_suggestion.is_some() {
+                    p {
+                        class: "-mt-2 mb-2 text-xs text-center text-emerald-700",
+                        "中央のマスから始めるのがおすすめ"
+                    }
+                }
+
+                // コーチモードの解説メモ（直前の着手でラインがふさがったときなど）
+                if let (true, Some(note)) = (coach_mode(), teaching_note()) {
+                    p {
+                        class: "-mt-2 mb-2 text-xs text-center text-sky-700",
+                        role: "status",
+                        "📝 {note}"
+                    }
+                }
+            }
+
+            // 下部：設定・練習問題・リセット（横向き時は右カラムの下段）
+            div {
+                class: "landscape:col-start-2 landscape:row-start-2",
+
+                // 設定ドロワーを開く歯車ボタン（設定の中身はカードの外のドロワーに表示）
+                div {
+                    class: "flex justify-center",
+                    button {
+                        id: SETTINGS_BUTTON_ID,
+                        class: "px-3 py-1 rounded-full border border-slate-300 bg-white text-xs font-semibold text-slate-600 hover:bg-slate-100",
+                        "aria-haspopup": "dialog",
+                        "aria-expanded": settings_open(),
+                        onclick: move |_| settings_open.set(true),
+                        "⚙ 設定"
+                    }
+                }
+
+                // 自動リセットのカウントダウン表示
+                if let Some(remaining) = countdown() {
+                    p {
+                        class: "mt-2 text-sm text-center text-slate-600",
+                        "{remaining}秒後に新しいゲーム"
+                    }
+                }
+
+                // 公平な対戦モードの成績（ONのときのみ）
+                if fair_match() {
+                    FairMatchPanel {
+                        tally: stats::fairness_tally(fair_outcomes()),
+                        human_starts: fair_human_starts(),
+                        ai_player: ai_player(),
+                        status: match_mode().then(|| stats::match_status(&fair_results(), stats::MATCH_GAMES))
+                    }
+                }
+
+                // 練習問題の選択と目標・結果表示
+                PuzzleSelector {
+                    selected: active_puzzle(),
+                    progress: puzzle_progress,
+                    onselect: select_puzzle
+                }
+
+                // 手順の記録（過去の自分との対戦用）
+                RecordingControls {
+                    recording: recording_side(),
+                    saved: recorded().map(|(player, moves)| (player, moves.len())),
+                    onrecord: set_recording
+                }
+
+                // リセットボタンコンポーネント
+                // 学習ポイント: シンプルなイベントハンドリング
+                ResetButton {
+                    onclick: reset_game,
+                    pointer: show_reset_hint(),
+                    reduce_motion: reduce_motion()
+                }
+
+                // 一手戻す（AI対戦ではAIの応手とあわせて自分の手番まで戻す）
+                button {
+                    class: "mt-2 w-full px-3 py-1 rounded-lg border border-slate-300 bg-white text-sm text-slate-700 hover:bg-slate-100 disabled:opacity-40 disabled:cursor-not-allowed",
+                    disabled: game_state() != GameState::Playing
+                        || active_puzzle().is_some()
+                        || !mode().is_play()
+                        || history().undo_count(ai_player()) == 0,
+                    onclick: undo_move,
+                    "一手戻す"
+                }
+
+                // 直前の対局の再生（終わった対局がなければ無効）
+                button {
+                    class: "mt-2 w-full px-3 py-1 rounded-lg border border-slate-300 bg-white text-sm text-slate-700 hover:bg-slate-100 disabled:opacity-40 disabled:cursor-not-allowed",
+                    disabled: history().last_game.is_none() || !mode().is_play(),
+                    onclick: move |_| enter_mode(AppMode::Replay),
+                    "前の対局を再生"
+                }
+
+                // 共有された盤面の読み込み（失敗したら理由を表示し、盤面は変えない）
+                button {
+                    class: "mt-2 w-full px-3 py-1 rounded-lg border border-slate-300 bg-white text-sm text-slate-700 hover:bg-slate-100",
+                    onclick: import_from_clipboard,
+                    "クリップボードから読み込む"
+                }
+                if let Some(error) = import_error() {
+                    p {
+                        class: "mt-1 text-xs text-rose-600",
+                        role: "alert",
+                        "{error}"
+                    }
+                }
+
+                // 盤面編集（左クリックで空→X→O、右クリックで逆順、反映するまで対局は止まる）
+                if mode().edit_draft().is_some() {
+                    p {
+                        class: "mt-2 text-xs text-slate-500",
+                        "左クリックで空→X→O、右クリックで逆順に切り替えます"
+                    }
+                    div {
+                        class: "mt-1 flex gap-2",
+                        button {
+                            class: "flex-1 px-3 py-1 rounded-lg bg-indigo-600 text-sm font-semibold text-white hover:bg-indigo-700",
+                            onclick: apply_edit,
+                            "この盤面で始める"
+                        }
+                        button {
+                            class: "flex-1 px-3 py-1 rounded-lg border border-slate-300 bg-white text-sm text-slate-700 hover:bg-slate-100",
+                            onclick: move |_| {
+                                enter_mode(AppMode::Play);
+                                edit_error.set(None);
+                            },
+                            "編集をやめる"
+                        }
+                    }
+                    if let Some(error) = edit_error() {
+                        p {
+                            class: "mt-1 text-xs text-rose-600",
+                            role: "alert",
+                            "この盤面は使えません: {error}"
+                        }
+                    }
+                } else {
+                    button {
+                        class: "mt-2 w-full px-3 py-1 rounded-lg border border-slate-300 bg-white text-sm text-slate-700 hover:bg-slate-100 disabled:opacity-40 disabled:cursor-not-allowed",
+                        disabled: input_locked() || !mode().is_play(),
+                        onclick: move |_| enter_mode(AppMode::Edit(board())),
+                        "盤面を編集"
+                    }
+                }
+                if let (true, Some(moves)) = (mode().is_replaying(), history().last_game) {
+                    ReplayViewer {
+                        start: history().last_start,
+                        moves,
+                        theme: symbol_theme(),
+                        mark_style: mark_style(),
+                        high_contrast: high_contrast(),
+                        rule: win_rule(),
+                        onexport: EventHandler::new(export_replay),
+                        onclose: move |_| {
+                            export_error.set(None);
+                            enter_mode(AppMode::Play);
+                        }
+                    }
+                    if let Some(error) = export_error() {
+                        p {
+                            class: "mt-1 text-xs text-rose-600",
+                            role: "alert",
+                            "{error}"
+                        }
+                    }
+                }
+
+                // 最近の対局履歴
+                RecentGamesPanel {
+                    games: recent_games(),
+                    onclear: move |_| recent_games.with_mut(|games| games.clear())
+                }
+
+                // 保存先が使えないときの控えめな注記
+                if !storage_available() {
+                    p {
+                        class: "mt-2 text-xs text-center text-slate-400",
+                        "設定は保存されません"
+                    }
+                }
+
+                {debug_panel}
             }
 
-            // ゲーム盤面コンポーネント
-            // 学習ポイント: イベントハンドラーの受け渡し
-            GameBoard {
-                board: board(),
-                game_state: game_state(),
-                onclick: handle_cell_click
+            // 対局結果のバナー（終了時のみ、閉じるまでカード下端に表示）
+            if game_state().is_over() && !banner_dismissed() {
+                ResultBanner {
+                    game_state: game_state(),
+                    onrematch: reset_game,
+                    onshare: share_result,
+                    ondismiss: move |_| banner_dismissed.set(true)
+                }
             }
+        }
+
+        // AIの着手の読み上げ（画面には表示しない）
+        LiveRegion { message: announcement().0, serial: announcement().1 }
 
-            // リセットボタンコンポーネント
-            // 学習ポイント: シンプルなイベントハンドリング
-            ResetButton { onclick: reset_game }
+        // 設定ドロワー（カードの外に置き、カード内のキー操作やスクロールの影響を受けないようにする）
+        SettingsDrawer {
+            open: settings_open(),
+            onclose: move |_| settings_open.set(false),
+            return_focus: SETTINGS_BUTTON_ID,
+            reduce_motion: reduce_motion(),
+
+            // 設定の一覧（モード・表示・AI・音など）
+            div {
+                class: "flex flex-wrap gap-2",
+                SettingToggle {
+                    label: "ブラインド",
+                    enabled: blind_mode(),
+                    onclick: move |_| blind_mode.set(!blind_mode())
+                }
+                SettingToggle {
+                    label: "ハイコントラスト",
+                    enabled: high_contrast(),
+                    onclick: move |_| high_contrast.set(!high_contrast())
+                }
+                SettingToggle {
+                    label: "アニメーションを減らす",
+                    enabled: reduce_motion(),
+                    onclick: move |_| reduce_motion.set(!reduce_motion())
+                }
+                SettingToggle {
+                    label: "評価バー",
+                    enabled: show_evaluation(),
+                    onclick: move |_| show_evaluation.set(!show_evaluation())
+                }
+                SettingToggle {
+                    label: "座標",
+                    enabled: show_coordinates(),
+                    onclick: move |_| show_coordinates.set(!show_coordinates())
+                }
+                SettingToggle {
+                    label: "コーチ",
+                    enabled: coach_mode(),
+                    onclick: move |_| coach_mode.set(!coach_mode())
+                }
+                SettingToggle {
+                    label: "手の見える化",
+                    enabled: show_ai_intent(),
+                    onclick: move |_| {
+                        // OFFにしたら考え中の強調もすぐに消す
+                        show_ai_intent.set(!show_ai_intent());
+                        ai_intent.set(None);
+                    }
+                }
+                SettingToggle {
+                    label: "タップで確定",
+                    enabled: tap_to_confirm(),
+                    onclick: move |_| {
+                        // OFFにしたら選択中のマスも消す
+                        tap_to_confirm.set(!tap_to_confirm());
+                        confirm.set(TapConfirm::default());
+                    }
+                }
+                SettingToggle {
+                    label: "斜めなし",
+                    enabled: rule_setting() == WinRule::NoDiagonals,
+                    // 練習問題は標準ルールで解くので、出題中はルールを切り替えない
+                    disabled: active_puzzle().is_some(),
+                    onclick: move |_| {
+                        // ルールを変えたら新しい対局から始める（reset_gameが対局に反映する）
+                        rule_setting.set(match rule_setting() {
+                            WinRule::Standard => WinRule::NoDiagonals,
+                            WinRule::NoDiagonals => WinRule::Standard,
+                        });
+                        reset_game(());
+                    }
+                }
+                SettingToggle {
+                    label: "ブリッツ",
+                    enabled: blitz().is_some(),
+                    onclick: move |_| {
+                        // 切り替えたら新しい対局から始める（ONなら持ち時間から）
+                        blitz.set(if blitz().is_some() { None } else { Some(BlitzClock::default()) });
+                        reset_game(());
+                    }
+                }
+                SettingToggle {
+                    label: "先手交代",
+                    enabled: fair_match(),
+                    onclick: move |_| {
+                        // 切り替えたら集計をやり直し、新しい対局から始める
+                        fair_match.set(!fair_match());
+                        match_mode.set(false);
+                        fair_outcomes.set(Vec::new());
+                        reset_game(());
+                    }
+                }
+                SettingToggle {
+                    label: "3局勝負",
+                    enabled: match_mode(),
+                    onclick: move |_| {
+                        // 対戦は先手交代モードの上で行う（ONにしたら先手交代もONにする）
+                        match_mode.set(!match_mode());
+                        fair_match.set(fair_match() || match_mode());
+                        fair_outcomes.set(Vec::new());
+                        reset_game(());
+                    }
+                }
+                ThemePicker {
+                    theme: symbol_theme(),
+                    onchange: move |theme| symbol_theme.set(theme)
+                }
+                AiSelector {
+                    ai_player: ai_player(),
+                    strategy: ai_strategy(),
+                    strategies: ai_strategies,
+                    onplayer: move |player| ai_player.set(player),
+                    onstrategy: move |strategy: AiStrategy| {
+                        // 「過去の自分」は記録したプレイヤーを担当する
+                        if let (AiStrategy::Recorded(_), Some((side, _))) = (&strategy, recorded()) {
+                            ai_player.set(Some(side));
+                        }
+                        ai_strategy.set(strategy);
+                        adaptive_results.set(Vec::new());
+                    }
+                }
+                if ai_player().is_some() {
+                    AdaptiveDifficulty {
+                        enabled: adaptive(),
+                        level: ai_strategy(),
+                        bounds: adaptive_bounds(),
+                        ontoggle: move |_| {
+                            adaptive.set(!adaptive());
+                            adaptive_results.set(Vec::new());
+                        },
+                        onbounds: move |bounds| adaptive_bounds.set(bounds)
+                    }
+                }
+                // BGM（Webのみ）
+                // 学習ポイント: cfg!マクロはbool値になるため、rsx!のif条件に使える
+                if cfg!(target_arch = "wasm32") {
+                    MusicControls {
+                        enabled: music_enabled(),
+                        volume: music_volume(),
+                        ontoggle: move |_| music_enabled.set(!music_enabled()),
+                        onvolume: move |volume| music_volume.set(volume)
+                    }
+                }
+                CellSizePicker {
+                    size: cell_size(),
+                    onchange: move |size| cell_size.set(size)
+                }
+                MarkStylePicker {
+                    style: mark_style(),
+                    onchange: move |style| mark_style.set(style)
+                }
+                RotationPicker {
+                    rotation: rotation(),
+                    onchange: move |choice| rotation.set(choice)
+                }
+                HandicapPicker {
+                    handicap: handicap(),
+                    onchange: move |choice| {
+                        // 変更したら新しい対局から始める
+                        handicap.set(choice);
+                        reset_game(());
+                    }
+                }
+                AutoResetSelector {
+                    secs: auto_reset_secs(),
+                    onchange: move |secs| auto_reset_secs.set(secs)
+                }
+            }
         }
     }
 }
+
+// ============================================================================
+// ヘルパー: ゲームの世代を進める
+// ============================================================================
+// 世代を1つ進めて、待機中のAIの着手（ai::PendingMove）を無効にする
+// 学習ポイント: 数値のシグナルは`+=`で読み取りと書き込みをまとめて行える
+fn advance_epoch(mut epoch: Signal<u32>) {
+    epoch += 1;
+}
+
+// ============================================================================
+// ヘルパー: モードの切り替え
+// ============================================================================
+// AppMode::transitionの後片付けのうち、待機中の着手の無効化（世代を進める）までを適用して切り替える
+// 学習ポイント: シグナルを受け取る関数にしておくと、enter_modeと同じ処理をテストから呼べる
+fn switch_mode(mut mode: Signal<AppMode>, game_epoch: Signal<u32>, next: AppMode) -> ModeCleanup {
+    let cleanup = mode.peek().transition(&next);
+    if cleanup.cancel_pending {
+        advance_epoch(game_epoch);  // 待機中のAIの着手・観戦の再生を無効にする
+    }
+    mode.set(next);
+    cleanup
+}
+
+// ============================================================================
+// ヘルパー: AIの着手前の入力ロックの解除
+// ============================================================================
+// AIが手を選べたときだけロックを外し、その手を返す
+// 学習ポイント: 選べなかったときに外すと、人間がAIの手番に置けて手番の順序が崩れる。
+// ロックしたままでも、リセット等で局面が変われば副作用の再実行でロックが決め直される
+fn release_ai_lock(choice: Option<(usize, usize)>, mut input_locked: Signal<bool>) -> Option<(usize, usize)> {
+    if choice.is_some() {
+        input_locked.set(false);
+    }
+    choice
+}
+
+// ============================================================================
+// テスト: シグナルを使うヘルパーの検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_epoch_invalidates_pending_move() {
+        // シグナルはランタイムの中でしか作れないので、空のVirtualDomのルートスコープを借りる
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+        dom.in_runtime(|| {
+            ScopeId::ROOT.in_runtime(|| {
+                let epoch = Signal::new(0u32);
+                let board = GameLogic::parse_board("X../.../...").unwrap();
+                let pending = ai::PendingMove::new(epoch(), board, Player::O);
+
+                advance_epoch(epoch);
+                assert_eq!(epoch(), 1);
+                assert!(!pending.is_current(epoch(), board, Some(Player::O)));
+            })
+        });
+    }
+
+    #[test]
+    fn test_entering_replay_cancels_pending_ai_move() {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+        dom.in_runtime(|| {
+            ScopeId::ROOT.in_runtime(|| {
+                // AIの着手を予約した状態で再生に切り替えると、世代が進んで予約が無効になる
+                let (mode, epoch) = (Signal::new(AppMode::Play), Signal::new(0u32));
+                let board = GameLogic::parse_board("X../.../...").unwrap();
+                let pending = ai::PendingMove::new(epoch(), board, Player::O);
+
+                switch_mode(mode, epoch, AppMode::Replay);
+                assert_eq!(mode(), AppMode::Replay);
+                assert!(!pending.is_current(epoch(), board, Some(Player::O)));
+
+                // 再生から対局に戻っても世代は進まない（再生中に予約された着手はない）
+                let pending = ai::PendingMove::new(epoch(), board, Player::O);
+                switch_mode(mode, epoch, AppMode::Play);
+                assert!(pending.is_current(epoch(), board, Some(Player::O)));
+            })
+        });
+    }
+
+    #[test]
+    fn test_ai_lock_stays_when_no_move_is_chosen() {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+        dom.in_runtime(|| {
+            ScopeId::ROOT.in_runtime(|| {
+                let input_locked = Signal::new(true);
+
+                // 手を選べなければロックしたまま（人間はAIの手番に置けない）
+                assert_eq!(release_ai_lock(None, input_locked), None);
+                assert!(input_locked());
+
+                // 選べたら置く直前にロックを外す
+                assert_eq!(release_ai_lock(Some((1, 1)), input_locked), Some((1, 1)));
+                assert!(!input_locked());
+            })
+        });
+    }
+}
@@ -0,0 +1,135 @@
+// ============================================================================
+// Dioxus学習プロジェクト: アプリのモード
+// ============================================================================
+// このファイルは「対局・盤面編集・再生・観戦」のどれが有効かを1つの列挙型で表し、
+// モードを切り替えるときに何を片付けるかを決めます。
+// 複数のboolフラグを組み合わせる代わりに、同時に1つのモードだけが有効になります。
+//
+// 学習ポイント:
+// - データを持つバリアント（編集の下書き・観戦リンク）で、モードとその状態をまとめる
+// - 切り替え時の後片付けを純粋関数で決め、UI側はその結果を適用するだけにする
+// - 同じモードの中での更新（下書きの編集など）は後片付けの対象にしない
+
+use crate::spectate::SpectateLink;
+use crate::types::Board;
+
+// ============================================================================
+// 型定義: アプリのモード
+// ============================================================================
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum AppMode {
+    #[default]
+    Play,                    // 通常の対局
+    Edit(Board),             // 盤面編集中（反映するまで対局の盤面は変えない下書き）
+    Replay,                  // 直前の対局を再生中（対局は一時停止）
+    Spectate(SpectateLink),  // 観戦リンクのAI同士の対局を再生中
+}
+
+impl AppMode {
+    /// 起動時のモード（観戦リンクがあれば観戦、なければ通常の対局）
+    pub fn from_spectate(link: Option<SpectateLink>) -> Self {
+        link.map_or(AppMode::Play, AppMode::Spectate)
+    }
+
+    /// 通常の対局中か（人間の着手とAIの着手を受け付ける）
+    pub fn is_play(&self) -> bool {
+        *self == AppMode::Play
+    }
+
+    /// 盤面に駒が置かれうるか（観戦では再生が着手を進める）
+    pub fn allows_moves(&self) -> bool {
+        matches!(self, AppMode::Play | AppMode::Spectate(_))
+    }
+
+    /// 盤面編集の下書き（編集中でなければNone）
+    pub fn edit_draft(&self) -> Option<Board> {
+        match self {
+            AppMode::Edit(draft) => Some(*draft),
+            _ => None,
+        }
+    }
+
+    /// 観戦中のリンク（観戦中でなければNone）
+    pub fn spectate_link(&self) -> Option<SpectateLink> {
+        match self {
+            AppMode::Spectate(link) => Some(link.clone()),
+            _ => None,
+        }
+    }
+
+    /// 観戦中か
+    pub fn is_spectating(&self) -> bool {
+        matches!(self, AppMode::Spectate(_))
+    }
+
+    /// 直前の対局を再生中か
+    pub fn is_replaying(&self) -> bool {
+        *self == AppMode::Replay
+    }
+
+    /// nextへ切り替えるときに必要な後片付け
+    /// 学習ポイント: バリアントの種類（中身は見ない）が変わるときだけ片付ける
+    pub fn transition(&self, next: &AppMode) -> ModeCleanup {
+        if std::mem::discriminant(self) == std::mem::discriminant(next) {
+            return ModeCleanup::default();
+        }
+        ModeCleanup {
+            // 対局・観戦では待機中の着手がありうるので、離れるときに無効にする
+            cancel_pending: self.allows_moves(),
+            clear_selection: true,
+        }
+    }
+}
+
+// ============================================================================
+// 型定義: モード切り替え時の後片付け
+// ============================================================================
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct ModeCleanup {
+    pub cancel_pending: bool,   // 待機中のAIの着手・観戦の再生を無効にする
+    pub clear_selection: bool,  // ホバー中のセルやAIの強調など、選択の表示を消す
+}
+
+// ============================================================================
+// テスト: モード切り替えの検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::AiStrategy;
+    use crate::types::GameLogic;
+
+    #[test]
+    fn test_entering_replay_stops_moves() {
+        // 対局から再生に切り替えると待機中の着手を無効にし、再生中は着手を受け付けない
+        // （世代が進んで予約が無効になることは、切り替えを適用するmain.rsのswitch_modeで確かめる）
+        let cleanup = AppMode::Play.transition(&AppMode::Replay);
+        assert_eq!(cleanup, ModeCleanup { cancel_pending: true, clear_selection: true });
+        assert!(!AppMode::Replay.allows_moves());
+    }
+
+    #[test]
+    fn test_transition_cleanup() {
+        let board = GameLogic::empty_board();
+        let link = SpectateLink { seed: 1, x: AiStrategy::Random, o: AiStrategy::Minimax };
+
+        // 同じモードの中での更新（下書きの編集）は片付けない
+        let edited = GameLogic::parse_board("X../.../...").unwrap();
+        assert_eq!(AppMode::Edit(board).transition(&AppMode::Edit(edited)), ModeCleanup::default());
+        assert_eq!(AppMode::Play.transition(&AppMode::Play), ModeCleanup::default());
+
+        // 観戦をやめると再生を打ち切る。編集・再生から戻るときは選択だけ消す
+        assert!(AppMode::Spectate(link.clone()).transition(&AppMode::Play).cancel_pending);
+        assert_eq!(
+            AppMode::Edit(board).transition(&AppMode::Play),
+            ModeCleanup { cancel_pending: false, clear_selection: true }
+        );
+        assert!(!AppMode::Replay.transition(&AppMode::Edit(board)).cancel_pending);
+
+        // 起動時のモードと、モードごとの状態の取り出し
+        assert_eq!(AppMode::from_spectate(None), AppMode::Play);
+        assert_eq!(AppMode::from_spectate(Some(link.clone())).spectate_link(), Some(link));
+        assert_eq!(AppMode::Edit(edited).edit_draft(), Some(edited));
+        assert_eq!(AppMode::Play.edit_draft(), None);
+    }
+}
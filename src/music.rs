@@ -0,0 +1,60 @@
+// ============================================================================
+// Dioxus学習プロジェクト: BGM（背景音楽）
+// ============================================================================
+// このファイルはループ再生するBGMの再生・一時停止・音量変更を提供します。
+// 着手の効果音とは別の仕組みで、Web（wasm32）でのみ音が鳴ります。
+//
+// 学習ポイント:
+// - web_sys::HtmlAudioElementによるブラウザの音声再生
+// - #[cfg]属性で、Web以外では何もしない同じAPIを用意する
+// - ブラウザの自動再生制限のため、最初の操作の後で再生を始める
+
+/// ループ再生するBGM
+/// 学習ポイント: 要素を1つだけ作って使い回すことで、再描画のたびに最初から鳴り直さない
+#[derive(Clone)]
+pub struct BackgroundMusic {
+    #[cfg(target_arch = "wasm32")]
+    audio: Option<web_sys::HtmlAudioElement>,
+}
+
+impl BackgroundMusic {
+    /// 音声ファイルのURLからBGMを用意する（まだ再生しない）
+    pub fn new(src: &str) -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let audio = web_sys::HtmlAudioElement::new_with_src(src).ok();
+            if let Some(audio) = &audio {
+                audio.set_loop(true);
+            }
+            BackgroundMusic { audio }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = src;
+            BackgroundMusic {}
+        }
+    }
+
+    /// 音量（0〜100）を設定して再生する（再生中なら音量だけ変わる）
+    /// 学習ポイント: 再生中の要素にplay()を呼んでも最初からにはならない
+    pub fn play(&self, volume: u8) {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(audio) = &self.audio {
+            audio.set_volume(f64::from(volume.min(100)) / 100.0);
+            // 自動再生が拒否された場合のPromiseの失敗は無視する（呼び出し側が操作のたびに呼び直す）
+            let _ = audio.play();
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = volume;
+    }
+
+    /// 一時停止する（再開すると続きから鳴る）
+    pub fn pause(&self) {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(audio) = &self.audio {
+            let _ = audio.pause();
+        }
+    }
+}
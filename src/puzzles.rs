@@ -0,0 +1,174 @@
+// ============================================================================
+// Dioxus学習プロジェクト: 練習問題（詰め三目並べ）
+// ============================================================================
+// このファイルは練習用のプリセット局面（パズル）を定義しています。
+//
+// 学習ポイント:
+// - const配列による静的データの定義
+// - 構造体とenumを組み合わせたデータモデル
+// - ゲームロジック（ミニマックス探索）の再利用による正誤判定
+
+use crate::types::{Board, GameLogic, GameState, Outcome, Player};
+
+// ============================================================================
+// 型定義: パズルの目標
+// ============================================================================
+// パズルで達成すべき目標
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PuzzleGoal {
+    Win,   // 手番のプレイヤーが勝つ
+    Draw,  // 手番のプレイヤーが負けを避ける（引き分け以上）
+}
+
+// ============================================================================
+// 型定義: パズルの進行状況
+// ============================================================================
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PuzzleProgress {
+    InProgress,  // 目標はまだ達成可能
+    Solved,      // 目標を達成した
+    Failed,      // 最善を尽くされると目標を達成できなくなった
+}
+
+// ============================================================================
+// 型定義: パズル
+// ============================================================================
+// 出題する局面・手番・目標の組
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Puzzle {
+    pub title: &'static str,  // セレクターに表示する名前
+    pub board: Board,         // 出題局面
+    pub to_move: Player,      // 解答するプレイヤー（出題局面での手番）
+    pub goal: PuzzleGoal,     // 目標
+}
+
+const X: Option<Player> = Some(Player::X);
+const O: Option<Player> = Some(Player::O);
+const E: Option<Player> = None;
+
+/// 収録されているパズル一覧
+/// 学習ポイント: 短い定数名（X/O/E）で盤面を読みやすく記述
+pub const PUZZLES: [Puzzle; 4] = [
+    Puzzle {
+        title: "一手で勝ち",
+        board: [
+            [X, X, E],
+            [O, O, E],
+            [E, E, E],
+        ],
+        to_move: Player::X,
+        goal: PuzzleGoal::Win,
+    },
+    Puzzle {
+        title: "守りながらダブルリーチ",
+        board: [
+            [X, E, O],
+            [E, O, E],
+            [E, E, X],
+        ],
+        to_move: Player::X,
+        goal: PuzzleGoal::Win,
+    },
+    Puzzle {
+        title: "リーチを止めよう",
+        board: [
+            [X, X, E],
+            [E, O, E],
+            [E, E, E],
+        ],
+        to_move: Player::O,
+        goal: PuzzleGoal::Draw,
+    },
+    Puzzle {
+        title: "角を取られたら",
+        board: [
+            [X, E, E],
+            [E, E, E],
+            [E, E, E],
+        ],
+        to_move: Player::O,
+        goal: PuzzleGoal::Draw,
+    },
+];
+
+impl Puzzle {
+    /// 目標の説明文を返す
+    pub fn goal_text(&self) -> String {
+        match self.goal {
+            PuzzleGoal::Win => format!("{}の手番、勝ち筋を見つけよう", self.to_move.symbol()),
+            PuzzleGoal::Draw => format!("{}の手番、負けないように守ろう", self.to_move.symbol()),
+        }
+    }
+
+    /// 対局結果が目標を満たしているか
+    fn accepts(&self, outcome: Outcome) -> bool {
+        match (self.goal, outcome) {
+            (_, Outcome::Win(winner)) => winner == self.to_move,
+            (PuzzleGoal::Draw, Outcome::Draw) => true,
+            (PuzzleGoal::Win, Outcome::Draw) => false,
+        }
+    }
+
+    /// 現在の局面から進行状況を判定する（練習問題は標準ルールで解く前提）
+    /// 学習ポイント: 最善手順の結果（perfect_play_winner）で「まだ達成可能か」を判定
+    pub fn progress(&self, board: Board, current_player: Player, game_state: GameState) -> PuzzleProgress {
+        if let Some(outcome) = game_state.outcome() {
+            return if self.accepts(outcome) { PuzzleProgress::Solved } else { PuzzleProgress::Failed };
+        }
+
+        let best = match GameLogic::perfect_play_winner(board, current_player) {
+            Some(winner) => Outcome::Win(winner),
+            None => Outcome::Draw,
+        };
+        if self.accepts(best) { PuzzleProgress::InProgress } else { PuzzleProgress::Failed }
+    }
+}
+
+// ============================================================================
+// テスト: 収録パズルの検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzles_are_legal_positions() {
+        for puzzle in PUZZLES {
+            // set-board APIで読み込めて、手番が出題どおりになること
+            assert_eq!(
+                GameLogic::load_board(puzzle.board),
+                Ok((GameState::Playing, puzzle.to_move)),
+                "{}",
+                puzzle.title
+            );
+        }
+    }
+
+    #[test]
+    fn test_puzzles_are_solvable() {
+        for puzzle in PUZZLES {
+            assert_eq!(
+                puzzle.progress(puzzle.board, puzzle.to_move, GameState::Playing),
+                PuzzleProgress::InProgress,
+                "{}",
+                puzzle.title
+            );
+        }
+    }
+
+    #[test]
+    fn test_puzzle_progress() {
+        let puzzle = PUZZLES[0];
+
+        // 正解：上段を揃えて勝利
+        let mut solved = puzzle.board;
+        solved[0][2] = Some(Player::X);
+        let state = GameLogic::check_game_state(solved);
+        assert_eq!(puzzle.progress(solved, Player::X, state), PuzzleProgress::Solved);
+
+        // 不正解：勝ちを逃すとOに揃えられてしまう
+        let mut missed = puzzle.board;
+        missed[2][2] = Some(Player::X);
+        assert_eq!(puzzle.progress(missed, Player::O, GameState::Playing), PuzzleProgress::Failed);
+    }
+}
@@ -0,0 +1,390 @@
+// ============================================================================
+// Dioxus学習プロジェクト: セッションの保存と復元
+// ============================================================================
+// このファイルはスコア・設定・対局中の盤面をまとめた「セッション」を定義し、
+// localStorage（Web）またはファイル（デスクトップ/モバイル）に保存します。
+//
+// 学習ポイント:
+// - serdeのderiveによるJSONシリアライズ
+// - #[serde(default)]による古い形式・欠損データからの移行
+// - #[cfg]属性によるプラットフォーム別の保存先の切り替え
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ai::AiStrategy;
+use crate::stats::GameRecord;
+use crate::types::{Board, BoardRotation, CellSize, GameLogic, GameState, MarkStyle, Player, Score, SymbolTheme, WinRule};
+
+/// 現在のセッション形式のバージョン
+pub const SESSION_VERSION: u32 = 1;
+
+// localStorageのキー（Web）
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "tic-tac-toe-session";
+
+// 保存ファイル名（デスクトップ/モバイル、ホームディレクトリ直下）
+#[cfg(not(target_arch = "wasm32"))]
+const SESSION_FILE: &str = ".tic-tac-toe-session.json";
+
+// ============================================================================
+// 型定義: 表示・操作の設定
+// ============================================================================
+// 学習ポイント: #[serde(default)]で、保存されていない項目はDefaultの値になる
+// （AIの戦略は「過去の自分」の手順（Vec）を持つのでCopyにしない）
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub blind_mode: bool,              // ブラインドモード
+    pub high_contrast: bool,           // ハイコントラスト表示
+    pub reduce_motion: bool,           // アニメーションを減らす
+    pub theme: SymbolTheme,            // 駒の記号テーマ
+    pub auto_reset_secs: Option<u32>,  // 自動リセットまでの秒数（Noneはオフ）
+    pub cell_size: CellSize,           // セルの大きさ
+    pub mark_style: MarkStyle,         // 駒の描画方法（SVG/CSS）
+    pub win_rule: WinRule,             // 勝利ラインのルール（斜めなし等）
+    pub rotation: BoardRotation,       // 盤面の表示の向き
+    pub ai_strategy: AiStrategy,       // 対戦AIの戦略
+    pub ai_player: Option<Player>,     // 対戦AIが担当するプレイヤー（2人対戦ではNone）
+}
+
+// ============================================================================
+// 型定義: セッション
+// ============================================================================
+// スコア・設定・対局中の盤面をまとめて保存する単位
+// 学習ポイント: 手番やゲーム状態は盤面と開始局面から導出できるため保存しない
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    // 形式のバージョン（バージョン導入前のデータは0として読み込む）
+    #[serde(default)]
+    pub version: u32,
+    pub score: Score,        // 対局結果の累計
+    pub settings: Settings,  // 表示・操作の設定
+    pub board: Board,        // 対局中の盤面
+    pub start: (Board, Player),  // 対局の開始局面と最初の手番（ハンディキャップの駒は駒数が合わないため、盤面の検証に使う）
+    pub recent_games: VecDeque<GameRecord>,  // 最近の対局履歴
+    pub reset_hint_seen: bool,  // 「新しいゲーム」ボタンの案内を表示済みか（初回の終局で一度だけ）
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Session {
+            version: SESSION_VERSION,
+            score: Score::default(),
+            settings: Settings::default(),
+            board: GameLogic::empty_board(),
+            start: (GameLogic::empty_board(), Player::X),
+            recent_games: VecDeque::new(),
+            reset_hint_seen: false,
+        }
+    }
+}
+
+impl Session {
+    /// JSON文字列に変換する
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| e.to_string())
+    }
+
+    /// JSON文字列から読み込む（古い形式は現在の形式に移行する）
+    /// 学習ポイント: 欠けている項目はデフォルト値、未知の項目は無視される
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str::<Session>(json)
+            .map(Session::migrate)
+            .map_err(|e| e.to_string())
+    }
+
+    /// 古いバージョンのデータを現在の形式に移行する
+    /// 学習ポイント: 壊れた盤面は読み込みを失敗させず、空の盤面に戻す
+    /// （開始局面から指し進めた盤面でなければ、開始局面を空の盤面にして検証し直す）
+    fn migrate(mut self) -> Self {
+        let rule = self.settings.win_rule;
+        if GameLogic::load_board_from(self.start, self.board, rule).is_err() {
+            self.start = (GameLogic::empty_board(), Player::X);
+            if GameLogic::load_board_with(self.board, rule).is_err() {
+                self.board = GameLogic::empty_board();
+            }
+        }
+        self.version = SESSION_VERSION;
+        self
+    }
+
+    /// 保存された盤面と開始局面からゲーム状態と手番を、保存されたルールで復元する
+    pub fn game(&self) -> (GameState, Player) {
+        GameLogic::load_board_from(self.start, self.board, self.settings.win_rule).unwrap_or((GameState::Playing, Player::X))
+    }
+}
+
+// ============================================================================
+// 永続化: 保存先への読み書き
+// ============================================================================
+// 学習ポイント:
+// - 保存先をトレイトで抽象化し、テストでは失敗する保存先に差し替えられるようにする
+// - プライベートブラウズ等でlocalStorageが使えなくても、アプリは落とさずメモリ上で続ける
+
+/// セッションの保存先
+pub trait SessionStore {
+    /// 保存されたJSONを読む（まだ保存されていなければOk(None)、保存先が使えなければErr）
+    fn read(&self) -> Result<Option<String>, String>;
+    /// JSONを書き込む（保存先が使えなければErr）
+    fn write(&self, json: &str) -> Result<(), String>;
+}
+
+/// プラットフォームごとの保存先（Web: localStorage / それ以外: ホームディレクトリのファイル）
+pub struct PlatformStore;
+
+impl SessionStore for PlatformStore {
+    fn read(&self) -> Result<Option<String>, String> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            local_storage()?
+                .get_item(STORAGE_KEY)
+                .map_err(|_| "localStorageから読み込めません".to_string())
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            match std::fs::read_to_string(session_path()?) {
+                Ok(json) => Ok(Some(json)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e.to_string()),
+            }
+        }
+    }
+
+    /// 学習ポイント:
+    /// - Web: localStorage.setItemは1回の呼び出しで丸ごと置き換わる
+    /// - デスクトップ: 一時ファイルに書いてからrenameし、書きかけの状態を残さない
+    fn write(&self, json: &str) -> Result<(), String> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            local_storage()?
+                .set_item(STORAGE_KEY, json)
+                .map_err(|_| "localStorageへの保存に失敗しました".to_string())
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let path = session_path()?;
+            let tmp = path.with_extension("json.tmp");
+            std::fs::write(&tmp, json).map_err(|e| e.to_string())?;
+            std::fs::rename(&tmp, &path).map_err(|e| e.to_string())
+        }
+    }
+}
+
+/// セッションを保存先に書き込む
+pub fn save_to(store: &impl SessionStore, session: &Session) -> Result<(), String> {
+    store.write(&session.to_json()?)
+}
+
+/// 保存先からセッションを読み込む（未保存・壊れたデータはOk(None)、保存先が使えなければErr）
+pub fn load_from(store: &impl SessionStore) -> Result<Option<Session>, String> {
+    Ok(store.read()?.and_then(|json| Session::from_json(&json).ok()))
+}
+
+/// 保存先から読み込み、使えなければデフォルトのセッションで続ける
+/// 学習ポイント: 戻り値のboolで「保存できる状態か」を呼び出し側（UIの注記）に伝える
+pub fn load_or_default(store: &impl SessionStore) -> (Session, bool) {
+    match load_from(store) {
+        Ok(session) => (session.unwrap_or_default(), true),
+        Err(_) => (Session::default(), false),
+    }
+}
+
+/// セッションをプラットフォームの保存先に保存する
+pub fn save(session: &Session) -> Result<(), String> {
+    save_to(&PlatformStore, session)
+}
+
+/// プラットフォームの保存先から読み込む（保存先が使えなければ、デフォルトとfalse）
+pub fn load() -> (Session, bool) {
+    load_or_default(&PlatformStore)
+}
+
+/// localStorageを取得する（アクセス自体が例外になる環境ではErr）
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Result<web_sys::Storage, String> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .ok_or_else(|| "localStorageを利用できません".to_string())
+}
+
+/// 保存ファイルのパス（ホームディレクトリが分からなければErr）
+/// 学習ポイント: 作業ディレクトリに書き散らさないよう、保存先が分からなければ保存先が使えない扱いにする
+#[cfg(not(target_arch = "wasm32"))]
+fn session_path() -> Result<std::path::PathBuf, String> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .filter(|home| !home.is_empty())
+        .map(|home| std::path::PathBuf::from(home).join(SESSION_FILE))
+        .ok_or_else(|| "ホームディレクトリが見つかりません".to_string())
+}
+
+// ============================================================================
+// テスト: セッションのシリアライズ
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BoardBuilder, Handicap, Outcome};
+
+    #[test]
+    fn test_session_round_trip() {
+        let session = Session {
+            version: SESSION_VERSION,
+            score: Score { x_wins: 3, o_wins: 1, draws: 2 },
+            settings: Settings {
+                blind_mode: true,
+                high_contrast: true,
+                reduce_motion: false,
+                theme: SymbolTheme::Animals,
+                auto_reset_secs: Some(5),
+                cell_size: CellSize::Large,
+                mark_style: MarkStyle::Css,
+                win_rule: WinRule::NoDiagonals,
+                rotation: BoardRotation::Half,
+                ai_strategy: AiStrategy::Skill(70),
+                ai_player: Some(Player::O),
+            },
+            board: BoardBuilder::new().x(0, 0).o(1, 1).x(2, 2).build(),
+            start: (GameLogic::empty_board(), Player::X),
+            recent_games: VecDeque::from([GameRecord {
+                outcome: Outcome::Win(Player::X),
+                ai_player: Some(Player::O),
+                moves: 5,
+                duration_secs: 12,
+            }]),
+            reset_hint_seen: true,
+        };
+
+        let json = session.to_json().unwrap();
+        assert_eq!(Session::from_json(&json), Ok(session.clone()));
+        assert_eq!(session.game(), (GameState::Playing, Player::O));
+    }
+
+    #[test]
+    fn test_session_round_trip_keeps_ai_settings() {
+        // 「過去の自分」の手順ごと保存し、読み込んだときに同じ対戦相手に戻る
+        let settings = Settings {
+            ai_strategy: AiStrategy::Recorded(vec![(1, 1), (0, 0), (2, 2)]),
+            ai_player: Some(Player::X),
+            ..Settings::default()
+        };
+        let session = Session { settings: settings.clone(), ..Session::default() };
+
+        let json = session.to_json().unwrap();
+        assert_eq!(Session::from_json(&json).map(|s| s.settings), Ok(settings));
+
+        // AIの設定を持たない古いデータは、2人対戦・定石の戦略として読み込む
+        let session = Session::from_json(r#"{"settings":{"blind_mode":true}}"#).unwrap();
+        assert_eq!(session.settings.ai_strategy, AiStrategy::Heuristic);
+        assert_eq!(session.settings.ai_player, None);
+    }
+
+    #[test]
+    fn test_session_migrates_partial_data() {
+        // バージョン導入前の、スコアだけを持つ古い形式
+        let session = Session::from_json(r#"{"score":{"x_wins":2}}"#).unwrap();
+        assert_eq!(session.version, SESSION_VERSION);
+        assert_eq!(session.score, Score { x_wins: 2, o_wins: 0, draws: 0 });
+        assert_eq!(session.settings, Settings::default());
+        assert_eq!(session.board, GameLogic::empty_board());
+
+        // 未知の項目は無視し、ありえない盤面は空に戻す
+        let json = r#"{"version":1,"language":"ja","board":[["X","X","X"],[null,null,null],[null,null,null]]}"#;
+        let session = Session::from_json(json).unwrap();
+        assert_eq!(session.board, GameLogic::empty_board());
+
+        // JSONとして壊れている場合はエラー
+        assert!(Session::from_json("{").is_err());
+    }
+
+    #[test]
+    fn test_session_round_trip_from_handicap_start() {
+        // Oに1個のハンディキャップ：駒数だけでは不正な盤面も、開始局面とあわせて保存すれば復元できる
+        let start = Handicap { player: Player::O, marks: 1 }.start().unwrap();
+        let session = Session {
+            board: BoardBuilder::new().o(1, 1).x(0, 0).o(2, 2).build(),
+            start,
+            ..Session::default()
+        };
+        let restored = Session::from_json(&session.to_json().unwrap()).unwrap();
+        assert_eq!(restored, session);
+        assert_eq!(restored.game(), (GameState::Playing, Player::X));
+
+        // 開始局面を持たない古い形式では、同じ盤面は到達できないので空に戻す
+        let json = r#"{"version":1,"board":[["X",null,null],[null,"O",null],[null,null,"O"]]}"#;
+        let restored = Session::from_json(json).unwrap();
+        assert_eq!(restored.board, GameLogic::empty_board());
+        assert_eq!(restored.start, (GameLogic::empty_board(), Player::X));
+    }
+
+    #[test]
+    fn test_session_restores_with_saved_rule() {
+        // 斜めなしで斜めに揃った盤面は、勝ちではなく対局中として復元する
+        let session = Session {
+            settings: Settings { win_rule: WinRule::NoDiagonals, ..Settings::default() },
+            board: GameLogic::parse_board("XO./OX./..X").unwrap(),
+            ..Session::default()
+        };
+        let restored = Session::from_json(&session.to_json().unwrap()).unwrap();
+        assert_eq!(restored.board, session.board);
+        assert_eq!(restored.game(), (GameState::Playing, Player::O));
+
+        // 標準ルールなら同じ盤面はXの勝ち
+        let standard = Session { settings: Settings::default(), ..session };
+        assert_eq!(standard.game(), (GameState::Won(Player::X), Player::X));
+    }
+
+    // 常に失敗する保存先（プライベートブラウズ等でlocalStorageが例外を投げる状況の再現）
+    struct FailingStore;
+
+    impl SessionStore for FailingStore {
+        fn read(&self) -> Result<Option<String>, String> {
+            Err("SecurityError".to_string())
+        }
+        fn write(&self, _json: &str) -> Result<(), String> {
+            Err("SecurityError".to_string())
+        }
+    }
+
+    // メモリ上の保存先
+    struct MemoryStore(std::cell::RefCell<Option<String>>);
+
+    impl SessionStore for MemoryStore {
+        fn read(&self) -> Result<Option<String>, String> {
+            Ok(self.0.borrow().clone())
+        }
+        fn write(&self, json: &str) -> Result<(), String> {
+            *self.0.borrow_mut() = Some(json.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_unavailable_storage_falls_back_to_default() {
+        let (session, available) = load_or_default(&FailingStore);
+        assert_eq!(session, Session::default());
+        assert!(!available);
+
+        // 書き込みの失敗もパニックせずErrとして返る
+        assert!(save_to(&FailingStore, &session).is_err());
+    }
+
+    #[test]
+    fn test_store_round_trip() {
+        let store = MemoryStore(std::cell::RefCell::new(None));
+        assert_eq!(load_or_default(&store), (Session::default(), true));
+
+        let session = Session { score: Score { x_wins: 1, o_wins: 0, draws: 4 }, ..Session::default() };
+        save_to(&store, &session).unwrap();
+        assert_eq!(load_or_default(&store), (session, true));
+
+        // 壊れたデータは読み飛ばしてデフォルトで続ける（保存先自体は使える）
+        *store.0.borrow_mut() = Some("{".to_string());
+        assert_eq!(load_or_default(&store), (Session::default(), true));
+    }
+}
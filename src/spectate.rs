@@ -0,0 +1,138 @@
+// ============================================================================
+// Dioxus学習プロジェクト: 観戦リンク
+// ============================================================================
+// このファイルはAI同士の対局を「シード＋両者の戦略」で表すリンクを定義します。
+// 同じリンクを開けば、同じ乱数列から同じ対局が再現されます。
+//
+// 使い方（URLのクエリ文字列）:
+//   ?spectate=12345&x=minimax&o=random
+//
+// 学習ポイント:
+// - クエリ文字列の簡単な解析（split・split_once）
+// - シード付き疑似乱数による対局の再現
+// - Webではweb_sys::Locationから現在のURLを読む
+
+use crate::ai::{AiStrategy, Rng};
+use crate::types::{GameLogic, GameState, Player, WinRule};
+
+// ============================================================================
+// 型定義: 観戦リンク
+// ============================================================================
+#[derive(Clone, PartialEq, Debug)]
+pub struct SpectateLink {
+    pub seed: u64,        // 疑似乱数のシード
+    pub x: AiStrategy,    // Xを担当するAIの戦略
+    pub o: AiStrategy,    // Oを担当するAIの戦略
+}
+
+impl SpectateLink {
+    /// クエリ文字列（先頭の「?」は省略可）から読み取る（観戦リンクでなければNone）
+    /// 学習ポイント: 戦略が省略されたら「定石」、不明な値ならリンク全体を無効にする
+    pub fn parse(query: &str) -> Option<SpectateLink> {
+        let mut seed = None;
+        let mut x = AiStrategy::Heuristic;
+        let mut o = AiStrategy::Heuristic;
+
+        for pair in query.trim_start_matches('?').split('&') {
+            match pair.split_once('=') {
+                Some(("spectate", value)) => seed = Some(value.parse().ok()?),
+                Some(("x", value)) => x = AiStrategy::from_key(value)?,
+                Some(("o", value)) => o = AiStrategy::from_key(value)?,
+                _ => {}
+            }
+        }
+
+        Some(SpectateLink { seed: seed?, x, o })
+    }
+
+    /// クエリ文字列に変換する（parseの逆）
+    pub fn to_query(&self) -> String {
+        format!(
+            "?spectate={}&x={}&o={}",
+            self.seed,
+            self.x.key().unwrap_or("heuristic"),
+            self.o.key().unwrap_or("heuristic")
+        )
+    }
+
+    /// 指定プレイヤーを担当するAIの戦略
+    pub fn strategy(&self, player: Player) -> &AiStrategy {
+        match player {
+            Player::X => &self.x,
+            Player::O => &self.o,
+        }
+    }
+
+    /// 対局を最後まで進めた着手列を返す
+    /// 学習ポイント: 1つの乱数列を両者で共有し、手順全体を決定的にする
+    pub fn moves(&self) -> Vec<(usize, usize)> {
+        let mut rng = Rng::new(self.seed);
+        let mut board = GameLogic::empty_board();
+        let mut player = Player::X;
+        let mut moves = Vec::new();
+
+        while GameLogic::check_game_state(board) == GameState::Playing {
+            let Some((row, col)) = self.strategy(player).choose_move(board, player, WinRule::Standard, &mut rng) else {
+                break;
+            };
+            board[row][col] = Some(player);
+            moves.push((row, col));
+            player = player.next();
+        }
+
+        moves
+    }
+}
+
+/// 現在のページのURLから観戦リンクを読み取る（Web以外では常にNone）
+pub fn from_location() -> Option<SpectateLink> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let search = web_sys::window()?.location().search().ok()?;
+        SpectateLink::parse(&search)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+// ============================================================================
+// テスト: 観戦リンクの検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_to_query() {
+        let link = SpectateLink::parse("?spectate=42&x=minimax&o=random").unwrap();
+        assert_eq!(link, SpectateLink { seed: 42, x: AiStrategy::Minimax, o: AiStrategy::Random });
+        assert_eq!(SpectateLink::parse(&link.to_query()), Some(link));
+
+        // 戦略の省略は「定石」、シードがない・不正な値はNone
+        let link = SpectateLink::parse("spectate=7").unwrap();
+        assert_eq!((link.x, link.o), (AiStrategy::Heuristic, AiStrategy::Heuristic));
+        assert_eq!(SpectateLink::parse("?x=minimax"), None);
+        assert_eq!(SpectateLink::parse("?spectate=abc"), None);
+        assert_eq!(SpectateLink::parse("?spectate=1&x=unknown"), None);
+    }
+
+    #[test]
+    fn test_same_link_same_moves() {
+        let link = SpectateLink::parse("?spectate=2024&x=random&o=minimax").unwrap();
+        let moves = link.moves();
+        assert_eq!(moves, link.moves());
+        assert_eq!(SpectateLink::parse(&link.to_query()).unwrap().moves(), moves);
+
+        // 最後まで指した対局は終局している
+        let (_, state) = GameLogic::apply_sequence(GameLogic::empty_board(), &moves, Player::X).unwrap();
+        assert!(state.is_over());
+
+        // シードが違えば（ランダム同士では）普通は別の対局になる
+        let other = SpectateLink { seed: 1, x: AiStrategy::Random, o: AiStrategy::Random };
+        let another = SpectateLink { seed: 2, ..other.clone() };
+        assert_ne!(other.moves(), another.moves());
+    }
+}
@@ -0,0 +1,260 @@
+// ============================================================================
+// Dioxus学習プロジェクト: 最近の対局履歴
+// ============================================================================
+// このファイルは直近の対局結果（勝敗・手数・所要時間）を一定件数まで記録します。
+// 記録は端末内（セッション）にのみ保存し、外部には送信しません。
+//
+// 学習ポイント:
+// - VecDequeによる上限付きの履歴（古いものから捨てる）
+// - 記録の組み立てを純粋関数にしてテスト可能にする
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Board, GameLogic, Outcome, Player};
+
+/// 保持する対局数の上限
+pub const RECENT_GAMES_CAP: usize = 20;
+
+// ============================================================================
+// 型定義: 1局分の記録
+// ============================================================================
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub outcome: Outcome,           // 対局結果
+    pub ai_player: Option<Player>,  // AIが担当したプレイヤー（2人対戦ではNone）
+    pub moves: usize,               // 手数
+    pub duration_secs: u64,         // 所要時間（秒）
+}
+
+impl GameRecord {
+    /// 終局した盤面と開始・終了時刻（ミリ秒）から記録を作る
+    pub fn new(outcome: Outcome, ai_player: Option<Player>, board: Board, started_ms: u64, ended_ms: u64) -> Self {
+        let (x_count, o_count) = GameLogic::counts(board);
+        GameRecord {
+            outcome,
+            ai_player,
+            moves: x_count + o_count,
+            duration_secs: ended_ms.saturating_sub(started_ms) / 1000,
+        }
+    }
+
+    /// 結果の表示名（AI対戦では人間から見た勝ち・負け）
+    /// 学習ポイント: タプルのmatchとガード条件で視点を切り替える
+    pub fn label(&self) -> String {
+        match (self.outcome, self.ai_player) {
+            (Outcome::Draw, _) => "引き分け".to_string(),
+            (Outcome::Win(winner), Some(ai)) if winner == ai => "負け".to_string(),
+            (Outcome::Win(_), Some(_)) => "勝ち".to_string(),
+            (Outcome::Win(winner), None) => format!("{}の勝ち", winner.symbol()),
+        }
+    }
+}
+
+/// 記録を追加する（上限を超えたら最も古い記録を捨てる）
+pub fn push_record(games: &mut VecDeque<GameRecord>, record: GameRecord) {
+    games.push_back(record);
+    while games.len() > RECENT_GAMES_CAP {
+        games.pop_front();
+    }
+}
+
+// ============================================================================
+// 型定義: 先手・後手の成績（公平な対戦モード）
+// ============================================================================
+// 学習ポイント: ハンディキャップではOが先手になるので、対局ごとに記録した先手と勝者を比べて読み替える
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct FairnessTally {
+    pub starter_wins: u32,  // 先手の勝利数
+    pub second_wins: u32,   // 後手の勝利数
+    pub draws: u32,         // 引き分け数
+}
+
+impl FairnessTally {
+    /// 集計した対局数
+    pub fn games(&self) -> u32 {
+        self.starter_wins + self.second_wins + self.draws
+    }
+
+    /// 先手の勝ち越し数（負なら後手が勝ち越している）
+    pub fn starter_lead(&self) -> i32 {
+        self.starter_wins as i32 - self.second_wins as i32
+    }
+}
+
+/// （先手のプレイヤー, 対局結果）の一覧から先手・後手の成績を集計する
+/// 学習ポイント: fold()による集計
+pub fn fairness_tally(games: impl IntoIterator<Item = (Player, Outcome)>) -> FairnessTally {
+    games.into_iter().fold(FairnessTally::default(), |mut tally, (starter, outcome)| {
+        match outcome {
+            Outcome::Win(winner) if winner == starter => tally.starter_wins += 1,
+            Outcome::Win(_) => tally.second_wins += 1,
+            Outcome::Draw => tally.draws += 1,
+        }
+        tally
+    })
+}
+
+/// 公平な対戦モードで、game_index局目（0始まり）に人間が先手を持つか
+/// 学習ポイント: 局数の偶奇で先手を交互に入れ替える
+pub fn human_starts(game_index: usize) -> bool {
+    game_index.is_multiple_of(2)
+}
+
+// ============================================================================
+// 型定義: 先手交代の対戦（局数を決めた勝負）
+// ============================================================================
+// 先手交代モードで決めた局数を指し、勝ち数の多い側が対戦の勝者になる
+// 同点なら1局ずつの延長戦（サドンデス）を、決着がつくまで続ける
+// 学習ポイント: 延長戦も先手を交互に入れ替えるので、どちらかだけが有利にならない
+
+/// 対戦の局数（延長戦を除く）
+pub const MATCH_GAMES: usize = 3;
+
+/// 対戦する側（Aは1局目の先手、AI対戦では人間）
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MatchSide {
+    A,
+    B,
+}
+
+/// 対戦の状況
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MatchStatus {
+    InProgress,         // 決めた局数をまだ指し終えていない
+    SuddenDeath,        // 同点のため延長戦中（引き分けなら続ける）
+    Decided(MatchSide), // 勝者が決まった
+}
+
+/// 対局結果の一覧（1局目から順）から、対戦の状況を判定する
+/// 学習ポイント: 延長戦で勝負がつけば勝ち数に差ができるので、同点の判定だけで延長戦の継続がわかる
+pub fn match_status(outcomes: &[Outcome], planned_games: usize) -> MatchStatus {
+    let (a_wins, b_wins) = outcomes
+        .iter()
+        .enumerate()
+        .fold((0, 0), |(a, b), (index, &outcome)| match outcome {
+            // index局目はhuman_startsならAが先手（X）
+            Outcome::Win(winner) if (winner == Player::X) == human_starts(index) => (a + 1, b),
+            Outcome::Win(_) => (a, b + 1),
+            Outcome::Draw => (a, b),
+        });
+
+    if outcomes.len() < planned_games {
+        MatchStatus::InProgress
+    } else if a_wins > b_wins {
+        MatchStatus::Decided(MatchSide::A)
+    } else if b_wins > a_wins {
+        MatchStatus::Decided(MatchSide::B)
+    } else {
+        MatchStatus::SuddenDeath
+    }
+}
+
+// ============================================================================
+// テスト: 対局履歴の検証
+// ============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BoardBuilder, Handicap};
+
+    #[test]
+    fn test_game_record_new() {
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).x(0, 2).o(1, 0).o(1, 1).build();
+        let record = GameRecord::new(Outcome::Win(Player::X), Some(Player::O), board, 1_000, 13_500);
+
+        assert_eq!(record.moves, 5);
+        assert_eq!(record.duration_secs, 12);
+        assert_eq!(record.label(), "勝ち");
+    }
+
+    #[test]
+    fn test_game_record_label() {
+        let board = GameLogic::empty_board();
+        let record = |outcome, ai_player| GameRecord::new(outcome, ai_player, board, 0, 0);
+
+        assert_eq!(record(Outcome::Win(Player::X), Some(Player::X)).label(), "負け");
+        assert_eq!(record(Outcome::Win(Player::O), None).label(), "Oの勝ち");
+        assert_eq!(record(Outcome::Draw, Some(Player::O)).label(), "引き分け");
+    }
+
+    #[test]
+    fn test_push_record_evicts_oldest() {
+        let mut games = VecDeque::new();
+        for secs in 0..(RECENT_GAMES_CAP as u64 + 5) {
+            let record = GameRecord::new(Outcome::Draw, None, GameLogic::empty_board(), 0, secs * 1000);
+            push_record(&mut games, record);
+        }
+
+        // 上限件数だけ残り、最も古い5件が捨てられている
+        assert_eq!(games.len(), RECENT_GAMES_CAP);
+        assert_eq!(games.front().map(|g| g.duration_secs), Some(5));
+        assert_eq!(games.back().map(|g| g.duration_secs), Some(RECENT_GAMES_CAP as u64 + 4));
+    }
+
+    #[test]
+    fn test_fairness_tally() {
+        let games = [
+            (Player::X, Outcome::Win(Player::X)),
+            (Player::X, Outcome::Draw),
+            (Player::X, Outcome::Win(Player::X)),
+            (Player::X, Outcome::Win(Player::O)),
+        ];
+        let tally = fairness_tally(games);
+
+        assert_eq!(tally, FairnessTally { starter_wins: 2, second_wins: 1, draws: 1 });
+        assert_eq!(tally.games(), 4);
+        assert_eq!(tally.starter_lead(), 1);
+        assert_eq!(fairness_tally([]).games(), 0);
+    }
+
+    #[test]
+    fn test_fairness_tally_with_handicap_start() {
+        // ハンディキャップの対局はOが先手なので、Oの勝ちは先手の勝ちとして数える
+        let start_player = Handicap { player: Player::X, marks: 1 }.start().map(|(_, player)| player);
+        assert_eq!(start_player, Ok(Player::O));
+
+        let games = [
+            (Player::O, Outcome::Win(Player::O)),
+            (Player::O, Outcome::Win(Player::X)),
+            (Player::O, Outcome::Win(Player::O)),
+            (Player::X, Outcome::Win(Player::X)),
+        ];
+        let tally = fairness_tally(games);
+
+        assert_eq!(tally, FairnessTally { starter_wins: 3, second_wins: 1, draws: 0 });
+        assert_eq!(tally.starter_lead(), 2);
+    }
+
+    #[test]
+    fn test_match_status_sudden_death() {
+        use Outcome::{Draw, Win};
+
+        // 1局目はAが先手（X）、2局目はBが先手なので、Win(X)はA→Bの順に読み替える
+        let a_then_b = [Win(Player::X), Win(Player::X)];
+        assert_eq!(match_status(&a_then_b, MATCH_GAMES), MatchStatus::InProgress);
+
+        // 1勝1敗1分で予定の局数に達すると延長戦
+        let tied = [Win(Player::X), Win(Player::X), Draw];
+        assert_eq!(match_status(&tied, MATCH_GAMES), MatchStatus::SuddenDeath);
+
+        // 延長戦が引き分けなら続け、勝負がついたらその側の勝ち（4局目はB、5局目はAが先手）
+        let tied_again = [Win(Player::X), Win(Player::X), Draw, Draw];
+        assert_eq!(match_status(&tied_again, MATCH_GAMES), MatchStatus::SuddenDeath);
+        let fourth = [Win(Player::X), Win(Player::X), Draw, Win(Player::O)];
+        assert_eq!(match_status(&fourth, MATCH_GAMES), MatchStatus::Decided(MatchSide::A));
+        let fifth = [Win(Player::X), Win(Player::X), Draw, Draw, Win(Player::O)];
+        assert_eq!(match_status(&fifth, MATCH_GAMES), MatchStatus::Decided(MatchSide::B));
+
+        // 予定の局数で差がついていれば延長戦はない
+        let a_ahead = [Win(Player::X), Win(Player::O), Draw];
+        assert_eq!(match_status(&a_ahead, MATCH_GAMES), MatchStatus::Decided(MatchSide::A));
+    }
+
+    #[test]
+    fn test_human_starts_alternates() {
+        let starts: Vec<bool> = (0..4).map(human_starts).collect();
+        assert_eq!(starts, [true, false, true, false]);
+    }
+}
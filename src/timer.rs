@@ -0,0 +1,42 @@
+// ============================================================================
+// Dioxus学習プロジェクト: 非同期タイマー
+// ============================================================================
+// このファイルはプラットフォームに依存しない非同期スリープと現在時刻を提供します。
+//
+// 学習ポイント:
+// - #[cfg]属性によるプラットフォーム別実装の切り替え
+// - async/awaitとspawnによる非同期処理
+// - Web（wasm32）ではgloo-timers、デスクトップ/モバイルではtokioを使用
+
+use std::time::Duration;
+
+/// 指定ミリ秒だけ非同期に待機する
+/// 学習ポイント: spawn(async move { sleep_ms(1000).await; ... }) の形で使用
+pub async fn sleep_ms(ms: u64) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        gloo_timers::future::sleep(Duration::from_millis(ms)).await;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::time::sleep(Duration::from_millis(ms)).await;
+    }
+}
+
+/// 現在時刻（UNIXエポックからのミリ秒）を返す
+/// 学習ポイント: wasm32ではstd::time::SystemTimeが使えないため、JavaScriptのDate.now()を使う
+pub fn now_ms() -> u64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now() as u64
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or(0)
+    }
+}
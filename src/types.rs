@@ -9,15 +9,9 @@
 // - enumとimpl文による型安全な設計
 // - Option型とResult型の活用
 // - 関数型プログラミングパターン
+// - UIフレームワーク（Dioxus）に依存しない純粋なロジック
 
-use dioxus::prelude::*;
-
-// ============================================================================
-// アセット定義（モジュール内で使用）
-// ============================================================================
-// asset!マクロを使用してコンパイル時にアセットの存在を検証
-const X_ICON: Asset = asset!("/assets/x-icon.svg");
-const O_ICON: Asset = asset!("/assets/o-icon.svg");
+use serde::{Deserialize, Serialize};
 
 // ============================================================================
 // 型定義: プレイヤー
@@ -27,7 +21,8 @@ const O_ICON: Asset = asset!("/assets/o-icon.svg");
 // - Clone, Copy: 値の複製を効率的に行う
 // - PartialEq: 等価比較を可能にする
 // - Debug: デバッグ出力を可能にする
-#[derive(Clone, Copy, PartialEq, Debug)]
+// - Serialize, Deserialize: セッション保存（serde）に対応する
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Player {
     X,  // プレイヤーX
     O,  // プレイヤーO
@@ -45,15 +40,6 @@ impl Player {
         }
     }
 
-    /// プレイヤーのアイコンアセットを返す
-    /// 学習ポイント: Asset型との統合、コンパイル時アセット検証
-    pub fn icon(&self) -> Asset {
-        match self {
-            Player::X => X_ICON,
-            Player::O => O_ICON,
-        }
-    }
-
     /// 次のプレイヤーを返す
     /// 学習ポイント: 状態遷移の実装、ゲームロジック
     pub fn next(&self) -> Player {
@@ -62,6 +48,159 @@ impl Player {
             Player::O => Player::X,
         }
     }
+
+    /// 相手のプレイヤーを返す（2人対戦ではnext()と同じ）
+    /// 学習ポイント: 同じ計算でも、意図（手番の交代か・相手か）に合った名前で呼ぶ
+    pub fn opponent(&self) -> Player {
+        self.next()
+    }
+}
+
+// ============================================================================
+// 型定義: 記号テーマ
+// ============================================================================
+// プレイヤーの駒の見た目（記号セット）を切り替えるテーマ
+// 学習ポイント:
+// - #[default]属性によるDefaultトレイトの導出（enumのデフォルトバリアント指定）
+// - 関連定数（ALL）による全バリアントの列挙
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum SymbolTheme {
+    #[default]
+    Classic,   // X / O（SVGアイコン）
+    Animals,   // 🐱 / 🐶
+    SunMoon,   // 🌞 / 🌙
+    Weather,   // ⚡ / ❄️
+}
+
+impl SymbolTheme {
+    /// 選択肢として表示する全テーマ
+    pub const ALL: [SymbolTheme; 4] = [
+        SymbolTheme::Classic,
+        SymbolTheme::Animals,
+        SymbolTheme::SunMoon,
+        SymbolTheme::Weather,
+    ];
+
+    /// テーマ選択肢のラベル
+    pub fn label(&self) -> &'static str {
+        match self {
+            SymbolTheme::Classic => "クラシック",
+            SymbolTheme::Animals => "🐱 / 🐶",
+            SymbolTheme::SunMoon => "🌞 / 🌙",
+            SymbolTheme::Weather => "⚡ / ❄️",
+        }
+    }
+
+    /// プレイヤーに対応する記号（テキストとして描画する文字）
+    /// 学習ポイント: (テーマ, プレイヤー)のタプルマッチによる2次元の対応表
+    pub fn glyph(&self, player: Player) -> &'static str {
+        match (self, player) {
+            (SymbolTheme::Classic, _) => player.symbol(),
+            (SymbolTheme::Animals, Player::X) => "🐱",
+            (SymbolTheme::Animals, Player::O) => "🐶",
+            (SymbolTheme::SunMoon, Player::X) => "🌞",
+            (SymbolTheme::SunMoon, Player::O) => "🌙",
+            (SymbolTheme::Weather, Player::X) => "⚡",
+            (SymbolTheme::Weather, Player::O) => "❄️",
+        }
+    }
+
+    /// SVGアイコンで描画するテーマか（クラシックのみ）
+    pub fn uses_icons(&self) -> bool {
+        *self == SymbolTheme::Classic
+    }
+}
+
+// セル（クリック対象）の大きさ
+// 学習ポイント: 見た目の設定はenumで表し、具体的なクラス名はUI側（components.rs）で決める
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum CellSize {
+    Small,    // 小
+    #[default]
+    Medium,   // 中（従来の大きさ）
+    Large,    // 大（運動機能への配慮）
+}
+
+impl CellSize {
+    /// 選択肢として表示する全サイズ
+    pub const ALL: [CellSize; 3] = [CellSize::Small, CellSize::Medium, CellSize::Large];
+
+    /// サイズ選択肢のラベル
+    pub fn label(&self) -> &'static str {
+        match self {
+            CellSize::Small => "小",
+            CellSize::Medium => "中",
+            CellSize::Large => "大",
+        }
+    }
+}
+
+// クラシックテーマの駒の描画方法
+// 学習ポイント: 外部アセットを読み込まない描画方法を選べるようにする（埋め込み先への配慮）
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum MarkStyle {
+    #[default]
+    Svg,  // SVGアイコン（img要素）
+    Css,  // CSSで描いた線と輪（外部アセットなし）
+}
+
+impl MarkStyle {
+    /// 選択肢として表示する全描画方法
+    pub const ALL: [MarkStyle; 2] = [MarkStyle::Svg, MarkStyle::Css];
+
+    /// 描画方法の選択肢のラベル
+    pub fn label(&self) -> &'static str {
+        match self {
+            MarkStyle::Svg => "SVGアイコン",
+            MarkStyle::Css => "CSS",
+        }
+    }
+}
+
+// 盤面の表示の向き（見た目だけを回転し、盤面のデータと座標はそのまま）
+// 学習ポイント: 表示位置→論理位置の変換を1か所にまとめ、クリックは常に本来のマスに届ける
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum BoardRotation {
+    #[default]
+    None,          // 回転なし
+    Quarter,       // 時計回りに90度
+    Half,          // 180度
+    ThreeQuarter,  // 時計回りに270度
+}
+
+impl BoardRotation {
+    /// 選択肢として表示する全角度
+    pub const ALL: [BoardRotation; 4] = [
+        BoardRotation::None,
+        BoardRotation::Quarter,
+        BoardRotation::Half,
+        BoardRotation::ThreeQuarter,
+    ];
+
+    /// 角度の選択肢のラベル
+    pub fn label(&self) -> &'static str {
+        match self {
+            BoardRotation::None => "0°",
+            BoardRotation::Quarter => "90°",
+            BoardRotation::Half => "180°",
+            BoardRotation::ThreeQuarter => "270°",
+        }
+    }
+
+    /// 表示上の位置（行, 列）に描くマスの、盤面上の位置（行, 列）
+    pub fn to_logical(&self, row: usize, col: usize) -> (usize, usize) {
+        match self {
+            BoardRotation::None => (row, col),
+            BoardRotation::Quarter => (2 - col, row),
+            BoardRotation::Half => (2 - row, 2 - col),
+            BoardRotation::ThreeQuarter => (col, 2 - row),
+        }
+    }
+
+    /// 表示上の行と列が、盤面上の列と行に入れ替わるか（90度・270度）
+    pub fn swaps_axes(&self) -> bool {
+        matches!(self, BoardRotation::Quarter | BoardRotation::ThreeQuarter)
+    }
 }
 
 // ============================================================================
@@ -76,6 +215,86 @@ pub enum GameState {
     Draw,         // 引き分け
 }
 
+// ============================================================================
+// 型定義: 対局結果
+// ============================================================================
+// 終了した対局の結果だけを表す列挙型（Playingを含まない）
+// 学習ポイント: 「終わっていない」をOptionのNoneで表現し、型を分けて簡潔にする
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Outcome {
+    Win(Player),  // 勝利（勝者を保持）
+    Draw,         // 引き分け
+}
+
+// GameState enumのメソッド実装
+impl GameState {
+    /// 終了した対局の結果を返す（プレイ中はNone）
+    /// 学習ポイント: enum間の変換とOption型の組み合わせ
+    pub fn outcome(&self) -> Option<Outcome> {
+        match self {
+            GameState::Playing => None,
+            GameState::Won(player) => Some(Outcome::Win(*player)),
+            GameState::Draw => Some(Outcome::Draw),
+        }
+    }
+
+    /// 対局が終了しているか（勝利または引き分け）
+    pub fn is_over(&self) -> bool {
+        *self != GameState::Playing
+    }
+}
+
+// ============================================================================
+// 型定義: スコア
+// ============================================================================
+// 対局結果の累計（X勝利数・O勝利数・引き分け数）
+// 学習ポイント: Defaultトレイトの導出による0初期化
+// （#[serde(default)]で、保存データに欠けている項目も0として読み込む）
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Score {
+    pub x_wins: u32,  // Xの勝利数
+    pub o_wins: u32,  // Oの勝利数
+    pub draws: u32,   // 引き分け数
+}
+
+impl Score {
+    /// 対局結果を1件記録する
+    /// 学習ポイント: &mut selfによる構造体のフィールド更新
+    pub fn record(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Win(Player::X) => self.x_wins += 1,
+            Outcome::Win(Player::O) => self.o_wins += 1,
+            Outcome::Draw => self.draws += 1,
+        }
+    }
+
+    /// 指定プレイヤーの勝利数を返す
+    pub fn wins(&self, player: Player) -> u32 {
+        match player {
+            Player::X => self.x_wins,
+            Player::O => self.o_wins,
+        }
+    }
+
+    /// まだ1局も記録されていないか
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// previousから1局分増えた結果を返す（増えていない・リセット等で減った場合はNone）
+    /// 学習ポイント: 変化前後の値を比べて「どのカウンターが増えたか」を判定する
+    pub fn increment_from(&self, previous: Score) -> Option<Outcome> {
+        [Outcome::Win(Player::X), Outcome::Win(Player::O), Outcome::Draw]
+            .into_iter()
+            .find(|&outcome| {
+                let mut expected = previous;
+                expected.record(outcome);
+                expected == *self
+            })
+    }
+}
+
 // ============================================================================
 // 型エイリアス: ゲーム盤面
 // ============================================================================
@@ -84,6 +303,245 @@ pub enum GameState {
 // 学習ポイント: Option型による安全なnull表現、多次元配列の活用
 pub type Board = [[Option<Player>; 3]; 3];
 
+// 棋譜から読み取った着手列と、最初に指したプレイヤー（GameLogic::from_transcript）
+pub type Transcript = (Vec<(usize, usize)>, Player);
+
+// ============================================================================
+// 型定義: 盤面の対称変換
+// ============================================================================
+// 盤面を自分自身に重ねる8通りの変換（回転4通り＋裏返し4通り）
+// 学習ポイント: 基本の2操作（90度回転・左右反転）の組み合わせで全変換を表す
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Symmetry {
+    Identity,        // 恒等（何もしない）
+    Rotate90,        // 時計回りに90度回転
+    Rotate180,       // 180度回転
+    Rotate270,       // 時計回りに270度回転
+    VerticalAxis,    // 縦軸で左右反転
+    HorizontalAxis,  // 横軸で上下反転
+    MainDiagonal,    // 左上〜右下の対角線で反転
+    AntiDiagonal,    // 右上〜左下の対角線で反転
+}
+
+impl Symmetry {
+    /// すべての対称変換
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::VerticalAxis,
+        Symmetry::HorizontalAxis,
+        Symmetry::MainDiagonal,
+        Symmetry::AntiDiagonal,
+    ];
+
+    /// バッジに表示する名前
+    pub fn label(&self) -> &'static str {
+        match self {
+            Symmetry::Identity => "恒等",
+            Symmetry::Rotate90 => "90°回転",
+            Symmetry::Rotate180 => "180°回転",
+            Symmetry::Rotate270 => "270°回転",
+            Symmetry::VerticalAxis => "縦軸",
+            Symmetry::HorizontalAxis => "横軸",
+            Symmetry::MainDiagonal => "対角線",
+            Symmetry::AntiDiagonal => "逆対角線",
+        }
+    }
+
+    /// 盤面に変換を適用する
+    pub fn apply(&self, board: Board) -> Board {
+        let rotate = GameLogic::rotate90;
+        match self {
+            Symmetry::Identity => board,
+            Symmetry::Rotate90 => rotate(board),
+            Symmetry::Rotate180 => rotate(rotate(board)),
+            Symmetry::Rotate270 => rotate(rotate(rotate(board))),
+            Symmetry::VerticalAxis => GameLogic::mirror(board),
+            Symmetry::HorizontalAxis => rotate(rotate(GameLogic::mirror(board))),
+            Symmetry::MainDiagonal => GameLogic::transpose(board),
+            Symmetry::AntiDiagonal => rotate(GameLogic::mirror(board)),
+        }
+    }
+}
+
+// ============================================================================
+// 型定義: 勝利ラインのルール
+// ============================================================================
+// どのラインを揃えたら勝ちになるか（教材用の簡単なルールを選べる）
+// 学習ポイント: ルールごとの違いをデータ（GameLogic::lines）に閉じ込める
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum WinRule {
+    #[default]
+    Standard,     // 標準（横・縦・斜め）
+    NoDiagonals,  // 斜めなし（横・縦のみ）
+}
+
+impl WinRule {
+    /// すべてのルール
+    pub const ALL: [WinRule; 2] = [WinRule::Standard, WinRule::NoDiagonals];
+
+    /// 選択肢に表示する名前
+    pub fn label(&self) -> &'static str {
+        match self {
+            WinRule::Standard => "標準",
+            WinRule::NoDiagonals => "斜めなし",
+        }
+    }
+}
+
+// ============================================================================
+// 型定義: 着手のヒント
+// ============================================================================
+// 空きセルに置いたらどうなるかを、初心者向けに3通りで表す（コーチモードの長押し用）
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MoveHint {
+    Wins,     // 置けばすぐに勝てる
+    Blocks,   // 相手のリーチを止める
+    Neutral,  // どちらでもない
+}
+
+impl MoveHint {
+    /// ヒントとして表示する文言
+    pub fn label(&self) -> &'static str {
+        match self {
+            MoveHint::Wins => "勝てる手",
+            MoveHint::Blocks => "相手を止める手",
+            MoveHint::Neutral => "ふつうの手",
+        }
+    }
+}
+
+// ============================================================================
+// 型定義: 着手エラー
+// ============================================================================
+// 手順をまとめて適用するときに、何手目でなぜ失敗したかを表す列挙型
+// 学習ポイント: エラーの種類ごとにバリアントを分け、呼び出し側で区別できるようにする
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MoveError {
+    OutOfBounds { index: usize, row: usize, col: usize },  // 盤面の範囲外
+    Occupied { index: usize, row: usize, col: usize },     // すでに駒がある
+}
+
+impl std::fmt::Display for MoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MoveError::OutOfBounds { index, row, col } => {
+                write!(f, "{}手目の({}, {})は盤面の範囲外です", index + 1, row, col)
+            }
+            MoveError::Occupied { index, row, col } => {
+                write!(f, "{}手目の({}, {})にはすでに駒があります", index + 1, row, col)
+            }
+        }
+    }
+}
+
+// ============================================================================
+// 型定義: 着手の可否
+// ============================================================================
+// セルに今置けるかどうかと、置けない理由（ツールチップ・読み上げ用）
+// 学習ポイント: boolではなくenumで返し、UIが理由ごとに文言を変えられるようにする
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MoveStatus {
+    Ok,           // 置ける
+    OutOfBounds,  // 盤面の範囲外
+    Occupied,     // すでに駒がある
+    GameOver,     // 対局が終わっている
+}
+
+impl MoveStatus {
+    /// 置けない理由（置けるならNone）
+    pub fn reason(&self) -> Option<&'static str> {
+        match self {
+            MoveStatus::Ok => None,
+            MoveStatus::OutOfBounds => Some("盤面の範囲外です"),
+            MoveStatus::Occupied => Some("すでに置かれています"),
+            MoveStatus::GameOver => Some("ゲーム終了"),
+        }
+    }
+}
+
+// ============================================================================
+// 型定義: 対局中の出来事
+// ============================================================================
+// 着手によって起きた、勝敗には関係しない出来事（授業用の解説メモに使う）
+// 学習ポイント: ルールを変えずに「何が起きたか」だけをデータとして返す
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GameEvent {
+    LineBlocked([(usize, usize); 3]),  // 両者の駒が混ざったラインの、最後の空きが埋まった
+}
+
+impl GameEvent {
+    /// 解説メモとして表示する文言
+    pub fn note(&self) -> &'static str {
+        match self {
+            GameEvent::LineBlocked(_) => "このラインは両方の駒で埋まり、どちらも揃えられなくなりました",
+        }
+    }
+}
+
+// ============================================================================
+// 型定義: ハンディキャップ
+// ============================================================================
+// 弱い側のプレイヤーに、対局開始時点で駒を何個か置いておく設定
+// 学習ポイント: 設定値（どちらに何個）と、そこから作る開始局面を分けて持つ
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Handicap {
+    pub player: Player,  // 駒を置いておくプレイヤー
+    pub marks: usize,    // 置いておく駒の数（0ならハンディキャップなし）
+}
+
+impl Handicap {
+    /// ハンディキャップなし
+    pub const NONE: Handicap = Handicap { player: Player::X, marks: 0 };
+
+    /// 置いておける駒の上限
+    pub const MAX_MARKS: usize = 2;
+
+    /// 駒を置く順番（中央→角。上限の個数ではラインが揃わない並び）
+    const CELLS: [(usize, usize); Self::MAX_MARKS] = [(1, 1), (0, 0)];
+
+    /// 選択肢（なし・各プレイヤーに1〜上限個）
+    pub const CHOICES: [Handicap; 5] = [
+        Handicap::NONE,
+        Handicap { player: Player::X, marks: 1 },
+        Handicap { player: Player::X, marks: 2 },
+        Handicap { player: Player::O, marks: 1 },
+        Handicap { player: Player::O, marks: 2 },
+    ];
+
+    /// 選択肢に表示する名前
+    pub fn label(&self) -> String {
+        match self.marks {
+            0 => "なし".to_string(),
+            marks => format!("{}に{}個", self.player.symbol(), marks),
+        }
+    }
+
+    /// 開始局面と、最初に着手するプレイヤーを求める
+    /// 学習ポイント:
+    /// - 駒を置いた側は1手指したものとみなし、相手から着手する
+    /// - 駒数が通常の対局と合わないことがあるため、validateの駒数チェックの代わりに
+    ///   「置いた駒だけでラインが揃っていない」ことを確認する
+    pub fn start(&self) -> Result<(Board, Player), &'static str> {
+        if self.marks > Self::MAX_MARKS {
+            return Err("ハンディキャップの駒が多すぎます");
+        }
+
+        let mut board = GameLogic::empty_board();
+        for &(row, col) in &Self::CELLS[..self.marks] {
+            board[row][col] = Some(self.player);
+        }
+        if GameLogic::check_game_state(board) != GameState::Playing {
+            return Err("ハンディキャップの駒でラインが揃っています");
+        }
+
+        let to_move = if self.marks == 0 { Player::X } else { self.player.opponent() };
+        Ok((board, to_move))
+    }
+}
+
 // ============================================================================
 // ゲームロジック: 勝敗判定システム
 // ============================================================================
@@ -95,11 +553,37 @@ pub type Board = [[Option<Player>; 3]; 3];
 pub struct GameLogic;
 
 impl GameLogic {
+    /// 勝利ライン（横3本・縦3本・斜め2本）となる8本の座標の組
+    const LINES: [[(usize, usize); 3]; 8] = [
+        [(0, 0), (0, 1), (0, 2)],
+        [(1, 0), (1, 1), (1, 2)],
+        [(2, 0), (2, 1), (2, 2)],
+        [(0, 0), (1, 0), (2, 0)],
+        [(0, 1), (1, 1), (2, 1)],
+        [(0, 2), (1, 2), (2, 2)],
+        [(0, 0), (1, 1), (2, 2)],
+        [(0, 2), (1, 1), (2, 0)],
+    ];
+
+    /// ルールで勝ちになるラインの一覧（斜めなしでは先頭の横・縦6本だけ）
+    /// 学習ポイント: 配列のスライスでルールごとのラインを表す
+    pub fn lines(rule: WinRule) -> &'static [[(usize, usize); 3]] {
+        match rule {
+            WinRule::Standard => &Self::LINES,
+            WinRule::NoDiagonals => &Self::LINES[..6],
+        }
+    }
+
     /// ゲーム盤面から現在の状態を判定する
     /// 学習ポイント: 複合的な条件判定を段階的に実装
     pub fn check_game_state(board: Board) -> GameState {
+        Self::check_game_state_with(board, WinRule::Standard)
+    }
+
+    /// 指定したルールで現在の状態を判定する
+    pub fn check_game_state_with(board: Board, rule: WinRule) -> GameState {
         // 勝敗判定を実行
-        if let Some(winner) = Self::check_winner(board) {
+        if let Some(winner) = Self::winner_with(board, rule) {
             return GameState::Won(winner);
         }
 
@@ -111,50 +595,41 @@ impl GameLogic {
         }
     }
 
-    /// 勝者がいるかチェックする
-    /// 学習ポイント: Option型による安全な値の返却
-    fn check_winner(board: Board) -> Option<Player> {
-        // 横列をチェック（行ごとの勝敗判定）
-        for row in 0..3 {
-            if let Some(winner) = Self::check_line([
-                board[row][0],
-                board[row][1],
-                board[row][2]
-            ]) {
-                return Some(winner);
-            }
-        }
+    /// 直前に置いたセルを通るラインだけを調べて状態を判定する
+    /// 学習ポイント: 着手前が対局中なら、新しく揃いうるのは置いたセルを通るラインだけ
+    /// （全ラインを調べるcheck_game_stateと同じ結果になる）
+    pub fn check_after_move(board: Board, row: usize, col: usize) -> GameState {
+        Self::check_after_move_with(board, row, col, WinRule::Standard)
+    }
 
-        // 縦列をチェック（列ごとの勝敗判定）
-        for col in 0..3 {
-            if let Some(winner) = Self::check_line([
-                board[0][col],
-                board[1][col],
-                board[2][col]
-            ]) {
-                return Some(winner);
-            }
-        }
+    /// 指定したルールで、直前に置いたセルを通るラインだけを調べて状態を判定する
+    pub fn check_after_move_with(board: Board, row: usize, col: usize, rule: WinRule) -> GameState {
+        let winner = Self::lines(rule)
+            .iter()
+            .filter(|line| line.contains(&(row, col)))
+            .find_map(|line| Self::check_line(line.map(|(r, c)| board[r][c])));
 
-        // 対角線をチェック（左上から右下）
-        if let Some(winner) = Self::check_line([
-            board[0][0],
-            board[1][1],
-            board[2][2]
-        ]) {
-            return Some(winner);
+        match winner {
+            Some(winner) => GameState::Won(winner),
+            None if Self::is_board_full(board) => GameState::Draw,
+            None => GameState::Playing,
         }
+    }
 
-        // 対角線をチェック（右上から左下）
-        if let Some(winner) = Self::check_line([
-            board[0][2],
-            board[1][1],
-            board[2][0]
-        ]) {
-            return Some(winner);
-        }
+    /// 勝者がいるかチェックする（引き分けの判定はしないので、勝者だけ知りたいときに使う）
+    /// 学習ポイント: Option型による安全な値の返却
+    pub fn winner(board: Board) -> Option<Player> {
+        Self::winner_with(board, WinRule::Standard)
+    }
 
-        None
+    /// 指定したルールで勝者がいるかチェックする
+    /// 学習ポイント:
+    /// - 縦列は転置した盤面の横列として、横列と同じように調べる
+    /// - 対角線はルールのライン一覧（先頭6本の横・縦より後ろ、斜めなしでは空）から取り出す
+    pub fn winner_with(board: Board, rule: WinRule) -> Option<Player> {
+        let rows_and_columns = board.into_iter().chain(Self::transpose(board));
+        let diagonals = Self::lines(rule)[6..].iter().map(|line| line.map(|(r, c)| board[r][c]));
+        rows_and_columns.chain(diagonals).find_map(Self::check_line)
     }
 
     /// 3つのセルが同じプレイヤーで埋まっているかチェック
@@ -166,10 +641,15 @@ impl GameLogic {
         }
     }
 
-    /// 盤面が満杯かどうかをチェック
-    /// 学習ポイント: イテレータチェーンとall()の活用
-    fn is_board_full(board: Board) -> bool {
-        board.iter().flatten().all(|cell| cell.is_some())
+    /// 盤面が満杯かどうかをチェック（「もう置ける場所がない」の唯一の判定）
+    pub fn is_board_full(board: Board) -> bool {
+        Self::moves_remaining(board) == 0
+    }
+
+    /// 残りの着手可能数（空きマスの数、3x3では最大9）
+    /// 学習ポイント: イテレータチェーンとfilter()・count()の活用
+    pub fn moves_remaining(board: Board) -> usize {
+        board.iter().flatten().filter(|cell| cell.is_none()).count()
     }
 
     /// 空の盤面を作成
@@ -178,10 +658,31 @@ impl GameLogic {
         [[None; 3]; 3]
     }
 
+    /// 指定位置のセルを安全に取得する（範囲外ならNone）
+    /// 学習ポイント:
+    /// - get()による境界チェック付きアクセスでパニックを防ぐ
+    /// - Option<Option<T>>で「範囲外」と「空のセル」を区別
+    pub fn cell(board: Board, row: usize, col: usize) -> Option<Option<Player>> {
+        board.get(row)?.get(col).copied()
+    }
+
     /// 指定位置にプレイヤーの駒を配置可能かチェック
     /// 学習ポイント: バリデーション関数パターン
     pub fn is_valid_move(board: Board, row: usize, col: usize) -> bool {
-        row < 3 && col < 3 && board[row][col].is_none()
+        // 範囲内（Some）かつ空のセル（None）のときだけ配置可能
+        matches!(Self::cell(board, row, col), Some(None))
+    }
+
+    /// 指定位置に今置けるかを、置けない理由と一緒に返す
+    /// 学習ポイント: is_valid_moveの判定に「対局が終わっているか」を加える
+    /// （理由が重なる場合は 範囲外 → 対局終了 → 駒あり の順に優先する）
+    pub fn move_status(board: Board, game_state: GameState, row: usize, col: usize) -> MoveStatus {
+        match Self::cell(board, row, col) {
+            None => MoveStatus::OutOfBounds,
+            Some(_) if game_state != GameState::Playing => MoveStatus::GameOver,
+            Some(Some(_)) => MoveStatus::Occupied,
+            Some(None) => MoveStatus::Ok,
+        }
     }
 
     /// 盤面に駒を配置する（新しい盤面を返す）
@@ -194,83 +695,1642 @@ impl GameLogic {
         board[row][col] = Some(player);
         Ok(board)
     }
-}
-
-// ============================================================================
-// テスト: ゲームロジックの検証
-// ============================================================================
-// 学習ポイント: Rustのテスト駆動開発パターン
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_empty_board() {
-        let board = GameLogic::empty_board();
-        assert_eq!(GameLogic::check_game_state(board), GameState::Playing);
+    /// そのセルに置けばすぐに勝てるか（置けないセルはfalse）
+    /// 学習ポイント: make_moveが盤面のコピーを返すので、元の盤面は変わらない
+    pub fn is_winning_move(board: Board, row: usize, col: usize, player: Player) -> bool {
+        Self::is_winning_move_with(board, row, col, player, WinRule::Standard)
     }
 
-    #[test]
-    fn test_horizontal_win() {
-        let mut board = GameLogic::empty_board();
-        board[0][0] = Some(Player::X);
-        board[0][1] = Some(Player::X);
-        board[0][2] = Some(Player::X);
+    /// 指定したルールで、そのセルに置けばすぐに勝てるか
+    pub fn is_winning_move_with(board: Board, row: usize, col: usize, player: Player, rule: WinRule) -> bool {
+        Self::make_move(board, row, col, player)
+            .is_ok_and(|next| Self::winner_with(next, rule) == Some(player))
+    }
 
-        assert_eq!(GameLogic::check_game_state(board), GameState::Won(Player::X));
+    /// そのセルに置く手を、勝ち・相手を止める・どちらでもないに分類する（置けないセルはNone）
+    /// 学習ポイント: 勝ちを優先し、同時に相手を止める手も「勝てる手」として扱う
+    pub fn explain_move(board: Board, row: usize, col: usize, player: Player) -> Option<MoveHint> {
+        Self::explain_move_with(board, row, col, player, WinRule::Standard)
     }
 
-    #[test]
-    fn test_vertical_win() {
-        let mut board = GameLogic::empty_board();
-        board[0][0] = Some(Player::O);
-        board[1][0] = Some(Player::O);
-        board[2][0] = Some(Player::O);
+    /// 指定したルールで、そのセルに置く手を分類する
+    pub fn explain_move_with(board: Board, row: usize, col: usize, player: Player, rule: WinRule) -> Option<MoveHint> {
+        if !matches!(board.get(row).and_then(|cells| cells.get(col)), Some(None)) {
+            return None;
+        }
+        Some(if Self::is_winning_move_with(board, row, col, player, rule) {
+            MoveHint::Wins
+        } else if Self::is_winning_move_with(board, row, col, player.opponent(), rule) {
+            MoveHint::Blocks
+        } else {
+            MoveHint::Neutral
+        })
+    }
 
-        assert_eq!(GameLogic::check_game_state(board), GameState::Won(Player::O));
+    /// 手順をまとめて適用する（firstから交互に着手し、決着したらそこで止める）
+    /// 学習ポイント: 途中の失敗を何手目かと一緒にResultで返す
+    pub fn apply_sequence(
+        start: Board,
+        moves: &[(usize, usize)],
+        first: Player,
+    ) -> Result<(Board, GameState), MoveError> {
+        Self::apply_sequence_with(start, moves, first, WinRule::Standard)
     }
 
-    #[test]
-    fn test_diagonal_win() {
-        let mut board = GameLogic::empty_board();
-        board[0][0] = Some(Player::X);
-        board[1][1] = Some(Player::X);
-        board[2][2] = Some(Player::X);
+    /// 指定したルールで手順をまとめて適用する
+    pub fn apply_sequence_with(
+        start: Board,
+        moves: &[(usize, usize)],
+        first: Player,
+        rule: WinRule,
+    ) -> Result<(Board, GameState), MoveError> {
+        let mut board = start;
+        let mut player = first;
+        let mut state = Self::check_game_state_with(board, rule);
 
-        assert_eq!(GameLogic::check_game_state(board), GameState::Won(Player::X));
+        for (index, &(row, col)) in moves.iter().enumerate() {
+            if state.is_over() {
+                break;
+            }
+            match Self::cell(board, row, col) {
+                None => return Err(MoveError::OutOfBounds { index, row, col }),
+                Some(Some(_)) => return Err(MoveError::Occupied { index, row, col }),
+                Some(None) => board[row][col] = Some(player),
+            }
+            state = Self::check_game_state_with(board, rule);
+            player = player.next();
+        }
+
+        Ok((board, state))
     }
 
-    #[test]
-    fn test_draw() {
-        let board = [
-            [Some(Player::X), Some(Player::O), Some(Player::X)],
-            [Some(Player::O), Some(Player::O), Some(Player::X)],
-            [Some(Player::O), Some(Player::X), Some(Player::O)],
-        ];
+    /// 盤面を直接書き換えて駒を配置する（置けた場合のみtrue）
+    /// 学習ポイント: &mut参照による「確認と書き込み」の一体化
+    /// （with_mutの中で呼ぶことで、連続クリックでも二重配置されない）
+    pub fn try_place(board: &mut Board, row: usize, col: usize, player: Player) -> bool {
+        if !Self::is_valid_move(*board, row, col) {
+            return false;
+        }
 
-        assert_eq!(GameLogic::check_game_state(board), GameState::Draw);
+        board[row][col] = Some(player);
+        true
     }
 
-    #[test]
-    fn test_valid_move() {
-        let board = GameLogic::empty_board();
-        assert!(GameLogic::is_valid_move(board, 0, 0));
-        assert!(!GameLogic::is_valid_move(board, 3, 0)); // 範囲外
+    /// 中央のセル（1,1）を押さえているプレイヤーを返す（ヒューリスティック戦略の「中央」の判定）
+    /// 学習ポイント: 位置に基づくヒューリスティック（評価関数）の部品
+    pub fn center_controlled(board: Board) -> Option<Player> {
+        board[1][1]
     }
 
-    #[test]
-    fn test_make_move() {
-        let board = GameLogic::empty_board();
-        let result = GameLogic::make_move(board, 0, 0, Player::X);
+    /// 4つの角のセル座標
+    pub const CORNERS: [(usize, usize); 4] = [(0, 0), (0, 2), (2, 0), (2, 2)];
 
-        assert!(result.is_ok());
+    /// 4つの辺（角以外の外周）のセル座標
+    pub const SIDES: [(usize, usize); 4] = [(0, 1), (1, 0), (1, 2), (2, 1)];
+
+    /// 同じ評価の手から選ぶ優先順（中央→角→辺、角・辺の中は行優先）
+    /// 学習ポイント: 探索の順番を固定すると、同じ局面では常に同じ手が選ばれる
+    pub const MOVE_ORDER: [(usize, usize); 9] = [
+        (1, 1),
+        (0, 0), (0, 2), (2, 0), (2, 2),
+        (0, 1), (1, 0), (1, 2), (2, 1),
+    ];
+
+    /// 指定プレイヤーが押さえている角の数を数える（ヒューリスティック戦略の「相手の角の対角」の判定）
+    pub fn corner_count(board: Board, player: Player) -> usize {
+        Self::CORNERS
+            .iter()
+            .filter(|&&(row, col)| board[row][col] == Some(player))
+            .count()
+    }
+
+    /// 2つの盤面で内容が異なるセルの座標一覧を返す
+    /// 学習ポイント: zipによる2つのイテレータの同時走査
+    /// （受信した手が1セルだけを変更しているかの検証などに使用）
+    pub fn diff(a: Board, b: Board) -> Vec<(usize, usize)> {
+        (0..3)
+            .flat_map(|row| (0..3).map(move |col| (row, col)))
+            .zip(a.iter().flatten().zip(b.iter().flatten()))
+            .filter(|(_, (cell_a, cell_b))| cell_a != cell_b)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// キー入力（テンキー配列）をセルの座標に変換する
+    /// 学習ポイント: テンキーと同じ並びで盤面に対応付ける
+    ///
+    /// ```text
+    /// 7 | 8 | 9      (0,0) | (0,1) | (0,2)
+    /// 4 | 5 | 6  =>  (1,0) | (1,1) | (1,2)
+    /// 1 | 2 | 3      (2,0) | (2,1) | (2,2)
+    /// ```
+    ///
+    /// 数字キー（"1"〜"9"）とテンキーのコード（"Numpad1"〜"Numpad9"）を受け付ける
+    pub fn cell_from_key(key: &str) -> Option<(usize, usize)> {
+        let digit = key.strip_prefix("Numpad").unwrap_or(key);
+        let n = match digit.parse::<usize>() {
+            Ok(n @ 1..=9) => n - 1,
+            _ => return None,
+        };
+
+        // 下段が1〜3なので、行は上下を反転させる
+        Some((2 - n / 3, n % 3))
+    }
+
+    /// 指定プレイヤーの駒の数を数える
+    /// 学習ポイント: filter()とcount()によるイテレータ集計
+    pub fn count_pieces(board: Board, player: Player) -> usize {
+        board.iter().flatten().filter(|cell| **cell == Some(player)).count()
+    }
+
+    /// X・Oそれぞれの駒の数（X, O）
+    /// 学習ポイント: Xが先手なので、対局中は「同数」か「Xが1つ多い」のどちらか
+    pub fn counts(board: Board) -> (usize, usize) {
+        (Self::count_pieces(board, Player::X), Self::count_pieces(board, Player::O))
+    }
+
+    /// 盤面編集でセルの中身を順番に切り替える（空→X→O→空、reverseなら逆順の空→O→X→空）
+    pub fn cycle_cell(cell: Option<Player>, reverse: bool) -> Option<Player> {
+        match (cell, reverse) {
+            (None, false) | (Some(Player::O), true) => Some(Player::X),
+            (Some(Player::X), false) | (None, true) => Some(Player::O),
+            (Some(Player::O), false) | (Some(Player::X), true) => None,
+        }
+    }
+
+    /// 通常の対局で到達可能な盤面かどうかを検証する
+    /// 学習ポイント: Result型による検証結果とエラー理由の返却
+    pub fn validate(board: Board) -> Result<(), &'static str> {
+        Self::validate_with(board, WinRule::Standard)
+    }
+
+    /// 指定したルールの対局で到達可能な盤面かどうかを検証する
+    /// 学習ポイント: 斜めなしでは斜めの3つ揃いは勝ちにならず、その後も対局が続いた盤面でありうる
+    pub fn validate_with(board: Board, rule: WinRule) -> Result<(), &'static str> {
+        let x_count = Self::count_pieces(board, Player::X);
+        let o_count = Self::count_pieces(board, Player::O);
+
+        // Xが先手なので、駒数は「同数」か「Xが1つ多い」のどちらか
+        if x_count != o_count && x_count != o_count + 1 {
+            return Err("駒の数が不正です");
+        }
+
+        // 勝者がいる場合、勝者が最後に置いたはずなので駒数と整合している必要がある
+        match Self::winner_with(board, rule) {
+            Some(Player::X) if x_count != o_count + 1 => Err("Xの勝利後に駒が置かれています"),
+            Some(Player::O) if x_count != o_count => Err("Oの勝利後に駒が置かれています"),
+            Some(winner) if Self::count_lines(board, winner.opponent(), rule) > 0 => {
+                Err("両方のプレイヤーが勝利しています")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 指定プレイヤーが揃えているラインの数を数える
+    /// 学習ポイント: 全ラインの定義を配列で持つデータ駆動な判定
+    fn count_lines(board: Board, player: Player, rule: WinRule) -> usize {
+        Self::lines(rule)
+            .iter()
+            .filter(|line| line.iter().all(|&(r, c)| board[r][c] == Some(player)))
+            .count()
+    }
+
+    /// 指定プレイヤーがまだ揃えられるラインが残っているか
+    /// （相手の駒が1つもないラインが1本でもあればtrue）
+    /// 学習ポイント: any()とall()の組み合わせによる存在判定
+    pub fn can_still_win(board: Board, player: Player) -> bool {
+        Self::can_still_win_with(board, player, WinRule::Standard)
+    }
+
+    /// 指定したルールで、指定プレイヤーがまだ揃えられるラインが残っているか
+    pub fn can_still_win_with(board: Board, player: Player, rule: WinRule) -> bool {
+        Self::lines(rule)
+            .iter()
+            .any(|line| line.iter().all(|&(r, c)| board[r][c] != Some(player.opponent())))
+    }
+
+    /// 両者の駒で埋まった（もう誰も揃えられない）ラインを列挙する
+    pub fn blocked_lines(board: Board) -> Vec<[(usize, usize); 3]> {
+        Self::blocked_lines_with(board, WinRule::Standard)
+    }
+
+    /// 指定したルールで、両者の駒で埋まったラインを列挙する（斜めなしでは斜めを数えない）
+    pub fn blocked_lines_with(board: Board, rule: WinRule) -> Vec<[(usize, usize); 3]> {
+        Self::lines(rule)
+            .iter()
+            .filter(|line| {
+                let cells = line.map(|(r, c)| board[r][c]);
+                cells.contains(&Some(Player::X)) && cells.contains(&Some(Player::O)) && !cells.contains(&None)
+            })
+            .copied()
+            .collect()
+    }
+
+    /// 直前に置いたセルで起きた出来事を返す
+    /// 学習ポイント: 埋まったラインのうち置いたセルを通るものが、今の手で最後の空きを埋めたライン
+    pub fn events_after_move(board: Board, row: usize, col: usize) -> Vec<GameEvent> {
+        Self::events_after_move_with(board, row, col, WinRule::Standard)
+    }
+
+    /// 指定したルールで、直前に置いたセルで起きた出来事を返す
+    pub fn events_after_move_with(board: Board, row: usize, col: usize, rule: WinRule) -> Vec<GameEvent> {
+        Self::blocked_lines_with(board, rule)
+            .into_iter()
+            .filter(|line| line.contains(&(row, col)))
+            .map(GameEvent::LineBlocked)
+            .collect()
+    }
+
+    /// どちらもラインを揃えられず、引き分けが確定しているか
+    pub fn is_draw_inevitable(board: Board) -> bool {
+        Self::is_draw_inevitable_with(board, WinRule::Standard)
+    }
+
+    /// 指定したルールで、引き分けが確定しているか
+    pub fn is_draw_inevitable_with(board: Board, rule: WinRule) -> bool {
+        !Self::can_still_win_with(board, Player::X, rule) && !Self::can_still_win_with(board, Player::O, rule)
+    }
+
+    /// 盤面の駒数から次の手番を推定する（Xが先手）
+    /// 学習ポイント: 盤面だけから状態を復元する推論
+    pub fn infer_current_player(board: Board) -> Player {
+        if Self::count_pieces(board, Player::X) > Self::count_pieces(board, Player::O) {
+            Player::O
+        } else {
+            Player::X
+        }
+    }
+
+    /// お互いが最善を尽くした場合の勝者を求める（Noneは引き分け）
+    /// 学習ポイント:
+    /// - ミニマックス法による全探索（3x3なので全局面を読み切れる）
+    /// - 再帰関数による木構造の探索
+    pub fn perfect_play_winner(board: Board, to_move: Player) -> Option<Player> {
+        Self::perfect_play_winner_with(board, to_move, WinRule::Standard)
+    }
+
+    /// 指定したルールで、お互いが最善を尽くした場合の勝者を求める
+    pub fn perfect_play_winner_with(board: Board, to_move: Player, rule: WinRule) -> Option<Player> {
+        match Self::check_game_state_with(board, rule) {
+            GameState::Won(winner) => return Some(winner),
+            GameState::Draw => return None,
+            GameState::Playing => {}
+        }
+
+        let mut can_draw = false;
+        for (row, col) in Self::empty_cells(board) {
+            let mut next = board;
+            next[row][col] = Some(to_move);
+
+            match Self::perfect_play_winner_with(next, to_move.next(), rule) {
+                // 勝てる手が1つでもあれば勝ち
+                Some(winner) if winner == to_move => return Some(to_move),
+                None => can_draw = true,
+                Some(_) => {}
+            }
+        }
+
+        // 勝てる手がなければ、引き分けに持ち込めるかどうか
+        if can_draw { None } else { Some(to_move.opponent()) }
+    }
+
+    /// 局面から指せるすべての手順を終局まで展開し、結末ごとの数を返す（Xの勝ち, Oの勝ち, 引き分け）
+    /// 学習ポイント:
+    /// - 最善手だけでなくすべての手を展開する（ゲーム木の葉の数を数える）
+    /// - 子の集計を足し合わせる再帰（3x3なので空の盤面からでも数え切れる）
+    pub fn count_outcomes(board: Board, to_move: Player) -> (usize, usize, usize) {
+        Self::count_outcomes_with(board, to_move, WinRule::Standard)
+    }
+
+    /// 指定したルールで、すべての手順の結末ごとの数を返す
+    pub fn count_outcomes_with(board: Board, to_move: Player, rule: WinRule) -> (usize, usize, usize) {
+        match Self::check_game_state_with(board, rule) {
+            GameState::Won(Player::X) => return (1, 0, 0),
+            GameState::Won(Player::O) => return (0, 1, 0),
+            GameState::Draw => return (0, 0, 1),
+            GameState::Playing => {}
+        }
+
+        Self::empty_cells(board)
+            .into_iter()
+            .map(|(row, col)| {
+                let mut next = board;
+                next[row][col] = Some(to_move);
+                Self::count_outcomes_with(next, to_move.next(), rule)
+            })
+            .fold((0, 0, 0), |(x, o, d), (cx, co, cd)| (x + cx, o + co, d + cd))
+    }
+
+    /// 手番のプレイヤーが必ず勝てるなら、勝つまでの最短の手数（両者の着手を数える）を返す
+    /// 学習ポイント: 自分は最短で勝てる手を、相手は最も長く粘れる手を選ぶミニマックス法
+    pub fn mate_in(board: Board, to_move: Player) -> Option<usize> {
+        Self::mate_in_with(board, to_move, WinRule::Standard)
+    }
+
+    /// 指定したルールで、必ず勝てるなら勝つまでの最短の手数を返す
+    pub fn mate_in_with(board: Board, to_move: Player, rule: WinRule) -> Option<usize> {
+        if Self::check_game_state_with(board, rule) != GameState::Playing {
+            return None;
+        }
+        Self::forced_win_plies(board, to_move, to_move, rule)
+    }
+
+    /// attackerが勝つまでの手数（相手がどう指しても勝てないならNone）
+    fn forced_win_plies(board: Board, to_move: Player, attacker: Player, rule: WinRule) -> Option<usize> {
+        match Self::check_game_state_with(board, rule) {
+            GameState::Won(winner) if winner == attacker => return Some(0),
+            GameState::Won(_) | GameState::Draw => return None,
+            GameState::Playing => {}
+        }
+
+        let mut replies = Self::empty_cells(board).into_iter().map(|(row, col)| {
+            let mut next = board;
+            next[row][col] = Some(to_move);
+            Self::forced_win_plies(next, to_move.next(), attacker, rule).map(|plies| plies + 1)
+        });
+
+        if to_move == attacker {
+            // どれか1手で勝てればよい（その中の最短）
+            replies.flatten().min()
+        } else {
+            // 相手のすべての手に勝ち筋が必要（その中の最長）
+            replies.try_fold(0, |longest, plies| plies.map(|p| longest.max(p)))
+        }
+    }
+
+    /// 勝敗が理論上決まっているか（相手が最善を尽くしても負けを避けられないか）を判定する
+    /// 学習ポイント: 探索結果をゲームの「勝勢」判定として再利用
+    pub fn is_decided(board: Board, to_move: Player) -> Option<Player> {
+        Self::is_decided_with(board, to_move, WinRule::Standard)
+    }
+
+    /// 指定したルールで、勝敗が理論上決まっているかを判定する
+    pub fn is_decided_with(board: Board, to_move: Player, rule: WinRule) -> Option<Player> {
+        Self::perfect_play_winner_with(board, to_move, rule)
+    }
+
+    /// 勝ちの評価値の基準（勝った時点の残りマス数を加える）
+    pub const WIN_SCORE: i32 = 10;
+
+    /// 通常の対局での評価値の絶対値の最大（最短の5手目で勝ったとき：基準 + 残り4マス）
+    pub const MAX_EVALUATION: i32 = Self::WIN_SCORE + 9 - 5;
+
+    /// 最善手順での評価値を返す（Xから見た値：正ならX有利、負ならO有利、0は引き分け）
+    /// 学習ポイント:
+    /// - Xは最大化、Oは最小化するミニマックス法
+    /// - 残りマス数を加えて「早く勝つ」ほど絶対値が大きくなるようにする
+    pub fn evaluate(board: Board, to_move: Player) -> i32 {
+        Self::evaluate_with(board, to_move, WinRule::Standard)
+    }
+
+    /// 指定したルールでの最善手順の評価値を返す
+    pub fn evaluate_with(board: Board, to_move: Player, rule: WinRule) -> i32 {
+        let remaining = Self::moves_remaining(board) as i32;
+        match Self::check_game_state_with(board, rule) {
+            GameState::Won(Player::X) => return Self::WIN_SCORE + remaining,
+            GameState::Won(Player::O) => return -(Self::WIN_SCORE + remaining),
+            GameState::Draw => return 0,
+            GameState::Playing => {}
+        }
+
+        let scores = Self::empty_cells(board).into_iter().map(|(row, col)| {
+            let mut next = board;
+            next[row][col] = Some(to_move);
+            Self::evaluate_with(next, to_move.next(), rule)
+        });
+
+        match to_move {
+            Player::X => scores.max().unwrap_or(0),
+            Player::O => scores.min().unwrap_or(0),
+        }
+    }
+
+    /// そのセルに置いた直後の局面の評価値（置けないセルはNone）
+    /// 学習ポイント: 駒を置いた盤面のコピーを評価するので、実際の盤面は変わらない
+    pub fn evaluate_move(board: Board, row: usize, col: usize, player: Player) -> Option<i32> {
+        Self::evaluate_move_with(board, row, col, player, WinRule::Standard)
+    }
+
+    /// 指定したルールで、そのセルに置いた直後の局面の評価値を返す
+    pub fn evaluate_move_with(board: Board, row: usize, col: usize, player: Player, rule: WinRule) -> Option<i32> {
+        let next = Self::make_move(board, row, col, player).ok()?;
+        Some(Self::evaluate_with(next, player.next(), rule))
+    }
+
+    /// 盤面を時計回りに90度回転する
+    pub fn rotate90(board: Board) -> Board {
+        let mut rotated = Self::empty_board();
+        for (row, cells) in rotated.iter_mut().enumerate() {
+            for (col, cell) in cells.iter_mut().enumerate() {
+                *cell = board[2 - col][row];
+            }
+        }
+        rotated
+    }
+
+    /// 盤面を左右反転する
+    pub fn mirror(board: Board) -> Board {
+        board.map(|mut row| {
+            row.reverse();
+            row
+        })
+    }
+
+    /// 盤面を転置する（行と列を入れ替える、左上から右下の対角線で折り返すのと同じ）
+    /// 学習ポイント: 列の判定を、転置した盤面の行の判定として書ける
+    pub fn transpose(board: Board) -> Board {
+        std::array::from_fn(|row| std::array::from_fn(|col| board[col][row]))
+    }
+
+    /// 盤面を変えない対称変換の一覧（空の盤面なら8通りすべて）
+    /// 学習ポイント: filterで「変換しても同じ盤面」になるものだけを残す
+    pub fn symmetries(board: Board) -> Vec<Symmetry> {
+        Symmetry::ALL
+            .into_iter()
+            .filter(|symmetry| symmetry.apply(board) == board)
+            .collect()
+    }
+
+    /// 最善手を1つ返す（終局済みならNone）
+    /// 最善手が複数あれば、MOVE_ORDERの順（中央→角→辺→行優先）で最初の手を選ぶ
+    /// 学習ポイント: 子局面の評価値が手番から見て最善のものを選ぶ
+    pub fn best_move(board: Board, to_move: Player) -> Option<(usize, usize)> {
+        Self::best_move_with(board, to_move, WinRule::Standard)
+    }
+
+    /// 指定したルールで最善手を1つ返す
+    pub fn best_move_with(board: Board, to_move: Player, rule: WinRule) -> Option<(usize, usize)> {
+        if Self::check_game_state_with(board, rule) != GameState::Playing {
+            return None;
+        }
+
+        let scored = Self::MOVE_ORDER
+            .into_iter()
+            .filter(|&(row, col)| board[row][col].is_none())
+            .map(|(row, col)| {
+                let mut next = board;
+                next[row][col] = Some(to_move);
+                ((row, col), Self::evaluate_with(next, to_move.next(), rule))
+            });
+        // min_by_keyは同点なら最初の要素を返すので、Xは符号を反転して最大を選ぶ
+        let best = match to_move {
+            Player::X => scored.min_by_key(|&(_, score)| -score),
+            Player::O => scored.min_by_key(|&(_, score)| score),
+        };
+        best.map(|(cell, _)| cell)
+    }
+
+    /// 最善手順（読み筋）を終局まで返す
+    /// 学習ポイント: best_moveを終局まで繰り返し、1手ずつ進める
+    pub fn principal_variation(board: Board, to_move: Player) -> Vec<(usize, usize)> {
+        Self::principal_variation_with(board, to_move, WinRule::Standard)
+    }
+
+    /// 指定したルールでの最善手順を終局まで返す
+    pub fn principal_variation_with(board: Board, to_move: Player, rule: WinRule) -> Vec<(usize, usize)> {
+        let mut line = Vec::new();
+        let mut board = board;
+        let mut player = to_move;
+
+        while let Some((row, col)) = Self::best_move_with(board, player, rule) {
+            board[row][col] = Some(player);
+            line.push((row, col));
+            player = player.next();
+        }
+
+        line
+    }
+
+    /// 両者が最善手を指して終局まで進めた盤面とゲーム状態を返す（終局済みならそのまま）
+    pub fn complete_with_best_play(board: Board, to_move: Player) -> (Board, GameState) {
+        Self::complete_with_best_play_with(board, to_move, WinRule::Standard)
+    }
+
+    /// 指定したルールで、両者が最善手を指して終局まで進めた盤面とゲーム状態を返す
+    pub fn complete_with_best_play_with(board: Board, to_move: Player, rule: WinRule) -> (Board, GameState) {
+        let line = Self::principal_variation_with(board, to_move, rule);
+        Self::apply_sequence_with(board, &line, to_move, rule)
+            .unwrap_or((board, Self::check_game_state_with(board, rule)))
+    }
+
+    /// 空いているセルの座標一覧を返す
+    /// 学習ポイント: flat_mapによる2次元配列の走査
+    pub fn empty_cells(board: Board) -> Vec<(usize, usize)> {
+        (0..3)
+            .flat_map(|row| (0..3).map(move |col| (row, col)))
+            .filter(|&(row, col)| board[row][col].is_none())
+            .collect()
+    }
+
+    /// 盤面を検証し、その盤面に対応するゲーム状態と現在のプレイヤーを求める
+    /// 学習ポイント: ?演算子によるエラーの早期リターン
+    pub fn load_board(board: Board) -> Result<(GameState, Player), &'static str> {
+        Self::load_board_with(board, WinRule::Standard)
+    }
+
+    /// 指定したルールで盤面を検証し、ゲーム状態と現在のプレイヤーを求める
+    pub fn load_board_with(board: Board, rule: WinRule) -> Result<(GameState, Player), &'static str> {
+        Self::validate_with(board, rule)?;
+
+        let game_state = Self::check_game_state_with(board, rule);
+        let current_player = match game_state {
+            GameState::Playing => Self::infer_current_player(board),
+            // 終局時は最後に置いたプレイヤーのまま（通常の対局と同じ表示にする）
+            _ => Self::infer_current_player(board).next(),
+        };
+
+        Ok((game_state, current_player))
+    }
+
+    /// 開始局面（ハンディキャップ等）から指し進めた盤面として検証し、ゲーム状態と現在のプレイヤーを求める
+    /// 学習ポイント:
+    /// - 開始局面の駒は駒数が合わなくてよいので、開始局面の後に置いた駒だけを数える
+    /// - 最初の手番から交互に置いたなら、最初の手番の駒は「同数」か「1つ多い」のどちらか
+    pub fn load_board_from(
+        (start, first): (Board, Player),
+        board: Board,
+        rule: WinRule,
+    ) -> Result<(GameState, Player), &'static str> {
+        let kept = (0..3).all(|row| (0..3).all(|col| start[row][col].is_none() || board[row][col] == start[row][col]));
+        if !kept {
+            return Err("開始局面の駒が盤面にありません");
+        }
+
+        let placed = |player| Self::count_pieces(board, player) - Self::count_pieces(start, player);
+        let (first_count, second_count) = (placed(first), placed(first.opponent()));
+        if first_count != second_count && first_count != second_count + 1 {
+            return Err("駒の数が不正です");
+        }
+
+        // 勝者は最後に置いたプレイヤー（次の手番ではない）でなければならない
+        let to_move = if first_count == second_count { first } else { first.opponent() };
+        match Self::winner_with(board, rule) {
+            Some(winner) if winner == to_move => return Err("勝利後に駒が置かれています"),
+            Some(winner) if Self::count_lines(board, winner.opponent(), rule) > 0 => {
+                return Err("両方のプレイヤーが勝利しています");
+            }
+            _ => {}
+        }
+
+        let game_state = Self::check_game_state_with(board, rule);
+        let current_player = match game_state {
+            GameState::Playing => to_move,
+            // 終局時は最後に置いたプレイヤーのまま（load_board_withと同じ）
+            _ => to_move.next(),
+        };
+        Ok((game_state, current_player))
+    }
+
+    /// 共有用の絵文字グリッド（❌・⭕・⬜の3行）に変換する
+    /// 学習ポイント: map()とcollect::<String>()による文字列の組み立て
+    pub fn emoji_grid(board: Board) -> String {
+        board
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| match cell {
+                        Some(Player::X) => "❌",
+                        Some(Player::O) => "⭕",
+                        None => "⬜",
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// テキスト表記から盤面を読み取る
+    /// 学習ポイント: 1文字ずつ解釈し、区切り文字は読み飛ばす
+    ///
+    /// ```text
+    /// "XO./.X./..O"  または  "X O . | . X . | . . O"
+    /// ```
+    ///
+    /// X・Oは大文字小文字を問わず、空きマスは「.」「-」「_」で表す。
+    /// 共有用の絵文字グリッド（❌・⭕・⬜、emoji_gridの出力）もそのまま読める。
+    /// 空白・「|」「/」「,」と絵文字の異体字セレクタは区切りとして無視する（駒数などの検証はvalidateで行う）
+    pub fn parse_board(text: &str) -> Result<Board, &'static str> {
+        let mut cells = Vec::with_capacity(9);
+        for ch in text.chars() {
+            match ch {
+                'X' | 'x' | '❌' => cells.push(Some(Player::X)),
+                'O' | 'o' | '⭕' => cells.push(Some(Player::O)),
+                '.' | '-' | '_' | '⬜' => cells.push(None),
+                c if c.is_whitespace() || matches!(c, '|' | '/' | ',' | '\u{FE0F}') => {}
+                _ => return Err("盤面に使えない文字が含まれています"),
+            }
+        }
+
+        if cells.len() != 9 {
+            return Err("盤面は9マス分の記号が必要です");
+        }
+
+        let mut board = Self::empty_board();
+        for (index, cell) in cells.into_iter().enumerate() {
+            board[index / 3][index % 3] = cell;
+        }
+        Ok(board)
+    }
+
+    /// 共有されたテキスト（見出し行＋絵文字グリッド）から盤面を読み取る
+    /// 学習ポイント: 全体で読めなければ、末尾の3行（グリッド部分）だけで読み直す
+    pub fn parse_shared_board(text: &str) -> Result<Board, &'static str> {
+        Self::parse_board(text).or_else(|error| {
+            let lines: Vec<&str> = text.lines().filter(|line| !line.trim().is_empty()).collect();
+            match lines.len() {
+                0..=3 => Err(error),
+                len => Self::parse_board(&lines[len - 3..].join("\n")),
+            }
+        })
+    }
+
+    /// 着手列を棋譜の文字列にする（例: 「1. X b2 2. O a1」）
+    /// 学習ポイント: 座標は盤面の座標ラベルと同じく、列をa〜c・行を1〜3（上から）で表す
+    pub fn to_transcript(history: &[(usize, usize)], first: Player) -> String {
+        let mut player = first;
+        let mut parts = Vec::with_capacity(history.len());
+        for (index, &(row, col)) in history.iter().enumerate() {
+            let file = (b'a' + col as u8) as char;
+            parts.push(format!("{}. {} {}{}", index + 1, player.symbol(), file, row + 1));
+            player = player.next();
+        }
+        parts.join(" ")
+    }
+
+    /// 棋譜の文字列を着手列と最初のプレイヤーに戻す（to_transcriptの逆、空の棋譜はエラー）
+    /// 学習ポイント: 「番号・プレイヤー・座標」の3語ずつを、chunksでまとめて検証する
+    pub fn from_transcript(text: &str) -> Result<Transcript, &'static str> {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        if words.is_empty() || !words.len().is_multiple_of(3) {
+            return Err("棋譜の形式が正しくありません");
+        }
+
+        let mut moves = Vec::with_capacity(words.len() / 3);
+        let mut first = None;
+        for (index, chunk) in words.chunks(3).enumerate() {
+            let [number, symbol, square] = [chunk[0], chunk[1], chunk[2]];
+            if number != format!("{}.", index + 1) {
+                return Err("手数の番号が正しくありません");
+            }
+
+            let player = match symbol {
+                "X" | "x" => Player::X,
+                "O" | "o" => Player::O,
+                _ => return Err("プレイヤーはXかOで書いてください"),
+            };
+            let expected = first.map_or(player, |first: Player| if index.is_multiple_of(2) { first } else { first.next() });
+            if player != expected {
+                return Err("手番が交互になっていません");
+            }
+            first.get_or_insert(player);
+
+            let mut chars = square.chars();
+            let (col, row) = match (chars.next(), chars.next(), chars.next()) {
+                (Some(file @ 'a'..='c'), Some(rank @ '1'..='3'), None) => {
+                    (file as usize - 'a' as usize, rank as usize - '1' as usize)
+                }
+                _ => return Err("座標はa1〜c3で書いてください"),
+            };
+            moves.push((row, col));
+        }
+
+        first.map(|first| (moves, first)).ok_or("棋譜の形式が正しくありません")
+    }
+}
+
+// ============================================================================
+// テスト用ヘルパー: BoardBuilder
+// ============================================================================
+// テスト用の盤面をメソッドチェーンで組み立てるビルダー
+// 学習ポイント:
+// - ビルダーパターン（selfを受け取りselfを返すfluent API）
+// - #[cfg(test)]によるテスト時のみのコンパイル
+#[cfg(test)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct BoardBuilder {
+    board: Board,
+}
+
+#[cfg(test)]
+impl BoardBuilder {
+    /// 空の盤面から組み立てを開始する
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 指定位置にXを置く
+    pub fn x(self, row: usize, col: usize) -> Self {
+        self.place(row, col, Player::X)
+    }
+
+    /// 指定位置にOを置く
+    pub fn o(self, row: usize, col: usize) -> Self {
+        self.place(row, col, Player::O)
+    }
+
+    /// 指定位置に任意のプレイヤーの駒を置く（既存の駒は上書き）
+    pub fn place(mut self, row: usize, col: usize, player: Player) -> Self {
+        self.board[row][col] = Some(player);
+        self
+    }
+
+    /// 盤面上のXとOをすべて入れ替える（対称なケースのテスト用）
+    pub fn swap_marks(mut self) -> Self {
+        self.board = self.board.map(|row| row.map(|cell| cell.map(|player| player.opponent())));
+        self
+    }
+
+    /// 指定位置の駒を返す
+    pub fn player_at(&self, row: usize, col: usize) -> Option<Player> {
+        self.board[row][col]
+    }
+
+    /// 組み立てた盤面を返す
+    pub fn build(self) -> Board {
+        self.board
+    }
+}
+
+// ============================================================================
+// テスト用ヘルパー: 状態の不変条件
+// ============================================================================
+// 盤面・ゲーム状態・手番の3つが互いに矛盾していないことを確かめる
+// 学習ポイント: 操作のたびに同じ検査を呼ぶことで、機能追加による退行を早く見つける
+#[cfg(test)]
+impl GameLogic {
+    /// 不変条件を満たさなければパニックする（テスト専用）
+    /// - 駒数・勝者が通常の対局で到達できる盤面である
+    /// - ゲーム状態が盤面から判定した状態と一致する
+    /// - 手番が盤面から推定した手番と一致する（終局後は最後に置いたプレイヤー）
+    #[track_caller]
+    pub fn assert_invariants(board: Board, game_state: GameState, current_player: Player) {
+        let (expected_state, expected_player) = match Self::load_board(board) {
+            Ok(loaded) => loaded,
+            Err(error) => panic!("不正な盤面です（{}）: {:?}", error, board),
+        };
+        assert_eq!(game_state, expected_state, "ゲーム状態が盤面と一致しません: {:?}", board);
+        assert_eq!(current_player, expected_player, "手番が盤面と一致しません: {:?}", board);
+    }
+}
+
+// ============================================================================
+// テスト: ゲームロジックの検証
+// ============================================================================
+// 学習ポイント: Rustのテスト駆動開発パターン
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_board() {
+        let board = GameLogic::empty_board();
+        assert_eq!(GameLogic::check_game_state(board), GameState::Playing);
+    }
+
+    #[test]
+    fn test_horizontal_win() {
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).x(0, 2).build();
+
+        assert_eq!(GameLogic::check_game_state(board), GameState::Won(Player::X));
+    }
+
+    #[test]
+    fn test_vertical_win() {
+        let board = BoardBuilder::new().o(0, 0).o(1, 0).o(2, 0).build();
+
+        assert_eq!(GameLogic::check_game_state(board), GameState::Won(Player::O));
+    }
+
+    #[test]
+    fn test_board_builder() {
+        let builder = BoardBuilder::new().x(0, 0).o(1, 1);
+        assert_eq!(builder.player_at(0, 0), Some(Player::X));
+        assert_eq!(builder.player_at(1, 1), Some(Player::O));
+        assert_eq!(builder.player_at(2, 2), None);
+
+        // swap_marksでXとOが入れ替わる
+        let swapped = builder.swap_marks();
+        assert_eq!(swapped.player_at(0, 0), Some(Player::O));
+        assert_eq!(swapped.player_at(1, 1), Some(Player::X));
+    }
+
+    #[test]
+    fn test_diagonal_win() {
+        let mut board = GameLogic::empty_board();
+        board[0][0] = Some(Player::X);
+        board[1][1] = Some(Player::X);
+        board[2][2] = Some(Player::X);
+
+        assert_eq!(GameLogic::check_game_state(board), GameState::Won(Player::X));
+    }
+
+    #[test]
+    fn test_draw() {
+        let board = [
+            [Some(Player::X), Some(Player::O), Some(Player::X)],
+            [Some(Player::O), Some(Player::O), Some(Player::X)],
+            [Some(Player::O), Some(Player::X), Some(Player::O)],
+        ];
+
+        assert_eq!(GameLogic::check_game_state(board), GameState::Draw);
+    }
+
+    #[test]
+    fn test_valid_move() {
+        let board = GameLogic::empty_board();
+        assert!(GameLogic::is_valid_move(board, 0, 0));
+        assert!(!GameLogic::is_valid_move(board, 3, 0)); // 範囲外
+    }
+
+    #[test]
+    fn test_make_move() {
+        let board = GameLogic::empty_board();
+        let result = GameLogic::make_move(board, 0, 0, Player::X);
+
+        assert!(result.is_ok());
         let new_board = result.unwrap();
         assert_eq!(new_board[0][0], Some(Player::X));
     }
 
+    #[test]
+    fn test_double_click_places_once() {
+        let mut board = GameLogic::empty_board();
+
+        // 同じセルへの連続クリック（2回目は手番が進んだ後のプレイヤー）
+        assert!(GameLogic::try_place(&mut board, 1, 1, Player::X));
+        assert!(!GameLogic::try_place(&mut board, 1, 1, Player::O));
+
+        assert_eq!(board[1][1], Some(Player::X));
+        assert_eq!(GameLogic::count_pieces(board, Player::X), 1);
+        assert_eq!(GameLogic::count_pieces(board, Player::O), 0);
+    }
+
+    #[test]
+    fn test_load_board_accepts_legal_board() {
+        let board = BoardBuilder::new().x(0, 0).o(1, 1).x(2, 2).build();
+
+        assert_eq!(
+            GameLogic::load_board(board),
+            Ok((GameState::Playing, Player::O))
+        );
+    }
+
+    #[test]
+    fn test_load_board_reports_winner() {
+        let mut board = GameLogic::empty_board();
+        board[0][0] = Some(Player::X);
+        board[0][1] = Some(Player::X);
+        board[0][2] = Some(Player::X);
+        board[1][0] = Some(Player::O);
+        board[1][1] = Some(Player::O);
+
+        assert_eq!(
+            GameLogic::load_board(board),
+            Ok((GameState::Won(Player::X), Player::X))
+        );
+    }
+
+    #[test]
+    fn test_load_board_rejects_illegal_board() {
+        // Oの駒が多すぎる
+        let mut board = GameLogic::empty_board();
+        board[0][0] = Some(Player::O);
+        board[1][1] = Some(Player::O);
+        assert!(GameLogic::load_board(board).is_err());
+
+        // Xの勝利後にOが置いている
+        let mut board = GameLogic::empty_board();
+        board[0][0] = Some(Player::X);
+        board[0][1] = Some(Player::X);
+        board[0][2] = Some(Player::X);
+        board[1][0] = Some(Player::O);
+        board[1][1] = Some(Player::O);
+        board[2][2] = Some(Player::O);
+        assert!(GameLogic::validate(board).is_err());
+    }
+
+    #[test]
+    fn test_parse_board() {
+        let expected = BoardBuilder::new().x(0, 0).o(0, 1).x(1, 1).o(2, 2).build();
+        assert_eq!(GameLogic::parse_board("XO./.X./..O"), Ok(expected));
+        assert_eq!(GameLogic::parse_board("x o - | - x - | _ _ o"), Ok(expected));
+
+        // 文字数の過不足や不明な文字はエラー
+        assert!(GameLogic::parse_board("XO.").is_err());
+        assert!(GameLogic::parse_board("XO./.X./..O.").is_err());
+        assert!(GameLogic::parse_board("XO./.Z./..O").is_err());
+    }
+
+    #[test]
+    fn test_is_decided_immediate_win() {
+        // Xの手番で、Xは上段を揃えられる
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).o(1, 0).o(1, 1).build();
+        assert_eq!(GameLogic::is_decided(board, Player::X), Some(Player::X));
+    }
+
+    #[test]
+    fn test_is_decided_fork() {
+        // Oの手番だが、Xは2か所で揃う（ダブルリーチ）ので防げない
+        let board = BoardBuilder::new().x(0, 0).x(0, 2).x(2, 2).o(0, 1).o(2, 0).build();
+        assert_eq!(GameLogic::is_decided(board, Player::O), Some(Player::X));
+    }
+
+    #[test]
+    fn test_is_decided_balanced() {
+        assert_eq!(GameLogic::is_decided(GameLogic::empty_board(), Player::X), None);
+
+        let board = BoardBuilder::new().x(0, 0).o(1, 1).build();
+        assert_eq!(GameLogic::is_decided(board, Player::X), None);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        // Xが次の手で勝てる：X有利
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).o(1, 0).o(1, 1).build();
+        assert!(GameLogic::evaluate(board, Player::X) > 0);
+
+        // 互いにリーチだが、Oの手番なので先に揃えられる：O有利
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).x(2, 2).o(1, 0).o(1, 1).build();
+        assert!(GameLogic::evaluate(board, Player::O) < 0);
+
+        // 初期盤面は最善を尽くせば引き分け
+        assert_eq!(GameLogic::evaluate(GameLogic::empty_board(), Player::X), 0);
+
+        // 早く勝てる局面ほど評価が高い（一手で勝てる局面 > ダブルリーチで2手後に勝つ局面）
+        let immediate = BoardBuilder::new().x(0, 0).x(0, 1).o(1, 0).o(1, 1).build();
+        let fork = BoardBuilder::new().x(0, 0).x(2, 2).o(1, 1).o(0, 2).build();
+        assert!(GameLogic::evaluate(fork, Player::X) > 0);
+        assert!(GameLogic::evaluate(immediate, Player::X) > GameLogic::evaluate(fork, Player::X));
+    }
+
+    #[test]
+    fn test_can_still_win() {
+        // 初期盤面ではどちらも揃えられる
+        assert!(GameLogic::can_still_win(GameLogic::empty_board(), Player::X));
+
+        // Oは全ラインをXに塞がれているが、Xには中段・中列が残っている
+        let board = BoardBuilder::new()
+            .x(0, 1).x(1, 0).x(1, 1).x(2, 2)
+            .o(0, 0).o(0, 2).o(2, 0)
+            .build();
+        assert!(!GameLogic::can_still_win(board, Player::O));
+        assert!(GameLogic::can_still_win(board, Player::X));
+        assert!(!GameLogic::is_draw_inevitable(board));
+
+        // 残り1マスでどのラインも両者の駒が混ざっている：引き分け確定
+        let blocked = BoardBuilder::new()
+            .x(0, 0).x(0, 2).x(1, 0).x(2, 1)
+            .o(0, 1).o(1, 1).o(1, 2).o(2, 0)
+            .build();
+        assert!(GameLogic::is_draw_inevitable(blocked));
+    }
+
+    #[test]
+    fn test_line_helpers_follow_no_diagonals() {
+        // 斜めだけが塞がった盤面：斜めなしでは塞がったラインとして数えない
+        let board = BoardBuilder::new().x(0, 0).o(1, 1).x(2, 2).build();
+        assert_eq!(GameLogic::events_after_move(board, 2, 2).len(), 1);
+        assert!(GameLogic::events_after_move_with(board, 2, 2, WinRule::NoDiagonals).is_empty());
+
+        // 辺をすべてOが押さえている：Xに残るのは斜めだけ
+        let sides = BoardBuilder::new().o(0, 1).o(1, 0).o(1, 2).o(2, 1).build();
+        assert!(GameLogic::can_still_win_with(sides, Player::X, WinRule::Standard));
+        assert!(!GameLogic::can_still_win_with(sides, Player::X, WinRule::NoDiagonals));
+
+        // 最後の1マスで斜めが揃う：斜めなしでは引き分け
+        let last = GameLogic::parse_board("XOX/OXO/OX.").unwrap();
+        assert_eq!(GameLogic::count_outcomes(last, Player::X), (1, 0, 0));
+        assert_eq!(GameLogic::count_outcomes_with(last, Player::X, WinRule::NoDiagonals), (0, 0, 1));
+        assert_eq!(GameLogic::complete_with_best_play_with(last, Player::X, WinRule::NoDiagonals).1, GameState::Draw);
+    }
+
+    #[test]
+    fn test_emoji_grid() {
+        let board = BoardBuilder::new().x(0, 0).o(1, 1).x(2, 2).build();
+        assert_eq!(GameLogic::emoji_grid(board), "❌⬜⬜\n⬜⭕⬜\n⬜⬜❌");
+    }
+
+    #[test]
+    fn test_outcome() {
+        assert_eq!(GameState::Playing.outcome(), None);
+        assert!(!GameState::Playing.is_over());
+        assert!(GameState::Draw.is_over());
+        assert_eq!(GameState::Won(Player::X).outcome(), Some(Outcome::Win(Player::X)));
+        assert_eq!(GameState::Won(Player::O).outcome(), Some(Outcome::Win(Player::O)));
+        assert_eq!(GameState::Draw.outcome(), Some(Outcome::Draw));
+    }
+
+    #[test]
+    fn test_cell_from_key() {
+        assert_eq!(GameLogic::cell_from_key("7"), Some((0, 0)));
+        assert_eq!(GameLogic::cell_from_key("9"), Some((0, 2)));
+        assert_eq!(GameLogic::cell_from_key("5"), Some((1, 1)));
+        assert_eq!(GameLogic::cell_from_key("1"), Some((2, 0)));
+        assert_eq!(GameLogic::cell_from_key("3"), Some((2, 2)));
+        assert_eq!(GameLogic::cell_from_key("Numpad8"), Some((0, 1)));
+
+        assert_eq!(GameLogic::cell_from_key("0"), None);
+        assert_eq!(GameLogic::cell_from_key("a"), None);
+        assert_eq!(GameLogic::cell_from_key("Numpad0"), None);
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = BoardBuilder::new().x(0, 0).o(1, 1).build();
+        let after = BoardBuilder::new().x(0, 0).o(1, 1).x(2, 1).build();
+
+        assert_eq!(GameLogic::diff(before, after), vec![(2, 1)]);
+        assert_eq!(GameLogic::diff(before, before), vec![]);
+    }
+
+    #[test]
+    fn test_cell_access() {
+        let board = BoardBuilder::new().x(0, 0).build();
+
+        assert_eq!(GameLogic::cell(board, 0, 0), Some(Some(Player::X)));
+        assert_eq!(GameLogic::cell(board, 2, 2), Some(None));
+        assert_eq!(GameLogic::cell(board, 3, 0), None);
+        assert_eq!(GameLogic::cell(board, 0, 3), None);
+        assert_eq!(GameLogic::cell(board, usize::MAX, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_score_record() {
+        let mut score = Score::default();
+        assert!(score.is_empty());
+
+        score.record(Outcome::Win(Player::X));
+        score.record(Outcome::Win(Player::X));
+        score.record(Outcome::Draw);
+
+        assert_eq!(score.wins(Player::X), 2);
+        assert_eq!(score.wins(Player::O), 0);
+        assert_eq!(score.draws, 1);
+        assert!(!score.is_empty());
+    }
+
+    #[test]
+    fn test_symbol_theme_glyphs() {
+        for theme in SymbolTheme::ALL {
+            let x = theme.glyph(Player::X);
+            let o = theme.glyph(Player::O);
+            assert!(!x.is_empty() && !o.is_empty(), "{:?}", theme);
+            assert_ne!(x, o, "{:?}", theme);
+        }
+    }
+
+    #[test]
+    fn test_positional_helpers() {
+        let board = BoardBuilder::new().x(0, 0).o(1, 1).x(2, 2).o(0, 1).build();
+
+        assert_eq!(GameLogic::center_controlled(board), Some(Player::O));
+        assert_eq!(GameLogic::corner_count(board, Player::X), 2);
+        assert_eq!(GameLogic::corner_count(board, Player::O), 0);
+
+        let empty = GameLogic::empty_board();
+        assert_eq!(GameLogic::center_controlled(empty), None);
+        assert_eq!(GameLogic::corner_count(empty, Player::X), 0);
+    }
+
     #[test]
     fn test_player_next() {
         assert_eq!(Player::X.next(), Player::O);
         assert_eq!(Player::O.next(), Player::X);
     }
+
+    #[test]
+    fn test_player_opponent() {
+        assert_eq!(Player::X.opponent(), Player::O);
+        assert_eq!(Player::O.opponent(), Player::X);
+    }
+
+    #[test]
+    fn test_apply_sequence_win() {
+        // Xが上段を揃えた時点で止まり、残りの手は適用されない
+        let moves = [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (2, 2)];
+        let (board, state) = GameLogic::apply_sequence(GameLogic::empty_board(), &moves, Player::X).unwrap();
+        assert_eq!(state, GameState::Won(Player::X));
+        assert_eq!(board, GameLogic::parse_board("XXX/OO./...").unwrap());
+
+        // 途中局面からOが先に打つ
+        let start = GameLogic::parse_board("X../.../...").unwrap();
+        let (board, state) = GameLogic::apply_sequence(start, &[(1, 1)], Player::O).unwrap();
+        assert_eq!(state, GameState::Playing);
+        assert_eq!(board, GameLogic::parse_board("X../.O./...").unwrap());
+    }
+
+    #[test]
+    fn test_apply_sequence_illegal_move() {
+        let empty = GameLogic::empty_board();
+        assert_eq!(
+            GameLogic::apply_sequence(empty, &[(0, 0), (1, 1), (0, 0)], Player::X),
+            Err(MoveError::Occupied { index: 2, row: 0, col: 0 })
+        );
+        assert_eq!(
+            GameLogic::apply_sequence(empty, &[(3, 0)], Player::X),
+            Err(MoveError::OutOfBounds { index: 0, row: 3, col: 0 })
+        );
+    }
+
+    #[test]
+    fn test_best_move_tie_break() {
+        // 空の盤面ではすべての手が引き分けの評価なので、優先順の先頭（中央）を選ぶ
+        let empty = GameLogic::empty_board();
+        assert_eq!(GameLogic::best_move(empty, Player::X), Some((1, 1)));
+
+        // 中央を取られたら、同点の角のうち最初（左上）を選ぶ
+        let board = BoardBuilder::new().x(1, 1).build();
+        assert_eq!(GameLogic::best_move(board, Player::O), Some((0, 0)));
+
+        // 何度呼んでも同じ手になる
+        assert_eq!(GameLogic::best_move(board, Player::O), GameLogic::best_move(board, Player::O));
+    }
+
+    #[test]
+    fn test_principal_variation() {
+        // 空の盤面からの最善手順は9手で引き分けになる
+        let empty = GameLogic::empty_board();
+        let line = GameLogic::principal_variation(empty, Player::X);
+        assert_eq!(line.len(), 9);
+        let (_, state) = GameLogic::apply_sequence(empty, &line, Player::X).unwrap();
+        assert_eq!(state, GameState::Draw);
+
+        // 勝てる局面では最短の勝ち筋を選ぶ
+        let board = GameLogic::parse_board("XX./OO./...").unwrap();
+        assert_eq!(GameLogic::principal_variation(board, Player::X), vec![(0, 2)]);
+
+        // 終局済みなら空
+        let won = GameLogic::parse_board("XXX/OO./...").unwrap();
+        assert!(GameLogic::principal_variation(won, Player::O).is_empty());
+    }
+
+    #[test]
+    fn test_rotate_and_mirror() {
+        let board = GameLogic::parse_board("XO./.../...").unwrap();
+        assert_eq!(GameLogic::rotate90(board), GameLogic::parse_board("..X/..O/...").unwrap());
+        assert_eq!(GameLogic::mirror(board), GameLogic::parse_board(".OX/.../...").unwrap());
+        assert_eq!(Symmetry::MainDiagonal.apply(board), GameLogic::parse_board("X../O../...").unwrap());
+        assert_eq!(Symmetry::AntiDiagonal.apply(board), GameLogic::parse_board(".../..O/..X").unwrap());
+        assert_eq!(Symmetry::HorizontalAxis.apply(board), GameLogic::parse_board(".../.../XO.").unwrap());
+    }
+
+    #[test]
+    fn test_transpose() {
+        // 左の列（X・O・X）が上の行になる
+        let board = GameLogic::parse_board("XO./O../X..").unwrap();
+        let transposed = GameLogic::transpose(board);
+        assert_eq!(transposed, GameLogic::parse_board("XOX/O../...").unwrap());
+        assert_eq!(transposed[0], [0, 1, 2].map(|row| board[row][0]));
+
+        // 2回転置すると元に戻る
+        assert_eq!(GameLogic::transpose(transposed), board);
+    }
+
+    #[test]
+    fn test_symmetries() {
+        // 空の盤面はすべての変換で変わらない
+        assert_eq!(GameLogic::symmetries(GameLogic::empty_board()), Symmetry::ALL.to_vec());
+
+        // 非対称な盤面では恒等変換だけ
+        let asymmetric = GameLogic::parse_board("XO./.../...").unwrap();
+        assert_eq!(GameLogic::symmetries(asymmetric), vec![Symmetry::Identity]);
+
+        // 左上と右下のX：180°回転と両対角線で対称
+        let diagonal = GameLogic::parse_board("X../.../..X").unwrap();
+        assert_eq!(
+            GameLogic::symmetries(diagonal),
+            vec![Symmetry::Identity, Symmetry::Rotate180, Symmetry::MainDiagonal, Symmetry::AntiDiagonal]
+        );
+    }
+
+    #[test]
+    fn test_complete_with_best_play() {
+        // 空の盤面から最善手だけで進めると引き分けで終わる
+        let (board, state) = GameLogic::complete_with_best_play(GameLogic::empty_board(), Player::X);
+        assert_eq!(state, GameState::Draw);
+        assert!(GameLogic::is_board_full(board));
+
+        // 勝てる局面では勝ち切る、終局済みの盤面は変わらない
+        let board = GameLogic::parse_board("XX./OO./...").unwrap();
+        assert_eq!(GameLogic::complete_with_best_play(board, Player::O).1, GameState::Won(Player::O));
+        let won = GameLogic::parse_board("XXX/OO./...").unwrap();
+        assert_eq!(GameLogic::complete_with_best_play(won, Player::O), (won, GameState::Won(Player::X)));
+        assert_eq!(GameLogic::best_move(won, Player::O), None);
+    }
+
+    #[test]
+    fn test_moves_remaining() {
+        let empty = GameLogic::empty_board();
+        assert_eq!(GameLogic::moves_remaining(empty), 9);
+        assert!(!GameLogic::is_board_full(empty));
+
+        let partial = GameLogic::parse_board("XO./.X./...").unwrap();
+        assert_eq!(GameLogic::moves_remaining(partial), 6);
+        assert!(!GameLogic::is_board_full(partial));
+
+        let full = GameLogic::parse_board("XOX/XOO/OXX").unwrap();
+        assert_eq!(GameLogic::moves_remaining(full), 0);
+        assert!(GameLogic::is_board_full(full));
+    }
+
+    #[test]
+    fn test_winner() {
+        // 引き分けで埋まった盤面には勝者がいない
+        let drawn = GameLogic::parse_board("XOX/XOO/OXX").unwrap();
+        assert_eq!(GameLogic::winner(drawn), None);
+        assert_eq!(GameLogic::check_game_state(drawn), GameState::Draw);
+
+        let won = GameLogic::parse_board("XXX/OO./...").unwrap();
+        assert_eq!(GameLogic::winner(won), Some(Player::X));
+        assert_eq!(GameLogic::winner(GameLogic::empty_board()), None);
+    }
+
+    #[test]
+    fn test_score_increment_from() {
+        let previous = Score { x_wins: 2, o_wins: 1, draws: 0 };
+
+        let mut score = previous;
+        score.record(Outcome::Win(Player::O));
+        assert_eq!(score.increment_from(previous), Some(Outcome::Win(Player::O)));
+
+        let mut score = previous;
+        score.record(Outcome::Draw);
+        assert_eq!(score.increment_from(previous), Some(Outcome::Draw));
+
+        // 変化なし・全体のリセットでは反応しない
+        assert_eq!(previous.increment_from(previous), None);
+        assert_eq!(Score::default().increment_from(previous), None);
+    }
+
+    #[test]
+    fn test_is_winning_move() {
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).o(1, 0).o(1, 1).build();
+
+        // 揃う手はtrue、揃わない手・相手の手としてはfalse
+        assert!(GameLogic::is_winning_move(board, 0, 2, Player::X));
+        assert!(!GameLogic::is_winning_move(board, 2, 2, Player::X));
+        assert!(GameLogic::is_winning_move(board, 1, 2, Player::O));
+        assert!(!GameLogic::is_winning_move(board, 0, 2, Player::O));
+
+        // 埋まっているセル・範囲外はfalse（盤面はそのまま）
+        assert!(!GameLogic::is_winning_move(board, 0, 0, Player::X));
+        assert!(!GameLogic::is_winning_move(board, 3, 0, Player::X));
+        assert_eq!(board, BoardBuilder::new().x(0, 0).x(0, 1).o(1, 0).o(1, 1).build());
+    }
+
+    #[test]
+    fn test_evaluate_move() {
+        let board = BoardBuilder::new().x(0, 0).x(0, 1).o(1, 0).o(1, 1).build();
+
+        // Xが揃える手は即勝ち（残り4マス）、見逃すとOが揃えて負け
+        assert_eq!(GameLogic::evaluate_move(board, 0, 2, Player::X), Some(GameLogic::MAX_EVALUATION));
+        assert_eq!(GameLogic::evaluate_move(board, 2, 2, Player::X), Some(-13));
+
+        // 埋まっているセルは評価しない（盤面はそのまま）
+        assert_eq!(GameLogic::evaluate_move(board, 0, 0, Player::X), None);
+        assert_eq!(board[0][2], None);
+    }
+
+    #[test]
+    fn test_parse_board_accepts_emoji() {
+        let board = GameLogic::parse_board("XO./.X./..O").unwrap();
+
+        // emoji_gridの出力をそのまま読み戻せる（異体字セレクタ付きでもよい）
+        assert_eq!(GameLogic::parse_board(&GameLogic::emoji_grid(board)), Ok(board));
+        assert_eq!(GameLogic::parse_board("❌️⭕️⬜\n⬜❌️⬜\n⬜⬜⭕️"), Ok(board));
+
+        // 文字と絵文字が混ざっていてもよいが、マス数が足りなければエラー
+        assert_eq!(GameLogic::parse_board("❌O./.x./⬜.⭕"), Ok(board));
+        assert!(GameLogic::parse_board("❌⭕⬜").is_err());
+        assert!(GameLogic::parse_board("❌⭕⬜/🙂⬜⬜/⬜⬜⬜").is_err());
+    }
+
+    #[test]
+    fn test_parse_shared_board() {
+        let board = GameLogic::parse_board("XXX/OO./...").unwrap();
+
+        // 共有ボタンの文面（見出し行つき）から盤面だけを読み取る
+        let shared = format!("三目並べ: Xの勝ち！\n{}", GameLogic::emoji_grid(board));
+        assert_eq!(GameLogic::parse_shared_board(&shared), Ok(board));
+        assert_eq!(GameLogic::parse_shared_board("XXX/OO./..."), Ok(board));
+
+        // 盤面が含まれていなければ、元のエラーを返す
+        assert!(GameLogic::parse_shared_board("こんにちは").is_err());
+        assert!(GameLogic::parse_shared_board("見出し\n❌⭕\n⬜\n⬜").is_err());
+    }
+
+    #[test]
+    fn test_counts() {
+        assert_eq!(GameLogic::counts(GameLogic::empty_board()), (0, 0));
+
+        // 合計は手数（埋まっているマスの数）と一致する
+        let board = GameLogic::parse_board("XO./.X./..O").unwrap();
+        let (x, o) = GameLogic::counts(board);
+        assert_eq!((x, o), (2, 2));
+        assert_eq!(x + o, 9 - GameLogic::moves_remaining(board));
+    }
+
+    #[test]
+    fn test_no_diagonals_rule() {
+        // 斜めに揃えても勝ちにならない（標準ルールでは勝ち）
+        let diagonal = GameLogic::parse_board("XO./OX./..X").unwrap();
+        assert_eq!(GameLogic::check_game_state_with(diagonal, WinRule::Standard), GameState::Won(Player::X));
+        assert_eq!(GameLogic::check_game_state_with(diagonal, WinRule::NoDiagonals), GameState::Playing);
+
+        // 横・縦はどちらのルールでも勝ち
+        let row = GameLogic::parse_board("XXX/OO./...").unwrap();
+        assert_eq!(GameLogic::winner_with(row, WinRule::NoDiagonals), Some(Player::X));
+        let column = GameLogic::parse_board("OX./OX./.X.").unwrap();
+        assert_eq!(GameLogic::winner_with(column, WinRule::NoDiagonals), Some(Player::X));
+
+        assert_eq!(GameLogic::lines(WinRule::Standard).len(), 8);
+        assert_eq!(GameLogic::lines(WinRule::NoDiagonals).len(), 6);
+    }
+
+    #[test]
+    fn test_load_board_with_no_diagonals() {
+        // 斜めなしでは、斜めに揃った盤面も対局中として読み込む
+        let diagonal = GameLogic::parse_board("XO./OX./..X").unwrap();
+        assert_eq!(GameLogic::load_board(diagonal), Ok((GameState::Won(Player::X), Player::X)));
+        assert_eq!(GameLogic::load_board_with(diagonal, WinRule::NoDiagonals), Ok((GameState::Playing, Player::O)));
+
+        // 斜めに揃った後も対局が続いた盤面は、斜めなしでだけ到達できる
+        let continued = GameLogic::parse_board("XO./OXO/..X").unwrap();
+        assert!(GameLogic::validate(continued).is_err());
+        assert_eq!(GameLogic::validate_with(continued, WinRule::NoDiagonals), Ok(()));
+    }
+
+    #[test]
+    fn test_assert_invariants_accepts_consistent_states() {
+        GameLogic::assert_invariants(GameLogic::empty_board(), GameState::Playing, Player::X);
+        GameLogic::assert_invariants(BoardBuilder::new().x(1, 1).build(), GameState::Playing, Player::O);
+
+        // 終局後の手番は最後に置いたプレイヤー
+        let won = GameLogic::parse_board("XXX/OO./...").unwrap();
+        GameLogic::assert_invariants(won, GameState::Won(Player::X), Player::X);
+    }
+
+    #[test]
+    #[should_panic(expected = "ゲーム状態が盤面と一致しません")]
+    fn test_assert_invariants_catches_wrong_state() {
+        // 揃っているのに「プレイ中」のまま
+        let won = GameLogic::parse_board("XXX/OO./...").unwrap();
+        GameLogic::assert_invariants(won, GameState::Playing, Player::O);
+    }
+
+    #[test]
+    #[should_panic(expected = "手番が盤面と一致しません")]
+    fn test_assert_invariants_catches_wrong_turn() {
+        GameLogic::assert_invariants(BoardBuilder::new().x(1, 1).build(), GameState::Playing, Player::X);
+    }
+
+    #[test]
+    #[should_panic(expected = "不正な盤面です")]
+    fn test_assert_invariants_catches_illegal_counts() {
+        GameLogic::assert_invariants(BoardBuilder::new().o(1, 1).build(), GameState::Playing, Player::X);
+    }
+
+    #[test]
+    fn test_handicap_start() {
+        // Xに1個：中央にXを置いた局面からOが着手する（通常の盤面読み込みと同じ結果）
+        let handicap = Handicap { player: Player::X, marks: 1 };
+        let (board, to_move) = handicap.start().unwrap();
+        assert_eq!(board, BoardBuilder::new().x(1, 1).build());
+        assert_eq!(to_move, Player::O);
+        assert_eq!(GameLogic::load_board(board), Ok((GameState::Playing, Player::O)));
+
+        // Oに1個：Oの駒だけがあり、Xが着手する
+        let (board, to_move) = Handicap { player: Player::O, marks: 1 }.start().unwrap();
+        assert_eq!(board, BoardBuilder::new().o(1, 1).build());
+        assert_eq!(to_move, Player::X);
+
+        // すべての選択肢が対局中の局面になり、上限を超えるとエラー
+        for choice in Handicap::CHOICES {
+            let (board, _) = choice.start().unwrap();
+            assert_eq!(GameLogic::count_pieces(board, choice.player), choice.marks);
+            assert_eq!(GameLogic::check_game_state(board), GameState::Playing);
+        }
+        assert_eq!(Handicap::NONE.start(), Ok((GameLogic::empty_board(), Player::X)));
+        assert!(Handicap { player: Player::X, marks: 3 }.start().is_err());
+    }
+
+    #[test]
+    fn test_load_board_from_handicap_start() {
+        // Oに1個：通常の読み込みでは駒数が不正な盤面も、開始局面から指し進めた盤面として読み込める
+        let start = Handicap { player: Player::O, marks: 1 }.start().unwrap();
+        assert!(GameLogic::load_board(start.0).is_err());
+        assert_eq!(GameLogic::load_board_from(start, start.0, WinRule::Standard), Ok((GameState::Playing, Player::X)));
+        let board = BoardBuilder::new().o(1, 1).x(0, 0).build();
+        assert_eq!(GameLogic::load_board_from(start, board, WinRule::Standard), Ok((GameState::Playing, Player::O)));
+
+        // 開始局面の駒がない・後から置いた駒の数が合わない盤面はエラー
+        assert!(GameLogic::load_board_from(start, BoardBuilder::new().x(0, 0).build(), WinRule::Standard).is_err());
+        let board = BoardBuilder::new().o(1, 1).x(0, 0).x(0, 1).build();
+        assert!(GameLogic::load_board_from(start, board, WinRule::Standard).is_err());
+
+        // 空の盤面・Xからの開始なら、通常の読み込みと同じ結果
+        let empty = (GameLogic::empty_board(), Player::X);
+        for board in [
+            BoardBuilder::new().x(0, 0).o(1, 1).build(),
+            BoardBuilder::new().x(0, 0).x(0, 1).x(0, 2).o(1, 0).o(1, 1).build(),
+            BoardBuilder::new().x(0, 0).x(0, 1).x(0, 2).o(1, 0).o(1, 1).o(2, 2).build(),
+        ] {
+            assert_eq!(GameLogic::load_board_from(empty, board, WinRule::Standard).ok(), GameLogic::load_board(board).ok());
+        }
+    }
+
+    #[test]
+    fn test_check_after_move_matches_full_scan() {
+        // ランダムな対局を進め、毎手の判定を全ラインの判定と比べる
+        for seed in 0..200 {
+            let mut rng = crate::ai::Rng::new(seed);
+            for rule in WinRule::ALL {
+                let mut board = GameLogic::empty_board();
+                let mut player = Player::X;
+                while GameLogic::check_game_state_with(board, rule) == GameState::Playing {
+                    let cells = GameLogic::empty_cells(board);
+                    let (row, col) = cells[rng.below(cells.len())];
+                    board[row][col] = Some(player);
+                    assert_eq!(
+                        GameLogic::check_after_move_with(board, row, col, rule),
+                        GameLogic::check_game_state_with(board, rule),
+                        "seed={seed}, {rule:?}, {board:?}"
+                    );
+                    player = player.next();
+                }
+            }
+        }
+
+        // 置いたセルを通らないラインは調べない（着手前から揃っていた盤面は対象外）
+        let board = GameLogic::parse_board("XXX/OO./...").unwrap();
+        assert_eq!(GameLogic::check_after_move(board, 1, 1), GameState::Playing);
+        assert_eq!(GameLogic::check_after_move(board, 0, 2), GameState::Won(Player::X));
+    }
+
+    #[test]
+    fn test_explain_move() {
+        // Xは上段でリーチ、Oは中段でリーチ
+        let board = GameLogic::parse_board("XX./OO./...").unwrap();
+        assert_eq!(GameLogic::explain_move(board, 0, 2, Player::X), Some(MoveHint::Wins));
+        assert_eq!(GameLogic::explain_move(board, 1, 2, Player::X), Some(MoveHint::Blocks));
+        assert_eq!(GameLogic::explain_move(board, 2, 2, Player::X), Some(MoveHint::Neutral));
+
+        // 手番が逆なら、勝ちと止める手が入れ替わる
+        assert_eq!(GameLogic::explain_move(board, 1, 2, Player::O), Some(MoveHint::Wins));
+        assert_eq!(GameLogic::explain_move(board, 0, 2, Player::O), Some(MoveHint::Blocks));
+
+        // 駒のあるセル・範囲外は分類しない
+        assert_eq!(GameLogic::explain_move(board, 0, 0, Player::X), None);
+        assert_eq!(GameLogic::explain_move(board, 3, 0, Player::X), None);
+    }
+
+    #[test]
+    fn test_count_outcomes() {
+        // 空の盤面からのゲーム木は255,168通り（Xの勝ち131,184・Oの勝ち77,904・引き分け46,080）
+        let (x_wins, o_wins, draws) = GameLogic::count_outcomes(GameLogic::empty_board(), Player::X);
+        assert_eq!((x_wins, o_wins, draws), (131_184, 77_904, 46_080));
+        assert_eq!(x_wins + o_wins + draws, 255_168);
+
+        // 終局した盤面はその結末1通り、残り1マスなら置いた結果の1通り
+        let won = GameLogic::parse_board("XXX/OO./...").unwrap();
+        assert_eq!(GameLogic::count_outcomes(won, Player::O), (1, 0, 0));
+        let last = GameLogic::parse_board("XOX/XOO/OX.").unwrap();
+        assert_eq!(GameLogic::count_outcomes(last, Player::X), (0, 0, 1));
+    }
+
+    #[test]
+    fn test_move_status() {
+        let board = GameLogic::parse_board("XX./OO./...").unwrap();
+        let playing = GameState::Playing;
+        assert_eq!(GameLogic::move_status(board, playing, 0, 2), MoveStatus::Ok);
+        assert_eq!(GameLogic::move_status(board, playing, 0, 0), MoveStatus::Occupied);
+        assert_eq!(GameLogic::move_status(board, playing, 3, 0), MoveStatus::OutOfBounds);
+        assert_eq!(GameLogic::move_status(board, playing, 0, 3), MoveStatus::OutOfBounds);
+
+        // 勝負がついた盤面では、空きセルも駒のあるセルも「対局終了」
+        let won = GameLogic::parse_board("XXX/OO./...").unwrap();
+        let state = GameLogic::check_game_state(won);
+        assert_eq!(GameLogic::move_status(won, state, 2, 2), MoveStatus::GameOver);
+        assert_eq!(GameLogic::move_status(won, state, 1, 0), MoveStatus::GameOver);
+        assert_eq!(GameLogic::move_status(won, state, 5, 5), MoveStatus::OutOfBounds);
+
+        // 置けるのはOkのときだけで、理由はOk以外にある
+        assert_eq!(MoveStatus::Ok.reason(), None);
+        assert_eq!(MoveStatus::GameOver.reason(), Some("ゲーム終了"));
+    }
+
+    #[test]
+    fn test_line_blocked_on_last_empty_cell() {
+        // 上段はX・O・空き。Xが右上を埋めると上段がふさがる
+        let before = GameLogic::parse_board("XO./.../...").unwrap();
+        assert!(GameLogic::blocked_lines(before).is_empty());
+        let after = GameLogic::make_move(before, 0, 2, Player::X).unwrap();
+        assert_eq!(
+            GameLogic::events_after_move(after, 0, 2),
+            vec![GameEvent::LineBlocked([(0, 0), (0, 1), (0, 2)])]
+        );
+
+        // 既にふさがっているラインは、関係のない着手では通知しない
+        let later = GameLogic::make_move(after, 2, 0, Player::O).unwrap();
+        assert_eq!(GameLogic::blocked_lines(later).len(), 1);
+        assert!(GameLogic::events_after_move(later, 2, 0).is_empty());
+
+        // 同じ駒だけで埋まったラインは対象外
+        let won = GameLogic::parse_board("XXX/OO./...").unwrap();
+        assert!(GameLogic::events_after_move(won, 0, 2).is_empty());
+    }
+
+    #[test]
+    fn test_transcript_round_trip() {
+        // Xが中央・Oが左上・Xが下段中央
+        let moves = vec![(1, 1), (0, 0), (2, 1)];
+        let text = GameLogic::to_transcript(&moves, Player::X);
+        assert_eq!(text, "1. X b2 2. O a1 3. X b3");
+        assert_eq!(GameLogic::from_transcript(&text), Ok((moves, Player::X)));
+
+        // Oから始まる棋譜（ハンディキャップ等）も戻せる
+        let text = GameLogic::to_transcript(&[(0, 2), (2, 0)], Player::O);
+        assert_eq!(text, "1. O c1 2. X a3");
+        assert_eq!(GameLogic::from_transcript(&text), Ok((vec![(0, 2), (2, 0)], Player::O)));
+    }
+
+    #[test]
+    fn test_from_transcript_rejects_malformed() {
+        assert!(GameLogic::from_transcript("").is_err());
+        assert!(GameLogic::from_transcript("1. X b2 2. O").is_err());
+        assert!(GameLogic::from_transcript("1. X b2 3. O a1").is_err());
+        assert!(GameLogic::from_transcript("1. X b2 2. X a1").is_err());
+        assert!(GameLogic::from_transcript("1. X d4").is_err());
+        assert!(GameLogic::from_transcript("1. Z a1").is_err());
+    }
+
+    #[test]
+    fn test_mate_in() {
+        // Xは上段でリーチ：次の1手で勝てる
+        let board = GameLogic::parse_board("XX./OO./...").unwrap();
+        assert_eq!(GameLogic::mate_in(board, Player::X), Some(1));
+
+        // Xが右上に置くと両取り（上段と斜め）になり、Oが片方を止めても3手目で勝てる
+        let fork = GameLogic::parse_board("X../OX./..O").unwrap();
+        assert_eq!(GameLogic::mate_in(fork, Player::X), Some(3));
+
+        // 空の盤面は最善を尽くせば引き分けなので、必勝はない
+        assert_eq!(GameLogic::mate_in(GameLogic::empty_board(), Player::X), None);
+
+        // 終局した盤面も対象外
+        let won = GameLogic::parse_board("XXX/OO./...").unwrap();
+        assert_eq!(GameLogic::mate_in(won, Player::O), None);
+    }
+
+    #[test]
+    fn test_cycle_cell() {
+        // 左クリック：空→X→O→空
+        assert_eq!(GameLogic::cycle_cell(None, false), Some(Player::X));
+        assert_eq!(GameLogic::cycle_cell(Some(Player::X), false), Some(Player::O));
+        assert_eq!(GameLogic::cycle_cell(Some(Player::O), false), None);
+
+        // 右クリック：逆順（空→O→X→空）で、左クリックの1手を取り消す
+        assert_eq!(GameLogic::cycle_cell(None, true), Some(Player::O));
+        assert_eq!(GameLogic::cycle_cell(Some(Player::O), true), Some(Player::X));
+        assert_eq!(GameLogic::cycle_cell(Some(Player::X), true), None);
+        for cell in [None, Some(Player::X), Some(Player::O)] {
+            assert_eq!(GameLogic::cycle_cell(GameLogic::cycle_cell(cell, false), true), cell);
+        }
+    }
+
+    #[test]
+    fn test_board_rotation_maps_display_to_logical() {
+        // 90度：表示の左上には盤面の左下のマスを描く
+        assert_eq!(BoardRotation::Quarter.to_logical(0, 0), (2, 0));
+        assert_eq!(BoardRotation::Half.to_logical(0, 0), (2, 2));
+        assert_eq!(BoardRotation::ThreeQuarter.to_logical(0, 0), (0, 2));
+        assert_eq!(BoardRotation::None.to_logical(1, 2), (1, 2));
+
+        // 表示位置ごとに描いた盤面は、rotate90を回数分かけた盤面と一致する
+        let board = GameLogic::parse_board("XO./..X/O..").unwrap();
+        let mut expected = board;
+        for rotation in BoardRotation::ALL {
+            let displayed: Board = std::array::from_fn(|row| {
+                std::array::from_fn(|col| {
+                    let (r, c) = rotation.to_logical(row, col);
+                    board[r][c]
+                })
+            });
+            assert_eq!(displayed, expected, "{rotation:?}");
+            expected = GameLogic::rotate90(expected);
+        }
+
+        // どの角度でも9マスすべてに1対1で対応する（クリックは必ず本来のマスに届く）
+        for rotation in BoardRotation::ALL {
+            let mut seen = [[false; 3]; 3];
+            for row in 0..3 {
+                for col in 0..3 {
+                    let (r, c) = rotation.to_logical(row, col);
+                    assert!(!seen[r][c]);
+                    seen[r][c] = true;
+                }
+            }
+        }
+    }
 }